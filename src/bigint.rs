@@ -0,0 +1,535 @@
+use std::cmp::Ordering;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::scalar::{Integer, One, Zero};
+
+/// Arbitrary precision signed integer.
+///
+/// The magnitude is stored as little-endian base 2^32 limbs without trailing zeros,
+/// so zero is represented by an empty magnitude and is never negative.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl BigInt {
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        let negative = negative && !magnitude.is_empty();
+        Self {
+            negative,
+            magnitude,
+        }
+    }
+
+    pub fn signum(&self) -> i32 {
+        if self.magnitude.is_empty() {
+            0
+        } else if self.negative {
+            -1
+        } else {
+            1
+        }
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.magnitude.len() > 2 {
+            return None;
+        }
+        let mut value = 0u64;
+        for (i, limb) in self.magnitude.iter().enumerate() {
+            value |= (*limb as u64) << (32 * i);
+        }
+        if self.negative {
+            if value <= i64::MAX as u64 + 1 {
+                Some((value as i64).wrapping_neg())
+            } else {
+                None
+            }
+        } else {
+            i64::try_from(value).ok()
+        }
+    }
+}
+
+fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0u64;
+    for (i, limb) in long.iter().enumerate() {
+        let sum = *limb as u64 + short.get(i).copied().unwrap_or(0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+/// Requires `a >= b`.
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+    for (i, limb) in a.iter().enumerate() {
+        let mut diff = *limb as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        borrow = if diff < 0 {
+            diff += 1 << 32;
+            1
+        } else {
+            0
+        };
+        result.push(diff as u32);
+    }
+    result
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![0u32; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, y) in b.iter().enumerate() {
+            let product = *x as u64 * *y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    result
+}
+
+fn divrem_small(a: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0u32; a.len()];
+    let mut remainder = 0u64;
+    for i in (0..a.len()).rev() {
+        let current = (remainder << 32) | a[i] as u64;
+        quotient[i] = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+    (quotient, remainder as u32)
+}
+
+fn shl_bits(a: &[u32], shift: u32) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0u32;
+    for limb in a {
+        result.push((limb << shift) | carry);
+        carry = if shift == 0 { 0 } else { limb >> (32 - shift) };
+    }
+    result.push(carry);
+    result
+}
+
+fn shr_bits(a: &[u32], shift: u32) -> Vec<u32> {
+    let mut result = vec![0u32; a.len()];
+    for i in 0..a.len() {
+        let high = if shift == 0 {
+            0
+        } else {
+            a.get(i + 1).copied().unwrap_or(0) << (32 - shift)
+        };
+        result[i] = (a[i] >> shift) | high;
+    }
+    result
+}
+
+/// Long division of magnitudes (Knuth, TAOCP vol. 2, algorithm D).
+fn divrem_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    assert!(!b.is_empty(), "attempt to divide by zero");
+    if cmp_magnitude(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if b.len() == 1 {
+        let (quotient, remainder) = divrem_small(a, b[0]);
+        return (quotient, vec![remainder]);
+    }
+
+    let shift = b[b.len() - 1].leading_zeros();
+    let n = b.len();
+    let mut v = shl_bits(b, shift);
+    v.pop();
+    let mut u = shl_bits(a, shift);
+    let m = u.len() - n;
+    let mut quotient = vec![0u32; m];
+    let base = 1u64 << 32;
+
+    for j in (0..m).rev() {
+        let numerator = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+        let mut qhat = numerator / v[n - 1] as u64;
+        let mut rhat = numerator % v[n - 1] as u64;
+        while qhat >= base || qhat * v[n - 2] as u64 > ((rhat << 32) | u[j + n - 2] as u64) {
+            qhat -= 1;
+            rhat += v[n - 1] as u64;
+            if rhat >= base {
+                break;
+            }
+        }
+
+        let mut borrow = 0i64;
+        let mut carry = 0u64;
+        for i in 0..n {
+            let product = qhat * v[i] as u64 + carry;
+            carry = product >> 32;
+            let diff = u[i + j] as i64 - borrow - (product & 0xffff_ffff) as i64;
+            u[i + j] = diff as u32;
+            borrow = if diff < 0 { 1 } else { 0 };
+        }
+        let diff = u[j + n] as i64 - borrow - carry as i64;
+        u[j + n] = diff as u32;
+
+        if diff < 0 {
+            qhat -= 1;
+            let mut carry = 0u64;
+            for i in 0..n {
+                let sum = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = qhat as u32;
+    }
+
+    (quotient, shr_bits(&u[..n], shift))
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        let magnitude = value.unsigned_abs();
+        Self::from_parts(value < 0, vec![magnitude as u32, (magnitude >> 32) as u32])
+    }
+}
+
+impl From<i32> for BigInt {
+    fn from(value: i32) -> Self {
+        Self::from(value as i64)
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(value: u64) -> Self {
+        Self::from_parts(false, vec![value as u32, (value >> 32) as u32])
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+
+        let mut magnitude = Vec::new();
+        for chunk in digits.as_bytes().chunks(9) {
+            let chunk_value: u32 = std::str::from_utf8(chunk).unwrap().parse().unwrap();
+            let scale = 10u32.pow(chunk.len() as u32);
+            magnitude = add_magnitude(&mul_magnitude(&magnitude, &[scale]), &[chunk_value]);
+        }
+
+        Ok(Self::from_parts(negative, magnitude))
+    }
+}
+
+impl std::fmt::Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.magnitude.is_empty() {
+            return write!(f, "0");
+        }
+
+        let mut chunks = Vec::new();
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = divrem_small(&magnitude, 1_000_000_000);
+            chunks.push(remainder);
+            magnitude = BigInt::from_parts(false, quotient).magnitude;
+        }
+
+        let mut output = String::new();
+        if self.negative {
+            output.push('-');
+        }
+        output.push_str(&chunks.pop().unwrap().to_string());
+        for chunk in chunks.iter().rev() {
+            output.push_str(&format!("{:09}", chunk));
+        }
+        write!(f, "{}", output)
+    }
+}
+
+impl std::fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => cmp_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        BigInt::from_parts(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> Self::Output {
+        BigInt::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, rhs: &BigInt) -> Self::Output {
+        if self.negative == rhs.negative {
+            return BigInt::from_parts(
+                self.negative,
+                add_magnitude(&self.magnitude, &rhs.magnitude),
+            );
+        }
+        match cmp_magnitude(&self.magnitude, &rhs.magnitude) {
+            Ordering::Equal => BigInt::default(),
+            Ordering::Greater => BigInt::from_parts(
+                self.negative,
+                sub_magnitude(&self.magnitude, &rhs.magnitude),
+            ),
+            Ordering::Less => {
+                BigInt::from_parts(rhs.negative, sub_magnitude(&rhs.magnitude, &self.magnitude))
+            }
+        }
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, rhs: &BigInt) -> Self::Output {
+        self + &-rhs
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, rhs: &BigInt) -> Self::Output {
+        BigInt::from_parts(
+            self.negative != rhs.negative,
+            mul_magnitude(&self.magnitude, &rhs.magnitude),
+        )
+    }
+}
+
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn div(self, rhs: &BigInt) -> Self::Output {
+        let (quotient, _) = divrem_magnitude(&self.magnitude, &rhs.magnitude);
+        BigInt::from_parts(self.negative != rhs.negative, quotient)
+    }
+}
+
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    fn rem(self, rhs: &BigInt) -> Self::Output {
+        let (_, remainder) = divrem_magnitude(&self.magnitude, &rhs.magnitude);
+        BigInt::from_parts(self.negative, remainder)
+    }
+}
+
+macro_rules! forward_binop {
+    ($($trait:ident, $method:ident);*) => {
+        $(
+            impl $trait<BigInt> for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, rhs: BigInt) -> Self::Output {
+                    (&self).$method(&rhs)
+                }
+            }
+
+            impl $trait<&BigInt> for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, rhs: &BigInt) -> Self::Output {
+                    (&self).$method(rhs)
+                }
+            }
+
+            impl $trait<BigInt> for &BigInt {
+                type Output = BigInt;
+
+                fn $method(self, rhs: BigInt) -> Self::Output {
+                    self.$method(&rhs)
+                }
+            }
+        )*
+    };
+}
+
+forward_binop!(Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem);
+
+impl AddAssign<&BigInt> for BigInt {
+    fn add_assign(&mut self, rhs: &BigInt) {
+        *self = &*self + rhs;
+    }
+}
+
+impl SubAssign<&BigInt> for BigInt {
+    fn sub_assign(&mut self, rhs: &BigInt) {
+        *self = &*self - rhs;
+    }
+}
+
+impl MulAssign for BigInt {
+    fn mul_assign(&mut self, rhs: BigInt) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl Zero for BigInt {
+    fn zero() -> Self {
+        BigInt::default()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+}
+
+impl One for BigInt {
+    fn one() -> Self {
+        BigInt::from(1)
+    }
+}
+
+impl Integer for BigInt {
+    fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    fn abs(&self) -> Self {
+        BigInt::from_parts(false, self.magnitude.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::SplitMix64;
+
+    fn big(value: i128) -> BigInt {
+        value.to_string().parse().unwrap()
+    }
+
+    /// Random value of a random bit length below `bits`, with a random sign.
+    fn sample(rng: &mut SplitMix64, bits: u32) -> i128 {
+        let raw = ((rng.next_u64() as u128) << 64 | rng.next_u64() as u128) >> 2;
+        let value = (raw >> (126 - rng.next_u64() as u32 % bits)) as i128;
+        if rng.next_u64() < u64::MAX / 2 {
+            value
+        } else {
+            -value
+        }
+    }
+
+    #[test]
+    fn parsing_and_display_round_trip() {
+        for text in [
+            "0",
+            "1",
+            "-1",
+            "4294967296",
+            "-340282366920938463463374607431768211455",
+        ] {
+            assert_eq!(text.parse::<BigInt>().unwrap().to_string(), text);
+        }
+        assert_eq!("-0".parse::<BigInt>().unwrap(), BigInt::zero());
+        assert!("12a".parse::<BigInt>().is_err());
+        assert!("".parse::<BigInt>().is_err());
+    }
+
+    #[test]
+    fn arithmetic_matches_i128() {
+        let mut rng = SplitMix64::new(7);
+        for _ in 0..2000 {
+            let (a, b) = (sample(&mut rng, 62), sample(&mut rng, 62));
+            assert_eq!((big(a) + big(b)).to_string(), (a + b).to_string());
+            assert_eq!((big(a) - big(b)).to_string(), (a - b).to_string());
+            assert_eq!((big(a) * big(b)).to_string(), (a * b).to_string());
+            assert_eq!(big(a).cmp(&big(b)), a.cmp(&b));
+        }
+    }
+
+    #[test]
+    fn division_matches_i128() {
+        let mut rng = SplitMix64::new(11);
+        for _ in 0..5000 {
+            let (a, b) = (sample(&mut rng, 126), sample(&mut rng, 126));
+            if b == 0 {
+                continue;
+            }
+            assert_eq!(
+                (big(a) / big(b)).to_string(),
+                (a / b).to_string(),
+                "{} / {}",
+                a,
+                b
+            );
+            assert_eq!(
+                (big(a) % big(b)).to_string(),
+                (a % b).to_string(),
+                "{} % {}",
+                a,
+                b
+            );
+        }
+    }
+
+    #[test]
+    fn to_i64_checks_range() {
+        assert_eq!(big(i64::MIN as i128).to_i64(), Some(i64::MIN));
+        assert_eq!(big(i64::MAX as i128).to_i64(), Some(i64::MAX));
+        assert_eq!(big(i64::MAX as i128 + 1).to_i64(), None);
+        assert_eq!(big(i64::MIN as i128 - 1).to_i64(), None);
+    }
+}
@@ -0,0 +1,194 @@
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::scalar::{One, Zero};
+
+/// Complex number with `f64` parts.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    /// `r * e^(i theta)`.
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        Self::new(r * theta.cos(), r * theta.sin())
+    }
+
+    pub fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    pub fn norm(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    pub fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    pub fn arg(self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    /// Principal square root, with non-negative real part.
+    pub fn sqrt(self) -> Self {
+        Self::from_polar(self.norm().sqrt(), self.arg() / 2.0)
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(value: f64) -> Self {
+        Self::new(value, 0.0)
+    }
+}
+
+impl std::fmt::Display for Complex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.im < 0.0 {
+            write!(f, "{}-{}i", self.re, -self.im)
+        } else {
+            write!(f, "{}+{}i", self.re, self.im)
+        }
+    }
+}
+
+impl std::fmt::Debug for Complex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        let scale = rhs.norm_sqr();
+        let numerator = self * rhs.conj();
+        Self::new(numerator.re / scale, numerator.im / scale)
+    }
+}
+
+impl Mul<f64> for Complex {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.re * rhs, self.im * rhs)
+    }
+}
+
+impl Neg for Complex {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl AddAssign for Complex {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Complex {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Complex {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Zero for Complex {
+    fn zero() -> Self {
+        Self::new(0.0, 0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re == 0.0 && self.im == 0.0
+    }
+}
+
+impl One for Complex {
+    fn one() -> Self {
+        Self::new(1.0, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Complex, b: Complex) -> bool {
+        (a - b).norm() < 1e-12
+    }
+
+    #[test]
+    fn field_arithmetic() {
+        let a = Complex::new(3.0, -2.0);
+        let b = Complex::new(-1.0, 4.0);
+        assert_eq!(a + b, Complex::new(2.0, 2.0));
+        assert_eq!(a - b, Complex::new(4.0, -6.0));
+        assert_eq!(a * b, Complex::new(5.0, 14.0));
+        assert!(close(a / b * b, a));
+        assert_eq!(a * a.conj(), Complex::from(a.norm_sqr()));
+        assert_eq!(-a * 2.0, Complex::new(-6.0, 4.0));
+    }
+
+    #[test]
+    fn polar_form_and_square_root() {
+        let z = Complex::from_polar(2.0, 0.75);
+        assert!((z.norm() - 2.0).abs() < 1e-12);
+        assert!((z.arg() - 0.75).abs() < 1e-12);
+
+        let root = Complex::new(-4.0, 0.0).sqrt();
+        assert!(close(root, Complex::new(0.0, 2.0)));
+        for z in [Complex::new(3.0, 4.0), Complex::new(-1.0, -1.0)] {
+            let root = z.sqrt();
+            assert!(root.re >= 0.0);
+            assert!(close(root * root, z));
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Complex::new(1.5, -2.0).to_string(), "1.5-2i");
+        assert_eq!(Complex::new(0.0, 3.0).to_string(), "0+3i");
+    }
+}
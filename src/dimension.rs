@@ -17,4 +17,8 @@ impl Dimension {
     pub fn len(&self) -> isize {
         self.width() * self.height()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
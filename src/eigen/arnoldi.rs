@@ -0,0 +1,199 @@
+use crate::complex::Complex;
+use crate::matrices::MisAlignment;
+use crate::operator::LinearOperator;
+use crate::scalar::Zero;
+
+use super::dense::{eigenvector, hessenberg_eigenvalues, identity, shifted_qr_step};
+use super::krylov::Factorization;
+use super::{Eigen, EigenOptions, Which};
+
+/// The `k` eigenvalues of largest or smallest modulus of a general operator and their
+/// eigenvectors, by the implicitly restarted Arnoldi method.
+///
+/// Unwanted Ritz values are used as exact shifts, complex ones in conjugate pairs so that all
+/// arithmetic on the basis stays real. If the `k`-th wanted eigenvalue is one half of a
+/// complex conjugate pair, the other half is kept in the subspace as well.
+///
+/// The two halves of a conjugate pair are returned next to each other, the one with positive
+/// imaginary part first.
+pub fn arnoldi<A: LinearOperator + ?Sized>(
+    a: &A,
+    k: usize,
+    which: Which,
+    options: &EigenOptions,
+) -> Result<Eigen, MisAlignment> {
+    let n = a.ncols();
+    if a.nrows() != n || k == 0 || k > n {
+        return Err(MisAlignment);
+    }
+
+    let m = options.subspace_size(n, k);
+    let mut factorization = Factorization::new(n, m, options.seed);
+    factorization.extend(a, m);
+
+    let mut iterations = 0;
+    loop {
+        let (values, deflated) = hessenberg_eigenvalues(&factorization.h);
+
+        let mut order: Vec<usize> = (0..m).collect();
+        order.sort_by(|&x, &y| {
+            let by_modulus = match which {
+                Which::Largest => values[y].norm().total_cmp(&values[x].norm()),
+                Which::Smallest => values[x].norm().total_cmp(&values[y].norm()),
+            };
+            by_modulus.then(values[y].im.total_cmp(&values[x].im))
+        });
+
+        let mut kept = k;
+        if kept < m && values[order[kept - 1]].im > 0.0 && values[order[kept]].im < 0.0 {
+            kept += 1;
+        }
+
+        let ritz_vectors: Vec<Vec<Complex>> = order[..k]
+            .iter()
+            .map(|&i| eigenvector(&factorization.h, values[i]))
+            .collect();
+        let beta = crate::iterative::norm(&factorization.residual);
+        let converged = deflated
+            && order[..k]
+                .iter()
+                .zip(&ritz_vectors)
+                .all(|(&i, y)| options.accepts(beta * y[m - 1].norm(), values[i].norm()));
+        if converged || iterations == options.max_iterations || kept == m {
+            let vectors = ritz_vectors
+                .iter()
+                .map(|y| {
+                    let mut x = vec![Complex::zero(); n];
+                    for (v, coefficient) in factorization.basis.iter().zip(y) {
+                        for (x, v) in x.iter_mut().zip(v) {
+                            *x += *coefficient * *v;
+                        }
+                    }
+                    x
+                })
+                .collect();
+            return Ok(Eigen {
+                values: order[..k].iter().map(|&i| values[i]).collect(),
+                vectors,
+                iterations,
+                converged,
+            });
+        }
+        iterations += 1;
+
+        // A conjugate pair uses two of the m - kept shift slots.
+        let mut q = identity(m);
+        let mut slots = m - kept;
+        for &i in &order[kept..] {
+            let shift = values[i];
+            let cost = if shift.im == 0.0 { 1 } else { 2 };
+            if shift.im < 0.0 || cost > slots {
+                continue;
+            }
+            shifted_qr_step(&mut factorization.h, &mut q, shift);
+            slots -= cost;
+        }
+        factorization.truncate(&q, kept);
+        factorization.extend(a, m);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimension::Dimension;
+    use crate::matrices::MatrixContent;
+
+    /// Block diagonal matrix with rotation-scaling blocks `[a, b; -b, a]`, whose eigenvalues
+    /// are `a +- b i`, followed by real diagonal entries, all conjugated by a dense similarity.
+    fn known_spectrum() -> (MatrixContent<f64>, Vec<Complex>) {
+        let blocks = [(3.0, 4.0), (1.0, 1.0)];
+        let reals = [-6.0, 2.0, 0.5, 0.25, -0.1, 0.05];
+        let n = 2 * blocks.len() + reals.len();
+        let mut d = MatrixContent::from_fn(Dimension::new(n as isize, n as isize), |_, _| 0.0);
+        let mut spectrum = Vec::new();
+        for (i, &(re, im)) in blocks.iter().enumerate() {
+            let k = 2 * i as isize;
+            d[(k, k)] = re;
+            d[(k + 1, k + 1)] = re;
+            d[(k, k + 1)] = im;
+            d[(k + 1, k)] = -im;
+            spectrum.push(Complex::new(re, im));
+            spectrum.push(Complex::new(re, -im));
+        }
+        for (i, &value) in reals.iter().enumerate() {
+            let k = (2 * blocks.len() + i) as isize;
+            d[(k, k)] = value;
+            spectrum.push(Complex::from(value));
+        }
+
+        // S = I + N with N strictly upper triangular, so S^-1 = I - N + N^2 - ...
+        let s = MatrixContent::from_fn(d.dimension().clone(), |row, col| match col - row {
+            0 => 1.0,
+            1 => 0.5,
+            _ => 0.0,
+        });
+        let s_inverse = MatrixContent::from_fn(d.dimension().clone(), |row, col| {
+            if col >= row {
+                (-0.5f64).powi((col - row) as i32)
+            } else {
+                0.0
+            }
+        });
+        (&(&s * &d) * &s_inverse, spectrum)
+    }
+
+    fn residual(a: &MatrixContent<f64>, value: Complex, x: &[Complex]) -> f64 {
+        let re: Vec<f64> = x.iter().map(|v| v.re).collect();
+        let im: Vec<f64> = x.iter().map(|v| v.im).collect();
+        let (mut a_re, mut a_im) = (vec![0.0; x.len()], vec![0.0; x.len()]);
+        a.apply(&re, &mut a_re);
+        a.apply(&im, &mut a_im);
+        (0..x.len())
+            .map(|i| (Complex::new(a_re[i], a_im[i]) - value * x[i]).norm_sqr())
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    #[test]
+    fn complex_pairs_of_a_nonsymmetric_matrix() {
+        let (a, spectrum) = known_spectrum();
+        let options = EigenOptions {
+            subspace: Some(8),
+            ..EigenOptions::default()
+        };
+        // By modulus the spectrum starts with -6 followed by the pair 3 +- 4i.
+        let eigen = arnoldi(&a, 3, Which::Largest, &options).unwrap();
+        assert!(eigen.converged);
+        assert_eq!(eigen.values.len(), 3);
+        assert!((eigen.values[0] - spectrum[4]).norm() < 1e-8);
+        assert!((eigen.values[1] - spectrum[0]).norm() < 1e-8);
+        assert_eq!(eigen.values[2], eigen.values[1].conj());
+        for (value, x) in eigen.values.iter().zip(&eigen.vectors) {
+            assert!(residual(&a, *value, x) < 1e-7);
+        }
+    }
+
+    #[test]
+    fn splitting_a_pair_keeps_both_halves_in_the_subspace() {
+        let (a, spectrum) = known_spectrum();
+        let eigen = arnoldi(&a, 2, Which::Largest, &EigenOptions::default()).unwrap();
+        assert!(eigen.converged);
+        assert!((eigen.values[0] - spectrum[4]).norm() < 1e-8);
+        assert!((eigen.values[1] - spectrum[0]).norm() < 1e-8);
+        assert!(residual(&a, eigen.values[1], &eigen.vectors[1]) < 1e-7);
+    }
+
+    #[test]
+    fn reports_a_missed_tolerance() {
+        let (a, _) = known_spectrum();
+        let options = EigenOptions {
+            subspace: Some(5),
+            max_iterations: 0,
+            ..EigenOptions::default()
+        };
+        let eigen = arnoldi(&a, 4, Which::Smallest, &options).unwrap();
+        assert!(!eigen.converged);
+        assert_eq!(eigen.iterations, 0);
+    }
+}
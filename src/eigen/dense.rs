@@ -0,0 +1,436 @@
+use crate::complex::Complex;
+use crate::scalar::Zero;
+
+/// Eigenvalues and eigenvectors of the symmetric tridiagonal matrix with the given diagonal and
+/// off-diagonal, by the implicit QL algorithm. Eigenvector `j` is column `j` of the returned
+/// matrix.
+///
+/// The flag is `false` if some eigenvalue did not converge within the iteration limit, in which
+/// case the returned pairs are only approximate.
+pub(super) fn tridiagonal_eigen(diagonal: &[f64], off: &[f64]) -> (Vec<f64>, Vec<Vec<f64>>, bool) {
+    let n = diagonal.len();
+    let mut d = diagonal.to_vec();
+    let mut e: Vec<f64> = off.iter().copied().chain(std::iter::once(0.0)).collect();
+    e.truncate(n);
+    let mut z = identity(n);
+    let mut deflated = true;
+
+    for l in 0..n {
+        let mut iterations = 0;
+        loop {
+            let mut m = l;
+            while m + 1 < n {
+                let scale = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= f64::EPSILON * scale {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+            if iterations == 60 {
+                deflated = false;
+                break;
+            }
+            iterations += 1;
+
+            let mut g = (d[l + 1] - d[l]) / (2.0 * e[l]);
+            let mut r = g.hypot(1.0);
+            g = d[m] - d[l] + e[l] / (g + r.copysign(g));
+            let (mut s, mut c, mut p) = (1.0, 1.0, 0.0);
+            let mut underflow = false;
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r == 0.0 {
+                    d[i + 1] -= p;
+                    e[m] = 0.0;
+                    underflow = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + 2.0 * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+                for row in z.iter_mut() {
+                    let f = row[i + 1];
+                    row[i + 1] = s * row[i] + c * f;
+                    row[i] = c * row[i] - s * f;
+                }
+            }
+            if underflow {
+                continue;
+            }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = 0.0;
+        }
+    }
+
+    (d, z, deflated)
+}
+
+/// Eigenvalues of a real upper Hessenberg matrix, by the shifted QR algorithm in complex
+/// arithmetic with Wilkinson shifts.
+///
+/// Complex eigenvalues are returned as exact conjugate pairs and real ones with a zero
+/// imaginary part. The flag is `false` if some
+/// eigenvalue did not converge within the iteration limit, in which case its diagonal entry is
+/// returned as an estimate.
+#[allow(clippy::needless_range_loop)]
+pub(super) fn hessenberg_eigenvalues(h: &[Vec<f64>]) -> (Vec<Complex>, bool) {
+    let n = h.len();
+    let mut a: Vec<Vec<Complex>> = h
+        .iter()
+        .map(|row| row.iter().map(|&v| Complex::from(v)).collect())
+        .collect();
+    let mut values = vec![Complex::zero(); n];
+    let mut deflated = true;
+
+    let mut hi = n;
+    let mut iterations = 0;
+    while hi > 0 {
+        let last = hi - 1;
+        let mut lo = last;
+        while lo > 0 {
+            let scale = a[lo - 1][lo - 1].norm() + a[lo][lo].norm();
+            if a[lo][lo - 1].norm() <= f64::EPSILON * scale {
+                a[lo][lo - 1] = Complex::zero();
+                break;
+            }
+            lo -= 1;
+        }
+        if lo == last || iterations == 100 {
+            deflated &= lo == last;
+            values[last] = a[last][last];
+            hi -= 1;
+            iterations = 0;
+            continue;
+        }
+        iterations += 1;
+
+        let shift = if iterations % 10 == 0 {
+            // Exceptional shift to break cycles.
+            a[last][last] + Complex::from(a[last][last - 1].norm())
+        } else {
+            let (p, q) = (a[last - 1][last - 1], a[last - 1][last]);
+            let (r, s) = (a[last][last - 1], a[last][last]);
+            let half = (p - s) * 0.5;
+            let root = (half * half + q * r).sqrt();
+            let (first, second) = (s + half + root, s + half - root);
+            if (first - s).norm() < (second - s).norm() {
+                first
+            } else {
+                second
+            }
+        };
+
+        for i in lo..=last {
+            a[i][i] -= shift;
+        }
+        let mut rotations = Vec::with_capacity(last - lo);
+        for k in lo..last {
+            let (c, s) = givens(a[k][k], a[k + 1][k]);
+            for j in k..=last {
+                let (x, y) = (a[k][j], a[k + 1][j]);
+                a[k][j] = x * c + s * y;
+                a[k + 1][j] = y * c - s.conj() * x;
+            }
+            rotations.push((c, s));
+        }
+        for (offset, &(c, s)) in rotations.iter().enumerate() {
+            let k = lo + offset;
+            for row in a.iter_mut().take((k + 2).min(last) + 1).skip(lo) {
+                let (x, y) = (row[k], row[k + 1]);
+                row[k] = x * c + y * s.conj();
+                row[k + 1] = y * c - x * s;
+            }
+        }
+        for i in lo..=last {
+            a[i][i] += shift;
+        }
+    }
+
+    // The spectrum of a real matrix is closed under conjugation, but rounding leaves real
+    // eigenvalues with tiny imaginary parts and the two halves of a pair slightly apart.
+    // Cleaning both up lets callers order pairs by modulus without the halves being split by
+    // noise.
+    for value in values.iter_mut() {
+        if value.im.abs() <= 1e-12 * value.norm() {
+            value.im = 0.0;
+        }
+    }
+    let mut lower: Vec<usize> = (0..n).filter(|&i| values[i].im < 0.0).collect();
+    for i in 0..n {
+        if values[i].im <= 0.0 {
+            continue;
+        }
+        let target = values[i].conj();
+        let closest = (0..lower.len()).min_by(|&x, &y| {
+            let distance = |j: usize| (values[lower[j]] - target).norm();
+            distance(x).total_cmp(&distance(y))
+        });
+        if let Some(position) = closest {
+            values[lower.swap_remove(position)] = target;
+        }
+    }
+
+    (values, deflated)
+}
+
+/// Complex Givens rotation `[c, s; -conj(s), c]` mapping `(x, y)` to `(r, 0)`.
+fn givens(x: Complex, y: Complex) -> (f64, Complex) {
+    let radius = x.norm().hypot(y.norm());
+    if radius == 0.0 {
+        (1.0, Complex::zero())
+    } else if x.norm() == 0.0 {
+        (0.0, y.conj() * (1.0 / y.norm()))
+    } else {
+        let phase = x * (1.0 / x.norm());
+        (x.norm() / radius, phase * y.conj() * (1.0 / radius))
+    }
+}
+
+/// Unit eigenvector of a real square matrix for an (approximate) eigenvalue, by inverse
+/// iteration in complex arithmetic.
+#[allow(clippy::needless_range_loop)]
+pub(super) fn eigenvector(h: &[Vec<f64>], value: Complex) -> Vec<Complex> {
+    let n = h.len();
+    let scale = h
+        .iter()
+        .flatten()
+        .fold(0.0f64, |acc, v| acc.max(v.abs()))
+        .max(f64::MIN_POSITIVE);
+    let tiny = f64::EPSILON * scale;
+
+    // LU with partial pivoting of `h - value I`, replacing exactly singular pivots by `tiny`.
+    let mut a: Vec<Vec<Complex>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    let v = Complex::from(h[i][j]);
+                    if i == j {
+                        v - value
+                    } else {
+                        v
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let mut permutation: Vec<usize> = (0..n).collect();
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&x, &y| a[x][col].norm().total_cmp(&a[y][col].norm()))
+            .unwrap_or(col);
+        a.swap(col, pivot);
+        permutation.swap(col, pivot);
+        if a[col][col].norm() < tiny {
+            a[col][col] = Complex::from(tiny);
+        }
+        for row in col + 1..n {
+            let multiplier = a[row][col] / a[col][col];
+            a[row][col] = multiplier;
+            for k in col + 1..n {
+                let update = multiplier * a[col][k];
+                a[row][k] -= update;
+            }
+        }
+    }
+
+    let mut x = vec![Complex::from(1.0); n];
+    for _ in 0..3 {
+        let mut y: Vec<Complex> = permutation.iter().map(|&i| x[i]).collect();
+        for row in 0..n {
+            for k in 0..row {
+                let update = a[row][k] * y[k];
+                y[row] -= update;
+            }
+        }
+        for row in (0..n).rev() {
+            for k in row + 1..n {
+                let update = a[row][k] * y[k];
+                y[row] -= update;
+            }
+            y[row] = y[row] / a[row][row];
+        }
+        let norm = y.iter().map(|v| v.norm_sqr()).sum::<f64>().sqrt();
+        x = y.into_iter().map(|v| v * (1.0 / norm)).collect();
+    }
+    x
+}
+
+/// One step of the explicitly shifted QR algorithm on `h`, accumulating the orthogonal factor
+/// into `q`. A complex shift is applied together with its conjugate as a real double shift.
+#[allow(clippy::needless_range_loop)]
+pub(super) fn shifted_qr_step(h: &mut [Vec<f64>], q: &mut [Vec<f64>], shift: Complex) {
+    let n = h.len();
+    let mut m: Vec<Vec<f64>> = if shift.im == 0.0 {
+        (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| if i == j { h[i][j] - shift.re } else { h[i][j] })
+                    .collect()
+            })
+            .collect()
+    } else {
+        // (H - s I)(H - conj(s) I) = H^2 - 2 Re(s) H + |s|^2 I
+        (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| {
+                        let square: f64 = (0..n).map(|k| h[i][k] * h[k][j]).sum();
+                        let diagonal = if i == j { shift.norm_sqr() } else { 0.0 };
+                        square - 2.0 * shift.re * h[i][j] + diagonal
+                    })
+                    .collect()
+            })
+            .collect()
+    };
+
+    // Householder QR of m, keeping only the orthogonal factor.
+    let mut factor = identity(n);
+    for j in 0..n.saturating_sub(1) {
+        let norm = (j..n).map(|i| m[i][j] * m[i][j]).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        let mut v: Vec<f64> = (j..n).map(|i| m[i][j]).collect();
+        v[0] += norm.copysign(v[0]);
+        let length = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        v.iter_mut().for_each(|x| *x /= length);
+
+        for col in j..n {
+            let projection: f64 = (j..n).map(|i| v[i - j] * m[i][col]).sum();
+            for i in j..n {
+                m[i][col] -= 2.0 * projection * v[i - j];
+            }
+        }
+        for row in factor.iter_mut() {
+            let projection: f64 = (j..n).map(|i| row[i] * v[i - j]).sum();
+            for i in j..n {
+                row[i] -= 2.0 * projection * v[i - j];
+            }
+        }
+    }
+
+    // h <- factor^T h factor, q <- q factor
+    let hq: Vec<Vec<f64>> = h
+        .iter()
+        .map(|row| {
+            (0..n)
+                .map(|j| (0..n).map(|k| row[k] * factor[k][j]).sum())
+                .collect()
+        })
+        .collect();
+    for i in 0..n {
+        for j in 0..n {
+            h[i][j] = if i > j + 1 {
+                0.0
+            } else {
+                (0..n).map(|k| factor[k][i] * hq[k][j]).sum()
+            };
+        }
+    }
+    for row in q.iter_mut() {
+        let updated: Vec<f64> = (0..n)
+            .map(|j| (0..n).map(|k| row[k] * factor[k][j]).sum())
+            .collect();
+        *row = updated;
+    }
+}
+
+pub(super) fn identity(n: usize) -> Vec<Vec<f64>> {
+    (0..n)
+        .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn tridiagonal_laplacian_spectrum() {
+        let n = 30;
+        let (values, vectors, deflated) = tridiagonal_eigen(&vec![2.0; n], &vec![-1.0; n - 1]);
+        assert!(deflated);
+
+        let mut sorted = values.clone();
+        sorted.sort_by(f64::total_cmp);
+        for (k, value) in sorted.iter().enumerate() {
+            let expected = 2.0 - 2.0 * ((k + 1) as f64 * PI / (n + 1) as f64).cos();
+            assert!((value - expected).abs() < 1e-12);
+        }
+
+        // Each column satisfies T z = lambda z.
+        for (j, &value) in values.iter().enumerate() {
+            for i in 0..n {
+                let mut tz = 2.0 * vectors[i][j];
+                if i > 0 {
+                    tz -= vectors[i - 1][j];
+                }
+                if i + 1 < n {
+                    tz -= vectors[i + 1][j];
+                }
+                assert!((tz - value * vectors[i][j]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn hessenberg_companion_matrix_roots() {
+        // Companion matrix of (x - 3)(x + 1)(x^2 - 2x + 5), with roots 3, -1 and 1 +- 2i.
+        let coefficients = [-15.0, -4.0, 6.0, -4.0];
+        let mut h = vec![vec![0.0; 4]; 4];
+        for i in 1..4 {
+            h[i][i - 1] = 1.0;
+        }
+        for (i, c) in coefficients.iter().enumerate() {
+            h[i][3] = -c;
+        }
+
+        let (values, deflated) = hessenberg_eigenvalues(&h);
+        assert!(deflated);
+        for root in [
+            Complex::from(3.0),
+            Complex::from(-1.0),
+            Complex::new(1.0, 2.0),
+            Complex::new(1.0, -2.0),
+        ] {
+            assert!(values.iter().any(|v| (*v - root).norm() < 1e-10), "{root}");
+        }
+
+        let upper = values.iter().find(|v| v.im > 0.5).unwrap();
+        let lower = values.iter().find(|v| v.im < -0.5).unwrap();
+        assert_eq!(*lower, upper.conj());
+        assert_eq!(values.iter().filter(|v| v.im == 0.0).count(), 2);
+    }
+
+    #[test]
+    fn inverse_iteration_eigenvector() {
+        let h = vec![
+            vec![4.0, 1.0, 2.0],
+            vec![1.0, 3.0, 0.0],
+            vec![0.0, 2.0, 1.0],
+        ];
+        let (values, _) = hessenberg_eigenvalues(&h);
+        for value in values {
+            let x = eigenvector(&h, value);
+            for (row, xi) in h.iter().zip(&x) {
+                let hx = row
+                    .iter()
+                    .zip(&x)
+                    .fold(Complex::zero(), |sum, (a, x)| sum + *x * *a);
+                assert!((hx - value * *xi).norm() < 1e-10);
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::iterative::{axpy, dot, norm};
+use crate::operator::LinearOperator;
+use crate::random::SplitMix64;
+
+/// Arnoldi factorization `A V = V H + f e^T` with orthonormal basis `V`, maintained with full
+/// reorthogonalization so that it stays accurate across implicit restarts.
+pub(super) struct Factorization {
+    pub(super) basis: Vec<Vec<f64>>,
+    /// `m` by `m` upper Hessenberg matrix, of which the leading `basis.len()` block is in use.
+    pub(super) h: Vec<Vec<f64>>,
+    pub(super) residual: Vec<f64>,
+    rng: SplitMix64,
+}
+
+impl Factorization {
+    pub(super) fn new(n: usize, m: usize, seed: u64) -> Self {
+        let mut rng = SplitMix64::new(seed);
+        let residual = (0..n).map(|_| rng.next_normal()).collect();
+        Self {
+            basis: Vec::with_capacity(m + 1),
+            h: vec![vec![0.0; m]; m],
+            residual,
+            rng,
+        }
+    }
+
+    /// Grows the factorization to `m` basis vectors.
+    pub(super) fn extend<A: LinearOperator + ?Sized>(&mut self, a: &A, m: usize) {
+        let n = self.residual.len();
+        while self.basis.len() < m {
+            let j = self.basis.len();
+            let beta = norm(&self.residual);
+            let scale = self
+                .h
+                .iter()
+                .flatten()
+                .fold(0.0f64, |acc, v| acc.max(v.abs()));
+
+            let v = if j == 0 || beta > 1e-12 * scale {
+                if j > 0 {
+                    self.h[j][j - 1] = beta;
+                }
+                self.residual.iter().map(|r| r / beta).collect()
+            } else {
+                // The basis spans an invariant subspace; continue with a fresh direction.
+                self.h[j][j - 1] = 0.0;
+                let mut v: Vec<f64> = (0..n).map(|_| self.rng.next_normal()).collect();
+                self.orthogonalize(&mut v);
+                let length = norm(&v);
+                v.iter_mut().for_each(|x| *x /= length);
+                v
+            };
+
+            let mut w = vec![0.0; n];
+            a.apply(&v, &mut w);
+            self.basis.push(v);
+            let coefficients = self.orthogonalize(&mut w);
+            for (i, c) in coefficients.into_iter().enumerate() {
+                self.h[i][j] = c;
+            }
+            self.residual = w;
+        }
+    }
+
+    /// Classical Gram-Schmidt with one reorthogonalization pass, returning the projections.
+    fn orthogonalize(&self, w: &mut [f64]) -> Vec<f64> {
+        let mut coefficients = vec![0.0; self.basis.len()];
+        for _ in 0..2 {
+            let projections: Vec<f64> = self.basis.iter().map(|v| dot(v, w)).collect();
+            for ((v, p), c) in self.basis.iter().zip(&projections).zip(&mut coefficients) {
+                axpy(-p, v, w);
+                *c += p;
+            }
+        }
+        coefficients
+    }
+
+    /// Shrinks the factorization to `k` vectors after `h` has been transformed to `q^T h q`
+    /// by shifted QR steps, so that the result is again an Arnoldi factorization.
+    pub(super) fn truncate(&mut self, q: &[Vec<f64>], k: usize) {
+        let m = self.basis.len();
+        if k >= m {
+            return;
+        }
+
+        let n = self.residual.len();
+        let rotated: Vec<Vec<f64>> = (0..=k)
+            .map(|j| {
+                let mut u = vec![0.0; n];
+                for (v, row) in self.basis.iter().zip(q) {
+                    axpy(row[j], v, &mut u);
+                }
+                u
+            })
+            .collect();
+
+        let mut residual: Vec<f64> = rotated[k].iter().map(|u| u * self.h[k][k - 1]).collect();
+        axpy(q[m - 1][k - 1], &self.residual, &mut residual);
+        self.residual = residual;
+        self.basis = rotated;
+        self.basis.truncate(k);
+        for (i, row) in self.h.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                if i >= k || j >= k {
+                    *value = 0.0;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use crate::complex::Complex;
+use crate::matrices::MisAlignment;
+use crate::operator::LinearOperator;
+
+use super::dense::{identity, shifted_qr_step, tridiagonal_eigen};
+use super::krylov::Factorization;
+use super::{EigenOptions, SymmetricEigen, Which};
+
+/// The `k` largest or smallest eigenvalues of a symmetric operator and their eigenvectors, by the
+/// implicitly restarted Lanczos method.
+///
+/// The Lanczos basis is kept with full reorthogonalization and restarted with the unwanted Ritz
+/// values as exact shifts, so only `options.subspace` vectors of length `n` are stored. The
+/// operator is assumed symmetric; this is not checked.
+pub fn lanczos<A: LinearOperator + ?Sized>(
+    a: &A,
+    k: usize,
+    which: Which,
+    options: &EigenOptions,
+) -> Result<SymmetricEigen, MisAlignment> {
+    let n = a.ncols();
+    if a.nrows() != n || k == 0 || k > n {
+        return Err(MisAlignment);
+    }
+
+    let m = options.subspace_size(n, k);
+    let mut factorization = Factorization::new(n, m, options.seed);
+    factorization.extend(a, m);
+    make_tridiagonal(&mut factorization.h);
+
+    let mut iterations = 0;
+    loop {
+        let h = &factorization.h;
+        let diagonal: Vec<f64> = (0..m).map(|i| h[i][i]).collect();
+        let off: Vec<f64> = (1..m).map(|i| h[i][i - 1]).collect();
+        let (values, ritz_vectors, deflated) = tridiagonal_eigen(&diagonal, &off);
+
+        let mut order: Vec<usize> = (0..m).collect();
+        order.sort_by(|&x, &y| match which {
+            Which::Largest => values[y].total_cmp(&values[x]),
+            Which::Smallest => values[x].total_cmp(&values[y]),
+        });
+
+        let beta = crate::iterative::norm(&factorization.residual);
+        let converged = deflated
+            && order[..k]
+                .iter()
+                .all(|&i| options.accepts(beta * ritz_vectors[m - 1][i].abs(), values[i]));
+        if converged || iterations == options.max_iterations || k == m {
+            let vectors = order[..k]
+                .iter()
+                .map(|&i| {
+                    let mut x = vec![0.0; n];
+                    for (v, row) in factorization.basis.iter().zip(&ritz_vectors) {
+                        crate::iterative::axpy(row[i], v, &mut x);
+                    }
+                    x
+                })
+                .collect();
+            return Ok(SymmetricEigen {
+                values: order[..k].iter().map(|&i| values[i]).collect(),
+                vectors,
+                iterations,
+                converged,
+            });
+        }
+        iterations += 1;
+
+        let mut q = identity(m);
+        for &i in &order[k..] {
+            shifted_qr_step(&mut factorization.h, &mut q, Complex::from(values[i]));
+        }
+        make_tridiagonal(&mut factorization.h);
+        factorization.truncate(&q, k);
+        factorization.extend(a, m);
+        make_tridiagonal(&mut factorization.h);
+    }
+}
+
+/// Drops the rounding noise outside the tridiagonal band and mirrors the subdiagonal.
+#[allow(clippy::needless_range_loop)]
+fn make_tridiagonal(h: &mut [Vec<f64>]) {
+    let m = h.len();
+    for i in 0..m {
+        for j in 0..m {
+            if i.abs_diff(j) > 1 {
+                h[i][j] = 0.0;
+            } else if j == i + 1 {
+                h[i][j] = h[j][i];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iterative::tests::convection_diffusion;
+    use std::f64::consts::PI;
+
+    fn residual<A: LinearOperator>(a: &A, value: f64, x: &[f64]) -> f64 {
+        let mut ax = vec![0.0; x.len()];
+        a.apply(x, &mut ax);
+        ax.iter()
+            .zip(x)
+            .map(|(ax, x)| (ax - value * x).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    #[test]
+    fn extreme_eigenvalues_of_a_tridiagonal_toeplitz_matrix() {
+        // Without convection the operator is tridiag(-1, 3, -1), which is symmetric with the
+        // simple eigenvalues 3 - 2 cos(j pi / (n + 1)).
+        let n = 100;
+        let a = convection_diffusion(n, 0.0);
+        let spectrum: Vec<f64> = (1..=n)
+            .map(|j| 3.0 - 2.0 * (j as f64 * PI / (n + 1) as f64).cos())
+            .collect();
+        let options = EigenOptions::default();
+
+        let largest = lanczos(&a, 4, Which::Largest, &options).unwrap();
+        assert!(largest.converged);
+        for (value, expected) in largest.values.iter().zip(spectrum.iter().rev()) {
+            assert!((value - expected).abs() < 1e-8);
+        }
+
+        let smallest = lanczos(&a, 3, Which::Smallest, &options).unwrap();
+        assert!(smallest.converged);
+        for (value, expected) in smallest.values.iter().zip(&spectrum) {
+            assert!((value - expected).abs() < 1e-8);
+        }
+
+        for (value, x) in largest.values.iter().zip(&largest.vectors) {
+            assert!(residual(&a, *value, x) < 1e-7);
+        }
+        let overlap: f64 = largest.vectors[0]
+            .iter()
+            .zip(&largest.vectors[1])
+            .map(|(x, y)| x * y)
+            .sum();
+        assert!(overlap.abs() < 1e-10);
+    }
+
+    #[test]
+    fn rejects_invalid_requests() {
+        let a = convection_diffusion(3, 0.0);
+        let options = EigenOptions::default();
+        assert!(lanczos(&a, 0, Which::Largest, &options).is_err());
+        assert!(lanczos(&a, 4, Which::Largest, &options).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+pub mod arnoldi;
+mod dense;
+mod krylov;
+pub mod lanczos;
+pub mod power;
+
+pub use arnoldi::arnoldi;
+pub use lanczos::lanczos;
+pub use power::{inverse_iteration, power_iteration};
+
+use crate::complex::Complex;
+
+/// Which end of the spectrum to compute.
+///
+/// [`lanczos`] orders the real eigenvalues of a symmetric operator by value, [`arnoldi`]
+/// orders the complex eigenvalues of a general operator by modulus. Without a shift-invert
+/// transformation, eigenvalues at the small end usually need many more restarts than those at
+/// the large end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Which {
+    Largest,
+    Smallest,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigenOptions {
+    /// An eigenpair `(l, x)` is accepted once `||A x - l x|| <= tolerance * max(|l|, eps^(2/3))`.
+    pub tolerance: f64,
+    /// Matrix-vector products for the single vector iterations, restarts for Lanczos and
+    /// Arnoldi.
+    pub max_iterations: usize,
+    /// Krylov subspace size kept between restarts, defaulting to `max(2k + 1, 20)`.
+    pub subspace: Option<usize>,
+    /// Seed of the random starting vector.
+    pub seed: u64,
+}
+
+impl Default for EigenOptions {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-10,
+            max_iterations: 1000,
+            subspace: None,
+            seed: 0,
+        }
+    }
+}
+
+impl EigenOptions {
+    fn accepts(&self, residual: f64, value: f64) -> bool {
+        residual <= self.tolerance * value.abs().max(f64::EPSILON.powf(2.0 / 3.0))
+    }
+
+    fn subspace_size(&self, n: usize, k: usize) -> usize {
+        self.subspace
+            .unwrap_or((2 * k + 1).max(20))
+            .max(k + 1)
+            .min(n)
+    }
+}
+
+/// A single real eigenpair with a unit eigenvector.
+#[derive(Debug, Clone)]
+pub struct EigenPair {
+    pub value: f64,
+    pub vector: Vec<f64>,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+/// Eigenpairs of a symmetric operator, ordered as requested by [`Which`], with orthonormal
+/// eigenvectors.
+#[derive(Debug, Clone)]
+pub struct SymmetricEigen {
+    pub values: Vec<f64>,
+    pub vectors: Vec<Vec<f64>>,
+    pub iterations: usize,
+    pub converged: bool,
+}
+
+/// Eigenpairs of a general operator, ordered as requested by [`Which`], with unit eigenvectors.
+#[derive(Debug, Clone)]
+pub struct Eigen {
+    pub values: Vec<Complex>,
+    pub vectors: Vec<Vec<Complex>>,
+    pub iterations: usize,
+    pub converged: bool,
+}
@@ -0,0 +1,173 @@
+use crate::iterative::{axpy, dot, norm};
+use crate::matrices::{MatrixContent, MisAlignment};
+use crate::operator::LinearOperator;
+use crate::random::SplitMix64;
+
+use super::{EigenOptions, EigenPair};
+
+/// Eigenvalue of largest modulus by power iteration, estimated with Rayleigh quotients.
+///
+/// Convergence is linear in the ratio of the two largest eigenvalue moduli and does not happen
+/// at all when the dominant eigenvalue is not unique, e.g. a complex conjugate pair.
+pub fn power_iteration<A: LinearOperator + ?Sized>(
+    a: &A,
+    options: &EigenOptions,
+) -> Result<EigenPair, MisAlignment> {
+    let n = a.ncols();
+    if a.nrows() != n || n == 0 {
+        return Err(MisAlignment);
+    }
+
+    let mut x = random_unit(n, options.seed);
+    let mut y = vec![0.0; n];
+    let mut iterations = 0;
+    let (value, converged) = loop {
+        a.apply(&x, &mut y);
+        iterations += 1;
+        let value = dot(&x, &y);
+        let length = norm(&y);
+        axpy(-value, &x, &mut y);
+        if length == 0.0 || options.accepts(norm(&y), value) {
+            break (value, true);
+        }
+        if iterations == options.max_iterations {
+            break (value, false);
+        }
+
+        // y holds A x - value x; rebuild A x from it.
+        axpy(value, &x, &mut y);
+        x.iter_mut().zip(&y).for_each(|(x, y)| *x = y / length);
+    };
+
+    Ok(EigenPair {
+        value,
+        vector: x,
+        iterations,
+        converged,
+    })
+}
+
+/// Eigenvalue closest to `shift` by inverse iteration with a dense LU factorization of
+/// `A - shift I`.
+///
+/// A shift that is exactly an eigenvalue is nudged slightly so that the factorization exists.
+pub fn inverse_iteration(
+    a: &MatrixContent<f64>,
+    shift: f64,
+    options: &EigenOptions,
+) -> Result<EigenPair, MisAlignment> {
+    let n = a.height();
+    if a.width() != n || n == 0 {
+        return Err(MisAlignment);
+    }
+
+    let shifted = |shift: f64| {
+        let mut m = a.clone();
+        for i in 0..n {
+            m[(i, i)] -= shift;
+        }
+        m.lu()
+    };
+    let lu = match shifted(shift) {
+        Some(lu) => lu,
+        None => shifted(shift + (shift.abs() + 1.0) * f64::EPSILON.sqrt()).ok_or(MisAlignment)?,
+    };
+
+    let mut x = random_unit(n as usize, options.seed);
+    let mut ax = vec![0.0; n as usize];
+    let mut iterations = 0;
+    let (value, converged) = loop {
+        let y = lu.solve(&x)?;
+        let length = norm(&y);
+        x = y.into_iter().map(|y| y / length).collect();
+        iterations += 1;
+
+        a.apply(&x, &mut ax);
+        let value = dot(&x, &ax);
+        axpy(-value, &x, &mut ax);
+        if options.accepts(norm(&ax), value) {
+            break (value, true);
+        }
+        if iterations == options.max_iterations {
+            break (value, false);
+        }
+    };
+
+    Ok(EigenPair {
+        value,
+        vector: x,
+        iterations,
+        converged,
+    })
+}
+
+fn random_unit(n: usize, seed: u64) -> Vec<f64> {
+    let mut rng = SplitMix64::new(seed);
+    let x: Vec<f64> = (0..n).map(|_| rng.next_normal()).collect();
+    let length = norm(&x);
+    x.into_iter().map(|x| x / length).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimension::Dimension;
+    use std::f64::consts::SQRT_2;
+
+    /// The 3 x 3 Laplacian `tridiag(1, 2, 1)`, with eigenvalues `2 - sqrt 2`, `2` and `2 + sqrt 2`.
+    fn laplacian() -> MatrixContent<f64> {
+        MatrixContent::new(
+            Dimension::new(3, 3),
+            vec![2.0, 1.0, 0.0, 1.0, 2.0, 1.0, 0.0, 1.0, 2.0],
+        )
+    }
+
+    fn residual(a: &MatrixContent<f64>, pair: &EigenPair) -> f64 {
+        let mut ax = vec![0.0; pair.vector.len()];
+        a.apply(&pair.vector, &mut ax);
+        axpy(-pair.value, &pair.vector, &mut ax);
+        norm(&ax)
+    }
+
+    #[test]
+    fn power_iteration_finds_the_dominant_eigenvalue() {
+        let a = laplacian();
+        let pair = power_iteration(&a, &EigenOptions::default()).unwrap();
+        assert!(pair.converged);
+        assert!((pair.value - (2.0 + SQRT_2)).abs() < 1e-9);
+        assert!(residual(&a, &pair) < 1e-9);
+        assert!((norm(&pair.vector) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn inverse_iteration_finds_the_closest_eigenvalue() {
+        let a = laplacian();
+        let options = EigenOptions::default();
+        // The last shift is exactly an eigenvalue, so the factorization must be nudged.
+        for (shift, expected) in [
+            (0.0, 2.0 - SQRT_2),
+            (2.1, 2.0),
+            (4.0, 2.0 + SQRT_2),
+            (2.0, 2.0),
+        ] {
+            let pair = inverse_iteration(&a, shift, &options).unwrap();
+            assert!(pair.converged, "shift {shift}");
+            assert!((pair.value - expected).abs() < 1e-9, "shift {shift}");
+            assert!(residual(&a, &pair) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn reports_a_missed_tolerance() {
+        // A rotation has no dominant real eigenvalue.
+        let rotation = MatrixContent::new(Dimension::new(2, 2), vec![0.0, -1.0, 1.0, 0.0]);
+        let options = EigenOptions {
+            max_iterations: 50,
+            ..EigenOptions::default()
+        };
+        let pair = power_iteration(&rotation, &options).unwrap();
+        assert!(!pair.converged);
+        assert_eq!(pair.iterations, 50);
+        assert!(power_iteration(&MatrixContent::<f64>::identity(0), &options).is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use std::f64::consts::PI;
+
+use crate::complex::Complex;
+
+/// Forward DFT, `X_k = sum_j x_j e^(-2 pi i jk / n)`.
+pub fn fft(input: &[Complex]) -> Vec<Complex> {
+    transform(input, false)
+}
+
+/// Inverse DFT including the `1 / n` normalization.
+pub fn ifft(input: &[Complex]) -> Vec<Complex> {
+    let scale = 1.0 / input.len().max(1) as f64;
+    transform(input, true)
+        .into_iter()
+        .map(|v| v * scale)
+        .collect()
+}
+
+fn transform(input: &[Complex], inverse: bool) -> Vec<Complex> {
+    let n = input.len();
+    if n <= 1 {
+        return input.to_vec();
+    }
+    if n.is_power_of_two() {
+        let mut data = input.to_vec();
+        radix2(&mut data, inverse);
+        data
+    } else {
+        bluestein(input, inverse)
+    }
+}
+
+/// In-place iterative Cooley-Tukey transform; `data.len()` must be a power of two.
+fn radix2(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut length = 2;
+    while length <= n {
+        let step = Complex::from_polar(1.0, sign * 2.0 * PI / length as f64);
+        for start in (0..n).step_by(length) {
+            let mut twiddle = Complex::new(1.0, 0.0);
+            for k in 0..length / 2 {
+                let even = data[start + k];
+                let odd = data[start + k + length / 2] * twiddle;
+                data[start + k] = even + odd;
+                data[start + k + length / 2] = even - odd;
+                twiddle *= step;
+            }
+        }
+        length <<= 1;
+    }
+}
+
+/// Bluestein's chirp-z algorithm, expressing a DFT of any length as a power-of-two convolution.
+fn bluestein(input: &[Complex], inverse: bool) -> Vec<Complex> {
+    let n = input.len();
+    let m = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { 1.0 } else { -1.0 };
+
+    // Reducing j^2 modulo 2n keeps the chirp angles small and accurate for long inputs.
+    let chirp: Vec<Complex> = (0..n)
+        .map(|j| {
+            let square = (j * j) % (2 * n);
+            Complex::from_polar(1.0, sign * PI * square as f64 / n as f64)
+        })
+        .collect();
+
+    let mut a = vec![Complex::default(); m];
+    for j in 0..n {
+        a[j] = input[j] * chirp[j];
+    }
+    let mut b = vec![Complex::default(); m];
+    b[0] = chirp[0].conj();
+    for j in 1..n {
+        b[j] = chirp[j].conj();
+        b[m - j] = chirp[j].conj();
+    }
+
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    let mut product: Vec<Complex> = a.iter().zip(&b).map(|(x, y)| *x * *y).collect();
+    radix2(&mut product, true);
+
+    let scale = 1.0 / m as f64;
+    (0..n).map(|k| product[k] * chirp[k] * scale).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::SplitMix64;
+
+    fn naive_dft(input: &[Complex]) -> Vec<Complex> {
+        let n = input.len();
+        (0..n)
+            .map(|k| {
+                input
+                    .iter()
+                    .enumerate()
+                    .fold(Complex::default(), |sum, (j, x)| {
+                        let angle = -2.0 * PI * ((j * k) % n) as f64 / n as f64;
+                        sum + *x * Complex::from_polar(1.0, angle)
+                    })
+            })
+            .collect()
+    }
+
+    fn random_signal(rng: &mut SplitMix64, n: usize) -> Vec<Complex> {
+        (0..n)
+            .map(|_| Complex::new(rng.next_normal(), rng.next_normal()))
+            .collect()
+    }
+
+    fn max_difference(a: &[Complex], b: &[Complex]) -> f64 {
+        assert_eq!(a.len(), b.len());
+        a.iter()
+            .zip(b)
+            .map(|(a, b)| (*a - *b).norm())
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn matches_the_naive_transform() {
+        let mut rng = SplitMix64::new(34);
+        // Powers of two take the radix-2 path, everything else goes through Bluestein.
+        for n in [1, 2, 3, 5, 8, 12, 17, 64, 100] {
+            let signal = random_signal(&mut rng, n);
+            assert!(
+                max_difference(&fft(&signal), &naive_dft(&signal)) < 1e-9,
+                "n = {n}"
+            );
+        }
+    }
+
+    #[test]
+    fn inverse_round_trip() {
+        let mut rng = SplitMix64::new(35);
+        for n in [0, 1, 16, 31, 1000] {
+            let signal = random_signal(&mut rng, n);
+            assert!(
+                max_difference(&ifft(&fft(&signal)), &signal) < 1e-10,
+                "n = {n}"
+            );
+        }
+    }
+
+    #[test]
+    fn impulse_has_a_flat_spectrum() {
+        let mut impulse = vec![Complex::default(); 6];
+        impulse[0] = Complex::from(1.0);
+        assert!(max_difference(&fft(&impulse), &[Complex::from(1.0); 6]) < 1e-12);
+    }
+}
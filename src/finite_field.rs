@@ -0,0 +1,284 @@
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::scalar::{Field, One, Zero};
+
+/// Element of the prime field GF(P).
+///
+/// Arithmetic is carried out in `u128` so any prime modulus that fits in a `u64` is supported.
+/// A modulus that is not prime is rejected at compile time, as division would not be defined:
+///
+/// ```compile_fail
+/// let _ = num_rust::finite_field::Fp::<4>::new(1);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fp<const P: u64>(u64);
+
+const fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+const fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Miller-Rabin test with the first twelve primes as witnesses, which is exact for every `u64`.
+const fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        if n.is_multiple_of(WITNESSES[i]) {
+            return n == WITNESSES[i];
+        }
+        i += 1;
+    }
+
+    let shift = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> shift;
+    let mut i = 0;
+    while i < WITNESSES.len() {
+        let mut x = pow_mod(WITNESSES[i], odd, n);
+        let mut round = 1;
+        while x != 1 && x != n - 1 && round < shift {
+            x = mul_mod(x, x, n);
+            round += 1;
+        }
+        if x != n - 1 && (x != 1 || round > 1) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl<const P: u64> Fp<P> {
+    /// `P`, checked when the type is first used.
+    const MODULUS: u64 = {
+        assert!(is_prime(P), "the modulus of a finite field must be prime");
+        P
+    };
+
+    pub fn new(value: u64) -> Self {
+        Self(value % Self::MODULUS)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = Self::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Multiplicative inverse by Fermat's little theorem, or `None` for zero.
+    pub fn inverse(self) -> Option<Self> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.pow(P - 2))
+        }
+    }
+}
+
+impl<const P: u64> From<u64> for Fp<P> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64> From<i64> for Fp<P> {
+    fn from(value: i64) -> Self {
+        let residue = value.unsigned_abs() % Self::MODULUS;
+        if value < 0 && residue != 0 {
+            Self(P - residue)
+        } else {
+            Self(residue)
+        }
+    }
+}
+
+impl<const P: u64> Default for Fp<P> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const P: u64> std::fmt::Display for Fp<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<const P: u64> std::fmt::Debug for Fp<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (mod {})", self.0, P)
+    }
+}
+
+impl<const P: u64> Add for Fp<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for Fp<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<const P: u64> Neg for Fp<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        if self.0 == 0 {
+            self
+        } else {
+            Self(P - self.0)
+        }
+    }
+}
+
+impl<const P: u64> Mul for Fp<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(((self.0 as u128 * rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Div for Fp<P> {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs
+            .inverse()
+            .expect("attempt to divide by zero in a finite field")
+    }
+}
+
+impl<const P: u64> AddAssign for Fp<P> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<'a, const P: u64> AddAssign<&'a Fp<P>> for Fp<P> {
+    fn add_assign(&mut self, rhs: &'a Fp<P>) {
+        *self = *self + *rhs;
+    }
+}
+
+impl<const P: u64> SubAssign for Fp<P> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const P: u64> MulAssign for Fp<P> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const P: u64> Zero for Fp<P> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const P: u64> One for Fp<P> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const P: u64> Field for Fp<P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type F7 = Fp<7>;
+    const LARGE: u64 = u64::MAX - 58;
+
+    #[test]
+    fn arithmetic_wraps_around_the_modulus() {
+        assert_eq!(F7::new(5) + F7::new(4), F7::new(2));
+        assert_eq!(F7::new(2) - F7::new(5), F7::new(4));
+        assert_eq!(F7::new(3) * F7::new(5), F7::new(1));
+        assert_eq!(-F7::new(0), F7::new(0));
+        assert_eq!(F7::new(3).pow(6), F7::one());
+        for value in 1..7 {
+            let a = F7::new(value);
+            assert_eq!(a * a.inverse().unwrap(), F7::one());
+            assert_eq!(F7::one() / a * a, F7::one());
+        }
+        assert!(F7::zero().inverse().is_none());
+    }
+
+    #[test]
+    fn negative_integers_map_to_their_residue() {
+        assert_eq!(F7::from(-1i64), F7::new(6));
+        assert_eq!(F7::from(-14i64), F7::zero());
+        assert_eq!(
+            F7::from(i64::MIN).value(),
+            (i64::MIN as i128).rem_euclid(7) as u64
+        );
+        assert_eq!(Fp::<LARGE>::from(-1i64).value(), LARGE - 1);
+        assert_eq!(Fp::<LARGE>::from(i64::MIN).value(), LARGE - (1u64 << 63));
+        assert_eq!(Fp::<LARGE>::from(i64::MAX).value(), i64::MAX as u64);
+    }
+
+    #[test]
+    fn primality_of_moduli() {
+        let primes: Vec<u64> = (0..60).filter(|&n| is_prime(n)).collect();
+        assert_eq!(
+            primes,
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59]
+        );
+        assert!(is_prime(LARGE));
+        assert!(is_prime(1_000_000_007));
+        // Strong pseudoprimes to several of the smaller witnesses.
+        assert!(!is_prime(3_215_031_751));
+        assert!(!is_prime(3_825_123_056_546_413_051));
+        assert!(!is_prime(LARGE - 2));
+        assert!(!is_prime(u64::MAX));
+    }
+
+    #[test]
+    fn large_moduli_do_not_overflow() {
+        let a = Fp::<LARGE>::new(LARGE - 1);
+        assert_eq!(a + a, Fp::new(LARGE - 2));
+        assert_eq!(a * a, Fp::one());
+        assert_eq!(a * a.inverse().unwrap(), Fp::one());
+    }
+}
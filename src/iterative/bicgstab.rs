@@ -0,0 +1,131 @@
+use crate::matrices::MisAlignment;
+use crate::operator::LinearOperator;
+
+use super::{
+    axpy, dot, initialize, norm, reference_norm, IdentityPreconditioner, Preconditioner, Solution,
+    SolverOptions, StopReason,
+};
+
+/// Biconjugate gradient stabilized method for general square operators.
+pub fn bicgstab<A: LinearOperator + ?Sized>(
+    a: &A,
+    b: &[f64],
+    x0: Option<&[f64]>,
+    options: &SolverOptions,
+) -> Result<Solution, MisAlignment> {
+    pbicgstab(a, &IdentityPreconditioner, b, x0, options)
+}
+
+/// Right preconditioned BiCGSTAB, solving `A M^-1 u = b` with `x = M^-1 u` so that the recorded
+/// residuals are those of the original system.
+pub fn pbicgstab<A: LinearOperator + ?Sized, M: Preconditioner + ?Sized>(
+    a: &A,
+    m: &M,
+    b: &[f64],
+    x0: Option<&[f64]>,
+    options: &SolverOptions,
+) -> Result<Solution, MisAlignment> {
+    let (mut x, mut r) = initialize(a, b, x0)?;
+    let n = r.len();
+    let scale = reference_norm(b);
+    let shadow = r.clone();
+    let (mut rho, mut alpha, mut omega) = (1.0, 1.0, 1.0);
+    let mut p = vec![0.0; n];
+    let mut v = vec![0.0; n];
+    let mut t = vec![0.0; n];
+    let mut preconditioned = vec![0.0; n];
+    let mut residual_history = vec![norm(&r) / scale];
+
+    let mut iterations = 0;
+    let stop_reason = loop {
+        if residual_history.last().copied().unwrap_or(0.0) <= options.tolerance {
+            break StopReason::Converged;
+        }
+        if iterations == options.max_iterations {
+            break StopReason::MaxIterations;
+        }
+
+        let next_rho = dot(&shadow, &r);
+        if next_rho == 0.0 || omega == 0.0 {
+            break StopReason::Breakdown;
+        }
+        let beta = (next_rho / rho) * (alpha / omega);
+        rho = next_rho;
+        for i in 0..n {
+            p[i] = r[i] + beta * (p[i] - omega * v[i]);
+        }
+
+        m.apply(&p, &mut preconditioned);
+        a.apply(&preconditioned, &mut v);
+        let denominator = dot(&shadow, &v);
+        if denominator == 0.0 {
+            break StopReason::Breakdown;
+        }
+        alpha = rho / denominator;
+
+        // `r` now holds the intermediate residual `s = r - alpha v`.
+        axpy(-alpha, &v, &mut r);
+        axpy(alpha, &preconditioned, &mut x);
+        iterations += 1;
+        if norm(&r) / scale <= options.tolerance {
+            residual_history.push(norm(&r) / scale);
+            continue;
+        }
+
+        m.apply(&r, &mut preconditioned);
+        a.apply(&preconditioned, &mut t);
+        let tt = dot(&t, &t);
+        if tt == 0.0 {
+            residual_history.push(norm(&r) / scale);
+            break StopReason::Breakdown;
+        }
+        omega = dot(&t, &r) / tt;
+        axpy(omega, &preconditioned, &mut x);
+        axpy(-omega, &t, &mut r);
+        residual_history.push(norm(&r) / scale);
+    };
+
+    Ok(Solution {
+        x,
+        residual_history,
+        iterations,
+        stop_reason,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iterative::tests::{convection_diffusion, poisson, relative_residual, rhs};
+
+    #[test]
+    fn solves_a_nonsymmetric_system() {
+        let a = convection_diffusion(200, 0.5);
+        let b = rhs(200);
+        let solution = bicgstab(&a, &b, None, &SolverOptions::default()).unwrap();
+        assert!(solution.converged());
+        assert!(relative_residual(&a, &solution.x, &b) < 1e-9);
+        assert_eq!(solution.residual_history.len(), solution.iterations + 1);
+    }
+
+    #[test]
+    fn solves_a_symmetric_system() {
+        let a = poisson(10);
+        let b = rhs(100);
+        let solution = bicgstab(&a, &b, None, &SolverOptions::default()).unwrap();
+        assert!(solution.converged());
+        assert!(relative_residual(&a, &solution.x, &b) < 1e-9);
+    }
+
+    #[test]
+    fn stops_at_the_iteration_budget() {
+        let options = SolverOptions {
+            max_iterations: 2,
+            ..SolverOptions::default()
+        };
+        let solution =
+            bicgstab(&convection_diffusion(100, 0.5), &rhs(100), None, &options).unwrap();
+        assert_eq!(solution.stop_reason, StopReason::MaxIterations);
+        assert_eq!(solution.iterations, 2);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::matrices::MisAlignment;
+use crate::operator::LinearOperator;
+
+use super::{
+    axpy, dot, initialize, norm, reference_norm, IdentityPreconditioner, Preconditioner, Solution,
+    SolverOptions, StopReason,
+};
+
+/// Conjugate gradient method for symmetric positive definite operators.
+pub fn cg<A: LinearOperator + ?Sized>(
+    a: &A,
+    b: &[f64],
+    x0: Option<&[f64]>,
+    options: &SolverOptions,
+) -> Result<Solution, MisAlignment> {
+    pcg(a, &IdentityPreconditioner, b, x0, options)
+}
+
+/// Preconditioned conjugate gradient method. The preconditioner must be symmetric positive
+/// definite as well.
+pub fn pcg<A: LinearOperator + ?Sized, M: Preconditioner + ?Sized>(
+    a: &A,
+    m: &M,
+    b: &[f64],
+    x0: Option<&[f64]>,
+    options: &SolverOptions,
+) -> Result<Solution, MisAlignment> {
+    let (mut x, mut r) = initialize(a, b, x0)?;
+    let scale = reference_norm(b);
+    let mut z = vec![0.0; r.len()];
+    m.apply(&r, &mut z);
+    let mut p = z.clone();
+    let mut ap = vec![0.0; r.len()];
+    let mut rz = dot(&r, &z);
+    let mut residual_history = vec![norm(&r) / scale];
+
+    let mut iterations = 0;
+    let stop_reason = loop {
+        if residual_history.last().copied().unwrap_or(0.0) <= options.tolerance {
+            break StopReason::Converged;
+        }
+        if iterations == options.max_iterations {
+            break StopReason::MaxIterations;
+        }
+
+        a.apply(&p, &mut ap);
+        let curvature = dot(&p, &ap);
+        if curvature <= 0.0 {
+            break StopReason::Breakdown;
+        }
+
+        let alpha = rz / curvature;
+        axpy(alpha, &p, &mut x);
+        axpy(-alpha, &ap, &mut r);
+        m.apply(&r, &mut z);
+        let next_rz = dot(&r, &z);
+        let beta = next_rz / rz;
+        p.iter_mut().zip(&z).for_each(|(p, z)| *p = z + beta * *p);
+        rz = next_rz;
+
+        iterations += 1;
+        residual_history.push(norm(&r) / scale);
+    };
+
+    Ok(Solution {
+        x,
+        residual_history,
+        iterations,
+        stop_reason,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimension::Dimension;
+    use crate::iterative::tests::{poisson, relative_residual, rhs};
+    use crate::matrices::MatrixContent;
+
+    #[test]
+    fn solves_the_poisson_problem() {
+        let a = poisson(12);
+        let b = rhs(144);
+        let solution = cg(&a, &b, None, &SolverOptions::default()).unwrap();
+        assert!(solution.converged());
+        assert!(relative_residual(&a, &solution.x, &b) < 1e-9);
+        assert_eq!(solution.residual_history.len(), solution.iterations + 1);
+        // In exact arithmetic CG terminates within n steps.
+        assert!(solution.iterations < 144);
+    }
+
+    #[test]
+    fn starting_from_the_solution_needs_no_iterations() {
+        let a = poisson(5);
+        let b = rhs(25);
+        let options = SolverOptions::default();
+        let x = cg(&a, &b, None, &options).unwrap().x;
+        let restarted = cg(&a, &b, Some(&x), &options).unwrap();
+        assert!(restarted.converged());
+        assert_eq!(restarted.iterations, 0);
+    }
+
+    #[test]
+    fn stops_at_the_iteration_budget() {
+        let a = poisson(10);
+        let options = SolverOptions {
+            max_iterations: 3,
+            ..SolverOptions::default()
+        };
+        let solution = cg(&a, &rhs(100), None, &options).unwrap();
+        assert_eq!(solution.stop_reason, StopReason::MaxIterations);
+        assert_eq!(solution.iterations, 3);
+    }
+
+    #[test]
+    fn breaks_down_on_indefinite_operators() {
+        let a = MatrixContent::new(Dimension::new(2, 2), vec![1.0, 0.0, 0.0, -1.0]);
+        let solution = cg(&a, &[1.0, 1.0], None, &SolverOptions::default()).unwrap();
+        assert_eq!(solution.stop_reason, StopReason::Breakdown);
+    }
+}
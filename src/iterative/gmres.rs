@@ -0,0 +1,181 @@
+use crate::matrices::MisAlignment;
+use crate::operator::LinearOperator;
+
+use super::{
+    axpy, dot, initialize, norm, reference_norm, IdentityPreconditioner, Preconditioner, Solution,
+    SolverOptions, StopReason,
+};
+
+/// Restarted GMRES(m) for general square operators, with `m = options.restart`.
+///
+/// The Arnoldi basis is orthogonalized with modified Gram-Schmidt and the least-squares problem
+/// is updated with Givens rotations, so the residual norm is known at every step without
+/// forming the iterate.
+pub fn gmres<A: LinearOperator + ?Sized>(
+    a: &A,
+    b: &[f64],
+    x0: Option<&[f64]>,
+    options: &SolverOptions,
+) -> Result<Solution, MisAlignment> {
+    pgmres(a, &IdentityPreconditioner, b, x0, options)
+}
+
+/// Right preconditioned GMRES(m): the Krylov space is built for `A M^-1`, so the residual
+/// estimates are those of the original system.
+pub fn pgmres<A: LinearOperator + ?Sized, M: Preconditioner + ?Sized>(
+    a: &A,
+    m: &M,
+    b: &[f64],
+    x0: Option<&[f64]>,
+    options: &SolverOptions,
+) -> Result<Solution, MisAlignment> {
+    let (mut x, mut r) = initialize(a, b, x0)?;
+    let n = r.len();
+    let restart = options.restart.max(1);
+    let scale = reference_norm(b);
+    let mut residual_history = vec![norm(&r) / scale];
+    let mut iterations = 0;
+    let mut preconditioned = vec![0.0; n];
+
+    let stop_reason = loop {
+        let beta = norm(&r);
+        if beta / scale <= options.tolerance {
+            break StopReason::Converged;
+        }
+        if iterations == options.max_iterations {
+            break StopReason::MaxIterations;
+        }
+
+        let mut basis: Vec<Vec<f64>> = vec![r.iter().map(|v| v / beta).collect()];
+        let mut h = vec![vec![0.0; restart]; restart + 1];
+        let mut rotations: Vec<(f64, f64)> = Vec::with_capacity(restart);
+        let mut g = vec![0.0; restart + 1];
+        g[0] = beta;
+
+        let mut size = 0;
+        // Set when the Krylov space stops growing, either because it is invariant (a happy
+        // breakdown) or because the rotated Hessenberg column vanished.
+        let mut breakdown = false;
+        while size < restart && iterations < options.max_iterations {
+            let j = size;
+            let mut w = vec![0.0; n];
+            m.apply(&basis[j], &mut preconditioned);
+            a.apply(&preconditioned, &mut w);
+            for (i, v) in basis.iter().enumerate() {
+                h[i][j] = dot(&w, v);
+                axpy(-h[i][j], v, &mut w);
+            }
+            h[j + 1][j] = norm(&w);
+            let subdiagonal = h[j + 1][j];
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (upper, lower) = (h[i][j], h[i + 1][j]);
+                h[i][j] = c * upper + s * lower;
+                h[i + 1][j] = -s * upper + c * lower;
+            }
+            let radius = h[j][j].hypot(h[j + 1][j]);
+            if radius == 0.0 {
+                breakdown = true;
+                break;
+            }
+            let (c, s) = (h[j][j] / radius, h[j + 1][j] / radius);
+            rotations.push((c, s));
+            h[j][j] = radius;
+            h[j + 1][j] = 0.0;
+            g[j + 1] = -s * g[j];
+            g[j] *= c;
+
+            size += 1;
+            iterations += 1;
+            residual_history.push(g[j + 1].abs() / scale);
+
+            if g[j + 1].abs() / scale <= options.tolerance || subdiagonal == 0.0 {
+                breakdown = subdiagonal == 0.0;
+                break;
+            }
+            basis.push(w.iter().map(|v| v / subdiagonal).collect());
+        }
+
+        // Back substitution on the rotated Hessenberg matrix gives the update coefficients.
+        let mut y = vec![0.0; size];
+        for i in (0..size).rev() {
+            let sum: f64 = (i + 1..size).map(|k| h[i][k] * y[k]).sum();
+            y[i] = (g[i] - sum) / h[i][i];
+        }
+        let mut update = vec![0.0; n];
+        for (coefficient, v) in y.iter().zip(&basis) {
+            axpy(*coefficient, v, &mut update);
+        }
+        m.apply(&update, &mut preconditioned);
+        axpy(1.0, &preconditioned, &mut x);
+
+        a.apply(&x, &mut r);
+        r.iter_mut().zip(b).for_each(|(r, b)| *r = b - *r);
+        if breakdown && norm(&r) / scale > options.tolerance {
+            break StopReason::Breakdown;
+        }
+    };
+
+    Ok(Solution {
+        x,
+        residual_history,
+        iterations,
+        stop_reason,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimension::Dimension;
+    use crate::iterative::tests::{convection_diffusion, relative_residual, rhs};
+    use crate::matrices::MatrixContent;
+
+    #[test]
+    fn solves_a_nonsymmetric_system_across_restarts() {
+        let a = convection_diffusion(100, 1.0);
+        let b = rhs(100);
+        for restart in [5, 30, 100] {
+            let options = SolverOptions {
+                restart,
+                max_iterations: 5000,
+                ..SolverOptions::default()
+            };
+            let solution = gmres(&a, &b, None, &options).unwrap();
+            assert!(solution.converged(), "restart = {restart}");
+            assert!(relative_residual(&a, &solution.x, &b) < 1e-9);
+            // Every step minimizes the residual over a space containing the previous iterate.
+            assert!(solution
+                .residual_history
+                .windows(2)
+                .all(|pair| pair[1] <= pair[0] * (1.0 + 1e-12)));
+        }
+    }
+
+    #[test]
+    fn invariant_krylov_space_converges_early() {
+        // Two distinct eigenvalues give a Krylov space of dimension two.
+        let a = MatrixContent::from_fn(Dimension::new(6, 6), |row, col| match (row, col) {
+            (row, col) if row != col => 0.0,
+            (row, _) if row < 3 => 2.0,
+            _ => 5.0,
+        });
+        let b = rhs(6);
+        let solution = gmres(&a, &b, None, &SolverOptions::default()).unwrap();
+        assert!(solution.converged());
+        assert_eq!(solution.iterations, 2);
+        assert!(relative_residual(&a, &solution.x, &b) < 1e-12);
+    }
+
+    #[test]
+    fn breakdown_keeps_the_partial_update() {
+        // The Krylov space of b = e1 is the whole plane, on which A is singular; GMRES breaks
+        // down in its second step but the first step's least-squares update is still optimal.
+        let a = MatrixContent::new(Dimension::new(2, 2), vec![1.0, 1.0, 1.0, 1.0]);
+        let b = [1.0, 0.0];
+        let solution = gmres(&a, &b, None, &SolverOptions::default()).unwrap();
+        assert_eq!(solution.stop_reason, StopReason::Breakdown);
+        assert!((solution.x[0] - 0.5).abs() < 1e-12 && solution.x[1].abs() < 1e-12);
+        assert!((relative_residual(&a, &solution.x, &b) - 0.5f64.sqrt()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,195 @@
+pub mod bicgstab;
+pub mod cg;
+pub mod gmres;
+pub mod preconditioner;
+
+pub use bicgstab::{bicgstab, pbicgstab};
+pub use cg::{cg, pcg};
+pub use gmres::{gmres, pgmres};
+pub use preconditioner::{
+    IdentityPreconditioner, IncompleteCholesky, IncompleteLu, Jacobi, Preconditioner, Ssor,
+};
+
+use crate::matrices::MisAlignment;
+use crate::operator::LinearOperator;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolverOptions {
+    /// Stop once `||b - A x|| <= tolerance * ||b||`.
+    pub tolerance: f64,
+    pub max_iterations: usize,
+    /// Krylov subspace size between GMRES restarts.
+    pub restart: usize,
+}
+
+impl Default for SolverOptions {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-10,
+            max_iterations: 1000,
+            restart: 30,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /// The relative residual dropped below the tolerance.
+    Converged,
+    /// The iteration budget ran out before converging.
+    MaxIterations,
+    /// A division by zero made the method unable to continue, e.g. CG on an indefinite
+    /// operator or a vanishing BiCGSTAB inner product.
+    Breakdown,
+}
+
+#[derive(Debug, Clone)]
+pub struct Solution {
+    pub x: Vec<f64>,
+    /// Relative residual `||r|| / ||b||` before the first iteration and after each one.
+    pub residual_history: Vec<f64>,
+    pub iterations: usize,
+    pub stop_reason: StopReason,
+}
+
+impl Solution {
+    pub fn converged(&self) -> bool {
+        self.stop_reason == StopReason::Converged
+    }
+}
+
+/// Checks shapes and returns the starting guess and the initial residual `b - A x0`.
+fn initialize<A: LinearOperator + ?Sized>(
+    a: &A,
+    b: &[f64],
+    x0: Option<&[f64]>,
+) -> Result<(Vec<f64>, Vec<f64>), MisAlignment> {
+    let n = a.ncols();
+    if a.nrows() != n || b.len() != n || x0.is_some_and(|x0| x0.len() != n) {
+        return Err(MisAlignment);
+    }
+
+    let x = x0.map_or_else(|| vec![0.0; n], <[f64]>::to_vec);
+    let mut r = vec![0.0; n];
+    a.apply(&x, &mut r);
+    r.iter_mut().zip(b).for_each(|(r, b)| *r = b - *r);
+    Ok((x, r))
+}
+
+pub(crate) fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+pub(crate) fn norm(a: &[f64]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// `y += alpha * x`
+pub(crate) fn axpy(alpha: f64, x: &[f64], y: &mut [f64]) {
+    y.iter_mut().zip(x).for_each(|(y, x)| *y += alpha * x);
+}
+
+/// Norm used to turn residuals into relative residuals; a zero right hand side falls back to
+/// absolute residuals.
+fn reference_norm(b: &[f64]) -> f64 {
+    let norm = norm(b);
+    if norm == 0.0 {
+        1.0
+    } else {
+        norm
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::matrices::sparse::CsrMatrix;
+
+    /// Five-point Laplacian on a `k x k` grid with Dirichlet boundaries, which is symmetric
+    /// positive definite.
+    pub(crate) fn poisson(k: usize) -> CsrMatrix<f64> {
+        let mut triplets = Vec::new();
+        for i in 0..k {
+            for j in 0..k {
+                let row = i * k + j;
+                triplets.push((row, row, 4.0));
+                if i > 0 {
+                    triplets.push((row, row - k, -1.0));
+                }
+                if i + 1 < k {
+                    triplets.push((row, row + k, -1.0));
+                }
+                if j > 0 {
+                    triplets.push((row, row - 1, -1.0));
+                }
+                if j + 1 < k {
+                    triplets.push((row, row + 1, -1.0));
+                }
+            }
+        }
+        CsrMatrix::from_triplets(k * k, k * k, &triplets).unwrap()
+    }
+
+    /// Upwinded one-dimensional convection-diffusion-reaction operator, which is not symmetric
+    /// but strictly diagonally dominant.
+    pub(crate) fn convection_diffusion(n: usize, convection: f64) -> CsrMatrix<f64> {
+        let mut triplets = Vec::new();
+        for i in 0..n {
+            triplets.push((i, i, 3.0 + convection));
+            if i > 0 {
+                triplets.push((i, i - 1, -1.0 - convection));
+            }
+            if i + 1 < n {
+                triplets.push((i, i + 1, -1.0));
+            }
+        }
+        CsrMatrix::from_triplets(n, n, &triplets).unwrap()
+    }
+
+    pub(crate) fn rhs(n: usize) -> Vec<f64> {
+        (0..n).map(|i| (i as f64 * 0.37).sin() + 1.0).collect()
+    }
+
+    /// `||b - A x|| / ||b||`, computed independently of the solvers' own bookkeeping.
+    pub(crate) fn relative_residual<A: LinearOperator + ?Sized>(
+        a: &A,
+        x: &[f64],
+        b: &[f64],
+    ) -> f64 {
+        let mut ax = vec![0.0; b.len()];
+        a.apply(x, &mut ax);
+        let r: Vec<f64> = b.iter().zip(&ax).map(|(b, ax)| b - ax).collect();
+        norm(&r) / norm(b)
+    }
+
+    #[test]
+    fn initialize_checks_shapes() {
+        let a = poisson(3);
+        assert!(initialize(&a, &[1.0; 8], None).is_err());
+        assert!(initialize(&a, &[1.0; 9], Some(&[0.0; 8])).is_err());
+        let wide = CsrMatrix::from_triplets(2, 3, &[(0, 0, 1.0)]).unwrap();
+        assert!(initialize(&wide, &[1.0; 2], None).is_err());
+
+        let (x, r) = initialize(&a, &[1.0; 9], Some(&[1.0; 9])).unwrap();
+        assert_eq!(x, vec![1.0; 9]);
+        // Row sums of the Laplacian are 4 minus the number of neighbours.
+        assert_eq!(r[4], 1.0);
+        assert_eq!(r[0], -1.0);
+    }
+
+    #[test]
+    fn zero_rhs_is_solved_without_iterating() {
+        let a = poisson(4);
+        let options = SolverOptions::default();
+        for solution in [
+            cg(&a, &[0.0; 16], None, &options).unwrap(),
+            bicgstab(&a, &[0.0; 16], None, &options).unwrap(),
+            gmres(&a, &[0.0; 16], None, &options).unwrap(),
+        ] {
+            assert!(solution.converged());
+            assert_eq!(solution.iterations, 0);
+            assert_eq!(solution.x, vec![0.0; 16]);
+            assert_eq!(solution.residual_history, vec![0.0]);
+        }
+    }
+}
@@ -0,0 +1,399 @@
+use crate::matrices::sparse::CsrMatrix;
+
+/// Approximate inverse `M^-1` of an operator, applied to residuals by the preconditioned solvers.
+pub trait Preconditioner {
+    /// Writes `M^-1 r` into `z`.
+    fn apply(&self, r: &[f64], z: &mut [f64]);
+}
+
+impl<P: Preconditioner + ?Sized> Preconditioner for &P {
+    fn apply(&self, r: &[f64], z: &mut [f64]) {
+        (**self).apply(r, z)
+    }
+}
+
+/// `M = I`, turning the preconditioned solvers into the plain ones.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdentityPreconditioner;
+
+impl Preconditioner for IdentityPreconditioner {
+    fn apply(&self, r: &[f64], z: &mut [f64]) {
+        z.copy_from_slice(r);
+    }
+}
+
+/// Diagonal scaling `M = diag(A)`.
+#[derive(Debug, Clone)]
+pub struct Jacobi {
+    inverse_diagonal: Vec<f64>,
+}
+
+impl Jacobi {
+    /// Returns `None` if `a` is not square or has a zero on its diagonal.
+    pub fn new(a: &CsrMatrix<f64>) -> Option<Self> {
+        Some(Self {
+            inverse_diagonal: diagonal(a)?.iter().map(|d| 1.0 / d).collect(),
+        })
+    }
+}
+
+impl Preconditioner for Jacobi {
+    fn apply(&self, r: &[f64], z: &mut [f64]) {
+        for ((z, r), d) in z.iter_mut().zip(r).zip(&self.inverse_diagonal) {
+            *z = r * d;
+        }
+    }
+}
+
+/// Symmetric successive over-relaxation,
+/// `M = w / (2 - w) (D / w + L) (D / w)^-1 (D / w + U)` for the splitting `A = L + D + U`.
+#[derive(Debug, Clone)]
+pub struct Ssor {
+    matrix: CsrMatrix<f64>,
+    diagonal: Vec<f64>,
+    omega: f64,
+}
+
+impl Ssor {
+    /// Returns `None` if `a` is not square, has a zero on its diagonal or `omega` is outside
+    /// `(0, 2)`.
+    pub fn new(a: &CsrMatrix<f64>, omega: f64) -> Option<Self> {
+        if !(omega > 0.0 && omega < 2.0) {
+            return None;
+        }
+
+        Some(Self {
+            diagonal: diagonal(a)?,
+            matrix: a.clone(),
+            omega,
+        })
+    }
+}
+
+impl Preconditioner for Ssor {
+    fn apply(&self, r: &[f64], z: &mut [f64]) {
+        let n = r.len();
+        let omega = self.omega;
+
+        // Forward sweep: (D / w + L) y = r.
+        for i in 0..n {
+            let sum: f64 = self
+                .matrix
+                .row(i)
+                .take_while(|&(j, _)| j < i)
+                .map(|(j, a)| a * z[j])
+                .sum();
+            z[i] = (r[i] - sum) * omega / self.diagonal[i];
+        }
+
+        // Backward sweep: (D / w + U) z = (2 - w) / w * (D / w) y.
+        for i in (0..n).rev() {
+            let sum: f64 = self
+                .matrix
+                .row(i)
+                .filter(|&(j, _)| j > i)
+                .map(|(j, a)| a * z[j])
+                .sum();
+            let scaled = (2.0 - omega) / omega * self.diagonal[i] / omega * z[i];
+            z[i] = (scaled - sum) * omega / self.diagonal[i];
+        }
+    }
+}
+
+/// Incomplete Cholesky factorization with zero fill-in, `M = L L^T` where `L` keeps the sparsity
+/// pattern of the lower triangle of `A`.
+#[derive(Debug, Clone)]
+pub struct IncompleteCholesky {
+    lower: CsrMatrix<f64>,
+}
+
+impl IncompleteCholesky {
+    /// Factors the lower triangle of `a`, which is assumed symmetric.
+    ///
+    /// Returns `None` if `a` is not square or a non-positive pivot appears, which can happen for
+    /// symmetric positive definite matrices that are not diagonally dominant.
+    pub fn new(a: &CsrMatrix<f64>) -> Option<Self> {
+        diagonal(a)?;
+        let n = a.height() as usize;
+        let mut row_offsets = vec![0];
+        let mut column_indices = Vec::new();
+        let mut values = Vec::new();
+        for i in 0..n {
+            for (j, value) in a.row(i).take_while(|&(j, _)| j <= i) {
+                column_indices.push(j);
+                values.push(*value);
+            }
+            row_offsets.push(values.len());
+        }
+
+        for i in 0..n {
+            let start = row_offsets[i];
+            let end = row_offsets[i + 1];
+            for p in start..end {
+                let j = column_indices[p];
+
+                // Sparse dot product of rows i and j of L over the columns left of j.
+                let mut sum = 0.0;
+                let (mut q, mut s) = (start, row_offsets[j]);
+                while q < p && column_indices[s] < j {
+                    match column_indices[q].cmp(&column_indices[s]) {
+                        std::cmp::Ordering::Less => q += 1,
+                        std::cmp::Ordering::Greater => s += 1,
+                        std::cmp::Ordering::Equal => {
+                            sum += values[q] * values[s];
+                            q += 1;
+                            s += 1;
+                        }
+                    }
+                }
+
+                if j == i {
+                    let pivot = values[p] - sum;
+                    if pivot <= 0.0 {
+                        return None;
+                    }
+                    values[p] = pivot.sqrt();
+                } else {
+                    values[p] = (values[p] - sum) / values[row_offsets[j + 1] - 1];
+                }
+            }
+        }
+
+        Some(Self {
+            lower: CsrMatrix::from_parts(n, n, row_offsets, column_indices, values).ok()?,
+        })
+    }
+}
+
+impl Preconditioner for IncompleteCholesky {
+    fn apply(&self, r: &[f64], z: &mut [f64]) {
+        let n = r.len();
+        let offsets = self.lower.row_offsets();
+        let values = self.lower.values();
+
+        // L y = r, with the diagonal stored last in each row.
+        for i in 0..n {
+            let sum: f64 = self
+                .lower
+                .row(i)
+                .take_while(|&(j, _)| j < i)
+                .map(|(j, l)| l * z[j])
+                .sum();
+            z[i] = (r[i] - sum) / values[offsets[i + 1] - 1];
+        }
+
+        // L^T z = y, scattering each solved entry into the rows above it.
+        for i in (0..n).rev() {
+            z[i] /= values[offsets[i + 1] - 1];
+            let solved = z[i];
+            for (j, l) in self.lower.row(i).take_while(|&(j, _)| j < i) {
+                z[j] -= l * solved;
+            }
+        }
+    }
+}
+
+/// Incomplete LU factorization with zero fill-in, `M = L U` where `L` (unit lower) and `U` share
+/// the sparsity pattern of `A`.
+#[derive(Debug, Clone)]
+pub struct IncompleteLu {
+    factors: CsrMatrix<f64>,
+    diagonal_positions: Vec<usize>,
+}
+
+impl IncompleteLu {
+    /// Returns `None` if `a` is not square, is missing a diagonal entry or a zero pivot appears.
+    pub fn new(a: &CsrMatrix<f64>) -> Option<Self> {
+        diagonal(a)?;
+        let n = a.height() as usize;
+        let mut factors = a.clone();
+        let offsets = factors.row_offsets.clone();
+        let columns = factors.column_indices.clone();
+        let diagonal_positions: Vec<usize> = (0..n)
+            .map(|i| {
+                offsets[i]
+                    + columns[offsets[i]..offsets[i + 1]]
+                        .binary_search(&i)
+                        .unwrap()
+            })
+            .collect();
+
+        let values = &mut factors.values;
+        for i in 1..n {
+            for p in offsets[i]..diagonal_positions[i] {
+                let k = columns[p];
+                let pivot = values[diagonal_positions[k]];
+                if pivot == 0.0 {
+                    return None;
+                }
+                values[p] /= pivot;
+                let multiplier = values[p];
+
+                // Update the entries of row i right of k that row k also stores.
+                let mut s = diagonal_positions[k] + 1;
+                for q in p + 1..offsets[i + 1] {
+                    while s < offsets[k + 1] && columns[s] < columns[q] {
+                        s += 1;
+                    }
+                    if s == offsets[k + 1] {
+                        break;
+                    }
+                    if columns[s] == columns[q] {
+                        values[q] -= multiplier * values[s];
+                    }
+                }
+            }
+        }
+
+        if diagonal_positions.iter().any(|&p| values[p] == 0.0) {
+            return None;
+        }
+
+        Some(Self {
+            factors,
+            diagonal_positions,
+        })
+    }
+}
+
+impl Preconditioner for IncompleteLu {
+    fn apply(&self, r: &[f64], z: &mut [f64]) {
+        let n = r.len();
+        let values = self.factors.values();
+
+        for i in 0..n {
+            let sum: f64 = self
+                .factors
+                .row(i)
+                .take_while(|&(j, _)| j < i)
+                .map(|(j, l)| l * z[j])
+                .sum();
+            z[i] = r[i] - sum;
+        }
+
+        for i in (0..n).rev() {
+            let sum: f64 = self
+                .factors
+                .row(i)
+                .filter(|&(j, _)| j > i)
+                .map(|(j, u)| u * z[j])
+                .sum();
+            z[i] = (z[i] - sum) / values[self.diagonal_positions[i]];
+        }
+    }
+}
+
+/// The diagonal of a square matrix, or `None` if it is not square or has a zero on it.
+fn diagonal(a: &CsrMatrix<f64>) -> Option<Vec<f64>> {
+    if a.width() != a.height() {
+        return None;
+    }
+
+    (0..a.height() as usize)
+        .map(|i| a.get(i, i).copied().filter(|&d| d != 0.0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iterative::tests::{convection_diffusion, poisson, relative_residual, rhs};
+    use crate::iterative::{cg, gmres, pbicgstab, pcg, pgmres, SolverOptions};
+
+    fn applied<P: Preconditioner>(m: &P, r: &[f64]) -> Vec<f64> {
+        let mut z = vec![0.0; r.len()];
+        m.apply(r, &mut z);
+        z
+    }
+
+    #[test]
+    fn jacobi_divides_by_the_diagonal() {
+        let a = CsrMatrix::from_triplets(2, 2, &[(0, 0, 2.0), (0, 1, 1.0), (1, 1, -4.0)]).unwrap();
+        assert_eq!(
+            applied(&Jacobi::new(&a).unwrap(), &[1.0, 1.0]),
+            vec![0.5, -0.25]
+        );
+
+        let missing_diagonal = CsrMatrix::from_triplets(2, 2, &[(0, 0, 1.0), (1, 0, 1.0)]).unwrap();
+        assert!(Jacobi::new(&missing_diagonal).is_none());
+        let wide = CsrMatrix::from_triplets(2, 3, &[(0, 0, 1.0), (1, 1, 1.0)]).unwrap();
+        assert!(Jacobi::new(&wide).is_none());
+    }
+
+    #[test]
+    fn ssor_rejects_relaxation_outside_the_open_interval() {
+        let a = poisson(3);
+        assert!(Ssor::new(&a, 0.0).is_none());
+        assert!(Ssor::new(&a, 2.0).is_none());
+        assert!(Ssor::new(&a, f64::NAN).is_none());
+        assert!(Ssor::new(&a, 1.2).is_some());
+    }
+
+    #[test]
+    fn zero_fill_factorizations_are_exact_on_tridiagonal_matrices() {
+        // Tridiagonal factors produce no fill-in, so the incomplete factorizations are exact.
+        let spd = CsrMatrix::from_triplets(
+            4,
+            4,
+            &(0..4)
+                .flat_map(|i| {
+                    let mut row = vec![(i, i, 3.0 + i as f64)];
+                    if i > 0 {
+                        row.push((i, i - 1, -1.0));
+                        row.push((i - 1, i, -1.0));
+                    }
+                    row
+                })
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let b = rhs(4);
+        let z = applied(&IncompleteCholesky::new(&spd).unwrap(), &b);
+        assert!(relative_residual(&spd, &z, &b) < 1e-14);
+
+        let a = convection_diffusion(6, 0.5);
+        let b = rhs(6);
+        let z = applied(&IncompleteLu::new(&a).unwrap(), &b);
+        assert!(relative_residual(&a, &z, &b) < 1e-14);
+    }
+
+    #[test]
+    fn preconditioned_cg_needs_fewer_iterations() {
+        let a = poisson(20);
+        let b = rhs(400);
+        let options = SolverOptions::default();
+        let plain = cg(&a, &b, None, &options).unwrap();
+
+        let jacobi = Jacobi::new(&a).unwrap();
+        let ssor = Ssor::new(&a, 1.5).unwrap();
+        let ic0 = IncompleteCholesky::new(&a).unwrap();
+        let preconditioners: [&dyn Preconditioner; 3] = [&jacobi, &ssor, &ic0];
+        for (i, m) in preconditioners.into_iter().enumerate() {
+            let solution = pcg(&a, m, &b, None, &options).unwrap();
+            assert!(solution.converged());
+            assert!(relative_residual(&a, &solution.x, &b) < 1e-9);
+            // Jacobi is a constant scaling of this Laplacian and cannot help.
+            if i > 0 {
+                assert!(solution.iterations < plain.iterations, "preconditioner {i}");
+            }
+        }
+    }
+
+    #[test]
+    fn ilu_speeds_up_the_nonsymmetric_solvers() {
+        let a = convection_diffusion(300, 2.0);
+        let b = rhs(300);
+        let options = SolverOptions::default();
+        let ilu = IncompleteLu::new(&a).unwrap();
+
+        let plain = gmres(&a, &b, None, &options).unwrap();
+        let preconditioned = pgmres(&a, &ilu, &b, None, &options).unwrap();
+        assert!(preconditioned.converged());
+        assert!(relative_residual(&a, &preconditioned.x, &b) < 1e-9);
+        assert!(preconditioned.iterations < plain.iterations);
+
+        let solution = pbicgstab(&a, &ilu, &b, None, &options).unwrap();
+        assert!(solution.converged());
+        assert!(relative_residual(&a, &solution.x, &b) < 1e-9);
+    }
+}
@@ -1,2 +1,11 @@
 pub mod matrices;
-pub mod dimension;
\ No newline at end of file
+pub mod dimension;
+pub mod scalar;
+pub mod bigint;
+pub mod finite_field;
+pub mod complex;
+pub mod fft;
+pub mod operator;
+pub mod iterative;
+pub mod eigen;
+mod random;
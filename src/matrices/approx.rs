@@ -0,0 +1,220 @@
+use super::MatrixContent;
+
+impl MatrixContent<f64> {
+    /// Whether both matrices have the same shape and every pair of cells satisfies
+    /// `|a - b| <= max(abs_tol, rel_tol * max(|a|, |b|))`.
+    ///
+    /// NaN is never equal to anything; infinities are only equal to themselves.
+    pub fn approx_eq(&self, other: &MatrixContent<f64>, abs_tol: f64, rel_tol: f64) -> bool {
+        self.same_shape(other)
+            && self
+                .cell_pairs(other)
+                .all(|(_, a, b)| close(a, b, abs_tol, rel_tol))
+    }
+
+    /// Whether both matrices have the same shape and every pair of cells is at most `max_ulps`
+    /// representable values apart. Zeros of either sign are equal; NaN is never equal to
+    /// anything.
+    pub fn ulps_eq(&self, other: &MatrixContent<f64>, max_ulps: u64) -> bool {
+        self.same_shape(other)
+            && self
+                .cell_pairs(other)
+                .all(|(_, a, b)| ulps_between(a, b).is_some_and(|ulps| ulps <= max_ulps))
+    }
+
+    /// Largest `|a - b|` over corresponding cells and where it occurs, or `None` if the shapes
+    /// differ or the matrices are empty.
+    pub fn max_deviation(&self, other: &MatrixContent<f64>) -> Option<((isize, isize), f64)> {
+        if !self.same_shape(other) {
+            return None;
+        }
+        self.cell_pairs(other)
+            .map(|(index, a, b)| (index, deviation(a, b)))
+            .max_by(|(_, x), (_, y)| x.total_cmp(y))
+    }
+
+    /// The maximum deviation followed by both matrices, with the cells that are not
+    /// approximately equal in brackets. Used by [`assert_matrix_approx_eq!`].
+    ///
+    /// [`assert_matrix_approx_eq!`]: crate::assert_matrix_approx_eq
+    pub fn describe_differences(
+        &self,
+        other: &MatrixContent<f64>,
+        abs_tol: f64,
+        rel_tol: f64,
+    ) -> String {
+        let mut output = String::new();
+        let same_shape = self.same_shape(other);
+        if same_shape {
+            let differing = self
+                .cell_pairs(other)
+                .filter(|&(_, a, b)| !close(a, b, abs_tol, rel_tol))
+                .count();
+            if let Some(((row, col), max)) = self.max_deviation(other) {
+                output.push_str(&format!(
+                    "{} differing cells, max deviation {:e} at ({}, {})\n",
+                    differing, max, row, col
+                ));
+            }
+        } else {
+            output.push_str(&format!(
+                "shapes differ: {}x{} vs {}x{}\n",
+                self.height(),
+                self.width(),
+                other.height(),
+                other.width()
+            ));
+        }
+
+        for (name, matrix, reference) in [("left", self, other), ("right", other, self)] {
+            output.push_str(name);
+            output.push_str(":\n");
+            for row in 0..matrix.height() {
+                for col in 0..matrix.width() {
+                    let value = matrix[(row, col)];
+                    let matches =
+                        !same_shape || close(value, reference[(row, col)], abs_tol, rel_tol);
+                    if matches {
+                        output.push_str(&format!("{:?}, ", value));
+                    } else {
+                        output.push_str(&format!("[{:?}], ", value));
+                    }
+                }
+                output.pop();
+                output.pop();
+                output.push('\n');
+            }
+        }
+        output.pop();
+        output
+    }
+
+    fn same_shape(&self, other: &MatrixContent<f64>) -> bool {
+        self.width() == other.width() && self.height() == other.height()
+    }
+
+    fn cell_pairs<'a>(
+        &'a self,
+        other: &'a MatrixContent<f64>,
+    ) -> impl Iterator<Item = ((isize, isize), f64, f64)> + 'a {
+        (0..self.height()).flat_map(move |row| {
+            (0..self.width()).map(move |col| ((row, col), self[(row, col)], other[(row, col)]))
+        })
+    }
+}
+
+fn close(a: f64, b: f64, abs_tol: f64, rel_tol: f64) -> bool {
+    if a == b {
+        return true;
+    }
+    if !a.is_finite() || !b.is_finite() {
+        return false;
+    }
+    (a - b).abs() <= abs_tol.max(rel_tol * a.abs().max(b.abs()))
+}
+
+fn deviation(a: f64, b: f64) -> f64 {
+    if a == b {
+        0.0
+    } else {
+        (a - b).abs()
+    }
+}
+
+/// Number of representable `f64` values between `a` and `b`, or `None` if either is NaN.
+fn ulps_between(a: f64, b: f64) -> Option<u64> {
+    if a.is_nan() || b.is_nan() {
+        return None;
+    }
+
+    // Map the bit patterns to integers that are ordered like the floats they encode.
+    let ordered = |x: f64| {
+        let bits = x.to_bits() as i64;
+        if bits < 0 {
+            i64::MIN - bits
+        } else {
+            bits
+        }
+    };
+    Some((ordered(a) as i128 - ordered(b) as i128).unsigned_abs() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimension::Dimension;
+
+    fn row(values: &[f64]) -> MatrixContent<f64> {
+        MatrixContent::new(Dimension::new(values.len() as isize, 1), values.to_vec())
+    }
+
+    #[test]
+    fn absolute_and_relative_tolerances() {
+        let a = row(&[1.0, 1e6, 0.0]);
+        // 1e-4 is far beyond the absolute tolerance but within 1e-9 of 1e6.
+        assert!(a.approx_eq(&row(&[1.0 + 1e-13, 1e6 + 1e-4, 1e-13]), 1e-12, 1e-9));
+        assert!(!a.approx_eq(&row(&[1.0, 1e6 + 1e-2, 0.0]), 1e-12, 1e-9));
+        assert!(!a.approx_eq(&row(&[1.0, 1e6, 1e-11]), 1e-12, 1e-9));
+        assert!(!a.approx_eq(&row(&[1.0, 1e6]), 1.0, 1.0));
+    }
+
+    #[test]
+    fn special_values() {
+        let nan = row(&[f64::NAN]);
+        assert!(!nan.approx_eq(&nan, 1.0, 1.0));
+        assert!(!nan.ulps_eq(&nan, u64::MAX));
+
+        let infinity = row(&[f64::INFINITY]);
+        assert!(infinity.approx_eq(&infinity, 0.0, 0.0));
+        assert!(!infinity.approx_eq(&row(&[f64::MAX]), 1.0, 1.0));
+        assert!(!infinity.approx_eq(&row(&[f64::NEG_INFINITY]), 1.0, 1.0));
+    }
+
+    #[test]
+    fn units_in_the_last_place() {
+        let one = row(&[1.0]);
+        assert!(one.ulps_eq(&row(&[1.0 + f64::EPSILON]), 1));
+        assert!(!one.ulps_eq(&row(&[1.0 + 2.0 * f64::EPSILON]), 1));
+        assert!(row(&[0.0]).ulps_eq(&row(&[-0.0]), 0));
+
+        let smallest = f64::from_bits(1);
+        assert_eq!(ulps_between(smallest, -smallest), Some(2));
+        assert_eq!(ulps_between(f64::MAX, f64::INFINITY), Some(1));
+    }
+
+    #[test]
+    fn maximum_deviation_and_its_location() {
+        let a = MatrixContent::new(Dimension::new(2, 2), vec![1.0, 2.0, 3.0, 4.0]);
+        let b = MatrixContent::new(Dimension::new(2, 2), vec![1.0, 2.5, 3.0, 3.0]);
+        assert_eq!(a.max_deviation(&b), Some(((1, 1), 1.0)));
+        assert_eq!(a.max_deviation(&row(&[1.0])), None);
+        assert_eq!(row(&[]).max_deviation(&row(&[])), None);
+    }
+
+    #[test]
+    fn differences_are_bracketed() {
+        let a = MatrixContent::new(Dimension::new(2, 2), vec![1.0, 2.0, 3.0, 4.0]);
+        let b = MatrixContent::new(Dimension::new(2, 2), vec![1.0, 2.5, 3.0, 4.0]);
+        assert_eq!(
+            a.describe_differences(&b, 1e-12, 1e-9),
+            "1 differing cells, max deviation 5e-1 at (0, 1)\n\
+             left:\n1.0, [2.0]\n3.0, 4.0\n\
+             right:\n1.0, [2.5]\n3.0, 4.0"
+        );
+        assert!(a
+            .describe_differences(&row(&[1.0]), 1e-12, 1e-9)
+            .starts_with("shapes differ: 2x2 vs 1x1\n"));
+    }
+
+    #[test]
+    fn assertion_macro_accepts_close_matrices() {
+        crate::assert_matrix_approx_eq!(row(&[1.0, 2.0]), row(&[1.0, 2.0 + 1e-12]));
+        crate::assert_matrix_approx_eq!(row(&[1.0]), row(&[1.1]), 0.2, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "matrices are not approximately equal: after 3 steps")]
+    fn assertion_macro_reports_the_message() {
+        crate::assert_matrix_approx_eq!(row(&[1.0]), row(&[1.1]), 1e-3, 0.0, "after {} steps", 3);
+    }
+}
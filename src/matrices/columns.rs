@@ -9,14 +9,7 @@ pub struct MatrixColumn<'a, T> {
 
 impl<'a, T> MatrixColumn<'a, T> {
     pub fn iter(&'a self) -> MatrixIter<'a, T> {
-        let pos = self.col;
-        let end = self.col + (self.mat.dimension.height() - 1) * self.mat.dimension.width();
-        MatrixIter {
-            step: self.mat.dimension.width() as usize,
-            end: end as usize,
-            pos: pos as usize,
-            mat: self.mat,
-        }
+        self.mat.column_iter(self.col)
     }
 }
 
@@ -74,14 +67,7 @@ impl<'a, T> IntoIterator for MatrixColumn<'a, T> {
     type IntoIter = MatrixIter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let pos = self.col;
-        let end = self.col + (self.mat.dimension.height() - 1) * self.mat.dimension.width();
-        MatrixIter {
-            step: self.mat.dimension.width() as usize,
-            end: end as usize,
-            pos: pos as usize,
-            mat: self.mat,
-        }
+        self.mat.column_iter(self.col)
     }
 }
 
@@ -0,0 +1,403 @@
+use crate::dimension::Dimension;
+
+use super::{MatrixContent, MisAlignment};
+
+/// Size of the result of [`MatrixContent::convolve2d`] relative to its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConvolutionMode {
+    /// Every position where the kernel overlaps the input: `n + k - 1` cells along each axis.
+    Full,
+    /// The central part of `Full` with the input's shape.
+    Same,
+    /// Only the positions where the kernel fits entirely inside the input: `n - k + 1` cells.
+    Valid,
+}
+
+/// How cells outside the input are filled in when the kernel overhangs its edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Boundary {
+    /// Zeros.
+    Zero,
+    /// Mirror image about the edge cell, which is not repeated: `c b | a b c | b a`.
+    Reflect,
+    /// Periodic continuation: `b c | a b c | a b`.
+    Wrap,
+    /// Repetition of the edge cell: `a a | a b c | c c`.
+    Edge,
+}
+
+impl Boundary {
+    /// Input index standing in for position `index` of a line of `len` cells.
+    fn resolve(self, index: isize, len: isize) -> Option<usize> {
+        if (0..len).contains(&index) {
+            return Some(index as usize);
+        }
+        if len == 0 {
+            return None;
+        }
+        let index = match self {
+            Boundary::Zero => return None,
+            Boundary::Wrap => index.rem_euclid(len),
+            Boundary::Edge => index.clamp(0, len - 1),
+            Boundary::Reflect if len == 1 => 0,
+            Boundary::Reflect => {
+                let period = 2 * (len - 1);
+                let index = index.rem_euclid(period);
+                if index < len {
+                    index
+                } else {
+                    period - index
+                }
+            }
+        };
+        Some(index as usize)
+    }
+
+    /// Resolved input indices for positions `from..from + count` of a line of `len` cells.
+    fn resolve_range(self, from: isize, count: isize, len: isize) -> Vec<Option<usize>> {
+        (from..from + count)
+            .map(|index| self.resolve(index, len))
+            .collect()
+    }
+}
+
+impl ConvolutionMode {
+    /// First position, in `Full` coordinates, and number of output cells along an axis where the
+    /// input has `len` cells and the kernel `size`.
+    fn range(self, len: isize, size: isize) -> Result<(isize, isize), MisAlignment> {
+        match self {
+            ConvolutionMode::Full => Ok((0, len + size - 1)),
+            ConvolutionMode::Same => Ok(((size - 1) / 2, len)),
+            ConvolutionMode::Valid if size > len => Err(MisAlignment),
+            ConvolutionMode::Valid => Ok((size - 1, len - size + 1)),
+        }
+    }
+}
+
+impl MatrixContent<f64> {
+    /// The cells in row-major order.
+    fn row_major_cells(&self) -> Vec<f64> {
+        (0..self.height())
+            .flat_map(|row| self.row_iter(row).copied())
+            .collect()
+    }
+
+    /// Splits a rank one kernel into the column and row whose outer product it is.
+    fn separate(&self) -> Option<(Vec<f64>, Vec<f64>)> {
+        let mut pivot = (0, 0);
+        for row in 0..self.height() {
+            for col in 0..self.width() {
+                if self[(row, col)].abs() > self[pivot].abs() {
+                    pivot = (row, col);
+                }
+            }
+        }
+        let scale = self[pivot];
+        if scale == 0.0 || !scale.is_finite() {
+            return None;
+        }
+
+        let column: Vec<f64> = (0..self.height()).map(|row| self[(row, pivot.1)]).collect();
+        let row: Vec<f64> = (0..self.width())
+            .map(|col| self[(pivot.0, col)] / scale)
+            .collect();
+        let tolerance = 1e-12 * scale.abs();
+        let separable = column.iter().enumerate().all(|(i, u)| {
+            row.iter()
+                .enumerate()
+                .all(|(j, v)| (u * v - self[(i as isize, j as isize)]).abs() <= tolerance)
+        });
+        separable.then_some((column, row))
+    }
+
+    /// Discrete 2D convolution with `kernel`, `out[i, j] = sum kernel[p, q] * self[i - p, j - q]`,
+    /// reading cells past the edges according to `boundary`. Kernels that are an outer product
+    /// of a column and a row are detected and applied as two 1D passes.
+    ///
+    /// Fails for an empty kernel, or for `Valid` when the kernel is larger than the input.
+    pub fn convolve2d(
+        &self,
+        kernel: &MatrixContent<f64>,
+        mode: ConvolutionMode,
+        boundary: Boundary,
+    ) -> Result<Self, MisAlignment> {
+        if kernel.height() == 0 || kernel.width() == 0 {
+            return Err(MisAlignment);
+        }
+        if kernel.height() > 1 && kernel.width() > 1 {
+            if let Some((column, row)) = kernel.separate() {
+                return self.convolve2d_separable(&column, &row, mode, boundary);
+            }
+        }
+
+        let (kernel_height, kernel_width) = (kernel.height(), kernel.width());
+        let (row_start, height) = mode.range(self.height(), kernel_height)?;
+        let (col_start, width) = mode.range(self.width(), kernel_width)?;
+        let rows = boundary.resolve_range(
+            row_start - (kernel_height - 1),
+            height + kernel_height - 1,
+            self.height(),
+        );
+        let cols = boundary.resolve_range(
+            col_start - (kernel_width - 1),
+            width + kernel_width - 1,
+            self.width(),
+        );
+        let input = self.row_major_cells();
+        let weights = kernel.row_major_cells();
+        let input_width = self.width() as usize;
+        let (kernel_height, kernel_width) = (kernel_height as usize, kernel_width as usize);
+
+        let mut buffer = Vec::with_capacity((height * width) as usize);
+        for out_row in 0..height as usize {
+            for out_col in 0..width as usize {
+                let mut sum = 0.0;
+                for p in 0..kernel_height {
+                    let Some(row) = rows[out_row + kernel_height - 1 - p] else {
+                        continue;
+                    };
+                    let line = &input[row * input_width..(row + 1) * input_width];
+                    for q in 0..kernel_width {
+                        if let Some(col) = cols[out_col + kernel_width - 1 - q] {
+                            sum += weights[p * kernel_width + q] * line[col];
+                        }
+                    }
+                }
+                buffer.push(sum);
+            }
+        }
+        Ok(MatrixContent::new(Dimension::new(width, height), buffer))
+    }
+
+    /// Convolution with the kernel `column * row^T`, applied as a pass along the rows followed by
+    /// a pass down the columns. Equivalent to [`MatrixContent::convolve2d`] with that kernel at
+    /// `O(k_h + k_w)` rather than `O(k_h * k_w)` work per cell.
+    pub fn convolve2d_separable(
+        &self,
+        column: &[f64],
+        row: &[f64],
+        mode: ConvolutionMode,
+        boundary: Boundary,
+    ) -> Result<Self, MisAlignment> {
+        if column.is_empty() || row.is_empty() {
+            return Err(MisAlignment);
+        }
+
+        let (kernel_height, kernel_width) = (column.len() as isize, row.len() as isize);
+        let (row_start, height) = mode.range(self.height(), kernel_height)?;
+        let (col_start, width) = mode.range(self.width(), kernel_width)?;
+        let rows = boundary.resolve_range(
+            row_start - (kernel_height - 1),
+            height + kernel_height - 1,
+            self.height(),
+        );
+        let cols = boundary.resolve_range(
+            col_start - (kernel_width - 1),
+            width + kernel_width - 1,
+            self.width(),
+        );
+        let input = self.row_major_cells();
+        let (input_width, width, height) = (self.width() as usize, width as usize, height as usize);
+
+        // Rows of the input convolved with `row`, for every output column.
+        let mut horizontal = Vec::with_capacity(self.height() as usize * width);
+        for source in 0..self.height() as usize {
+            let line = &input[source * input_width..(source + 1) * input_width];
+            for out_col in 0..width {
+                let mut sum = 0.0;
+                for (q, weight) in row.iter().enumerate() {
+                    if let Some(col) = cols[out_col + row.len() - 1 - q] {
+                        sum += weight * line[col];
+                    }
+                }
+                horizontal.push(sum);
+            }
+        }
+
+        let mut buffer = vec![0.0; height * width];
+        for out_row in 0..height {
+            let output = &mut buffer[out_row * width..(out_row + 1) * width];
+            for (p, weight) in column.iter().enumerate() {
+                if let Some(source) = rows[out_row + column.len() - 1 - p] {
+                    let line = &horizontal[source * width..(source + 1) * width];
+                    for (cell, value) in output.iter_mut().zip(line) {
+                        *cell += weight * value;
+                    }
+                }
+            }
+        }
+        Ok(MatrixContent::new(
+            Dimension::new(width as isize, height as isize),
+            buffer,
+        ))
+    }
+
+    /// Discrete 2D cross-correlation, `out[i, j] = sum kernel[p, q] * self[i + p, j + q]`: the
+    /// convolution with `kernel` rotated by a half turn. See [`MatrixContent::convolve2d`].
+    pub fn correlate2d(
+        &self,
+        kernel: &MatrixContent<f64>,
+        mode: ConvolutionMode,
+        boundary: Boundary,
+    ) -> Result<Self, MisAlignment> {
+        self.convolve2d(&kernel.clone().rotate90(2), mode, boundary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::SplitMix64;
+
+    const BOUNDARIES: [Boundary; 4] = [
+        Boundary::Zero,
+        Boundary::Reflect,
+        Boundary::Wrap,
+        Boundary::Edge,
+    ];
+    const MODES: [ConvolutionMode; 3] = [
+        ConvolutionMode::Full,
+        ConvolutionMode::Same,
+        ConvolutionMode::Valid,
+    ];
+
+    fn row(values: &[f64]) -> MatrixContent<f64> {
+        MatrixContent::new(Dimension::new(values.len() as isize, 1), values.to_vec())
+    }
+
+    fn random(rng: &mut SplitMix64, height: isize, width: isize) -> MatrixContent<f64> {
+        MatrixContent::from_fn(Dimension::new(width, height), |_, _| rng.next_normal())
+    }
+
+    /// The defining sum evaluated cell by cell.
+    fn reference(
+        input: &MatrixContent<f64>,
+        kernel: &MatrixContent<f64>,
+        mode: ConvolutionMode,
+        boundary: Boundary,
+    ) -> MatrixContent<f64> {
+        let (row_start, height) = mode.range(input.height(), kernel.height()).unwrap();
+        let (col_start, width) = mode.range(input.width(), kernel.width()).unwrap();
+        MatrixContent::from_fn(Dimension::new(width, height), |i, j| {
+            let mut sum = 0.0;
+            for p in 0..kernel.height() {
+                for q in 0..kernel.width() {
+                    let r = boundary.resolve(row_start + i - p, input.height());
+                    let c = boundary.resolve(col_start + j - q, input.width());
+                    if let (Some(r), Some(c)) = (r, c) {
+                        sum += kernel[(p, q)] * input[(r as isize, c as isize)];
+                    }
+                }
+            }
+            sum
+        })
+    }
+
+    #[test]
+    fn hand_computed_outputs_for_every_mode_and_boundary() {
+        let input = row(&[1.0, 2.0, 4.0]);
+        let kernel = row(&[1.0, 10.0, 100.0]);
+        let expected: [(Boundary, [f64; 5]); 4] = [
+            (Boundary::Zero, [1.0, 12.0, 124.0, 240.0, 400.0]),
+            (Boundary::Reflect, [421.0, 212.0, 124.0, 242.0, 421.0]),
+            (Boundary::Wrap, [241.0, 412.0, 124.0, 241.0, 412.0]),
+            (Boundary::Edge, [111.0, 112.0, 124.0, 244.0, 444.0]),
+        ];
+        for (boundary, full) in expected {
+            let convolve = |input: &MatrixContent<f64>, kernel: &MatrixContent<f64>, mode| {
+                input.convolve2d(kernel, mode, boundary).unwrap()
+            };
+            assert_eq!(convolve(&input, &kernel, ConvolutionMode::Full), row(&full));
+            assert_eq!(
+                convolve(&input, &kernel, ConvolutionMode::Same),
+                row(&full[1..4])
+            );
+            assert_eq!(
+                convolve(&input, &kernel, ConvolutionMode::Valid),
+                row(&full[2..3])
+            );
+
+            let (input, kernel) = (
+                input.clone().into_transpose(),
+                kernel.clone().into_transpose(),
+            );
+            assert_eq!(
+                convolve(&input, &kernel, ConvolutionMode::Full),
+                row(&full).into_transpose()
+            );
+            assert_eq!(
+                convolve(&input, &kernel, ConvolutionMode::Same),
+                row(&full[1..4]).into_transpose()
+            );
+        }
+    }
+
+    #[test]
+    fn correlation_does_not_flip_the_kernel() {
+        let input = row(&[1.0, 2.0, 4.0]);
+        let kernel = row(&[1.0, 10.0, 100.0]);
+        let valid = input
+            .correlate2d(&kernel, ConvolutionMode::Valid, Boundary::Zero)
+            .unwrap();
+        assert_eq!(valid, row(&[421.0]));
+    }
+
+    #[test]
+    fn direct_convolution_matches_the_defining_sum() {
+        let mut rng = SplitMix64::new(11);
+        let input = random(&mut rng, 6, 7);
+        let kernel = random(&mut rng, 3, 4);
+        assert!(kernel.separate().is_none());
+        for mode in MODES {
+            for boundary in BOUNDARIES {
+                let actual = input.convolve2d(&kernel, mode, boundary).unwrap();
+                let expected = reference(&input, &kernel, mode, boundary);
+                crate::assert_matrix_approx_eq!(actual, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn separable_convolution_matches_direct_convolution() {
+        let mut rng = SplitMix64::new(12);
+        let column: Vec<f64> = (0..3).map(|_| rng.next_normal()).collect();
+        let row: Vec<f64> = (0..5).map(|_| rng.next_normal()).collect();
+        let kernel = MatrixContent::from_fn(Dimension::new(5, 3), |p, q| {
+            column[p as usize] * row[q as usize]
+        });
+        let (u, v) = kernel.separate().unwrap();
+        let rebuilt =
+            MatrixContent::from_fn(Dimension::new(5, 3), |p, q| u[p as usize] * v[q as usize]);
+        crate::assert_matrix_approx_eq!(rebuilt, kernel);
+
+        // Inputs smaller than the kernel exercise boundaries that wrap around more than once.
+        for (height, width) in [(8, 9), (2, 3), (1, 1)] {
+            let input = random(&mut rng, height, width);
+            for mode in MODES {
+                for boundary in BOUNDARIES {
+                    let separable = input.convolve2d_separable(&column, &row, mode, boundary);
+                    let detected = input.convolve2d(&kernel, mode, boundary);
+                    if mode == ConvolutionMode::Valid && (height < 3 || width < 5) {
+                        assert!(separable.is_err() && detected.is_err());
+                        continue;
+                    }
+                    let expected = reference(&input, &kernel, mode, boundary);
+                    crate::assert_matrix_approx_eq!(separable.unwrap(), expected);
+                    crate::assert_matrix_approx_eq!(detected.unwrap(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty_kernels_are_rejected() {
+        let input = row(&[1.0, 2.0]);
+        let empty = MatrixContent::new(Dimension::new(0, 1), Vec::new());
+        assert!(input
+            .convolve2d(&empty, ConvolutionMode::Full, Boundary::Zero)
+            .is_err());
+        assert!(input
+            .convolve2d_separable(&[], &[1.0], ConvolutionMode::Full, Boundary::Zero)
+            .is_err());
+    }
+}
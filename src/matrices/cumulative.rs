@@ -0,0 +1,340 @@
+use std::ops::{Add, Mul, Sub};
+
+use crate::dimension::Dimension;
+
+use super::{layout::Layout, stacking::Axis, MatrixContent, MisAlignment};
+
+impl<T> MatrixContent<T> {
+    /// Number of cells in each line running along `axis`.
+    fn line_length(&self, axis: Axis) -> isize {
+        match axis {
+            Axis::Rows => self.height(),
+            Axis::Columns => self.width(),
+        }
+    }
+
+    /// Builds a matrix line by line: `f` receives each line along `axis` and pushes the `length`
+    /// cells of the corresponding output line. The result is row-major whichever way the lines
+    /// run.
+    fn map_lines<U>(
+        &self,
+        axis: Axis,
+        length: isize,
+        mut f: impl FnMut(Vec<&T>, &mut Vec<U>),
+    ) -> MatrixContent<U> {
+        let mut buffer = Vec::new();
+        match axis {
+            Axis::Rows => {
+                for col in 0..self.width() {
+                    f(self.column_iter(col).collect(), &mut buffer);
+                }
+                let dimension = Dimension::new(self.width(), length);
+                MatrixContent::with_layout(dimension, buffer, Layout::ColumnMajor)
+                    .into_layout(Layout::RowMajor)
+            }
+            Axis::Columns => {
+                for row in 0..self.height() {
+                    f(self.row_iter(row).collect(), &mut buffer);
+                }
+                let dimension = Dimension::new(length, self.height());
+                MatrixContent::with_layout(dimension, buffer, Layout::RowMajor)
+            }
+        }
+    }
+}
+
+impl<T: Clone> MatrixContent<T> {
+    /// Running fold along `axis`: each cell becomes `f(previous result, cell)`, except the first
+    /// of each line which is kept.
+    fn scan(&self, axis: Axis, mut f: impl FnMut(&T, &T) -> T) -> Self {
+        self.map_lines(axis, self.line_length(axis), |line, output| {
+            let mut acc: Option<T> = None;
+            for value in line {
+                let next = match &acc {
+                    Some(previous) => f(previous, value),
+                    None => value.clone(),
+                };
+                output.push(next.clone());
+                acc = Some(next);
+            }
+        })
+    }
+
+    /// Running sums down each column (`Axis::Rows`) or across each row (`Axis::Columns`).
+    pub fn cumsum(&self, axis: Axis) -> Self
+    where
+        T: Add<Output = T>,
+    {
+        self.scan(axis, |acc, value| acc.clone() + value.clone())
+    }
+
+    /// Running products along `axis`.
+    pub fn cumprod(&self, axis: Axis) -> Self
+    where
+        T: Mul<Output = T>,
+    {
+        self.scan(axis, |acc, value| acc.clone() * value.clone())
+    }
+
+    /// Running maxima along `axis`.
+    pub fn cummax(&self, axis: Axis) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.scan(axis, |acc, value| {
+            if value > acc { value } else { acc }.clone()
+        })
+    }
+
+    /// Running minima along `axis`.
+    pub fn cummin(&self, axis: Axis) -> Self
+    where
+        T: PartialOrd,
+    {
+        self.scan(axis, |acc, value| {
+            if value < acc { value } else { acc }.clone()
+        })
+    }
+
+    /// `n`-th discrete difference along `axis`, `x[i + 1] - x[i]` applied `n` times. Each line
+    /// loses `n` cells, leaving none once `n` reaches its length.
+    pub fn diff(&self, n: usize, axis: Axis) -> Self
+    where
+        T: Sub<Output = T>,
+    {
+        let length = (self.line_length(axis) - n as isize).max(0);
+        self.map_lines(axis, length, |line, output| {
+            let mut values: Vec<T> = line.into_iter().cloned().collect();
+            for _ in 0..n {
+                values = values
+                    .windows(2)
+                    .map(|pair| pair[1].clone() - pair[0].clone())
+                    .collect();
+            }
+            output.extend(values);
+        })
+    }
+}
+
+impl MatrixContent<f64> {
+    /// Slides a window of `window` cells along `axis`, reporting `f(mean, sum of squared
+    /// deviations)` for each full window. The moments are updated incrementally as the window
+    /// moves, on values shifted by the first finite cell of the line to limit cancellation.
+    /// Windows holding a `NaN` or an infinity are computed from scratch instead, so that such a
+    /// value only affects the windows it belongs to.
+    fn rolling(
+        &self,
+        window: isize,
+        axis: Axis,
+        f: impl Fn(f64, f64) -> f64,
+    ) -> Result<Self, MisAlignment> {
+        let length = self.line_length(axis);
+        if window < 1 || window > length {
+            return Err(MisAlignment);
+        }
+
+        let size = window as usize;
+        let count = window as f64;
+        Ok(self.map_lines(axis, length - window + 1, |line, output| {
+            let shift = line
+                .iter()
+                .map(|&&value| value)
+                .find(|value| value.is_finite())
+                .unwrap_or(0.0);
+            let moments = |values: &[&f64]| {
+                let mean = values.iter().map(|&&value| value - shift).sum::<f64>() / count;
+                let m2 = values
+                    .iter()
+                    .map(|&&value| (value - shift - mean).powi(2))
+                    .sum();
+                (mean, m2)
+            };
+
+            let mut non_finite = line[..size].iter().filter(|v| !v.is_finite()).count();
+            let (mut mean, mut m2) = moments(&line[..size]);
+            output.push(f(mean + shift, m2));
+
+            for i in size..line.len() {
+                let (incoming, outgoing) = (*line[i], *line[i - size]);
+                let was_finite = non_finite == 0;
+                non_finite += usize::from(!incoming.is_finite());
+                non_finite -= usize::from(!outgoing.is_finite());
+                if was_finite && non_finite == 0 {
+                    let (incoming, outgoing) = (incoming - shift, outgoing - shift);
+                    let previous = mean;
+                    mean += (incoming - outgoing) / count;
+                    m2 += (incoming - outgoing) * (incoming - mean + outgoing - previous);
+                    m2 = m2.max(0.0);
+                } else {
+                    (mean, m2) = moments(&line[i + 1 - size..=i]);
+                }
+                output.push(f(mean + shift, m2));
+            }
+        }))
+    }
+
+    /// Means of every run of `window` consecutive cells along `axis`. Each line shrinks to
+    /// `length - window + 1` cells; fails unless `1 <= window <= length`.
+    pub fn rolling_mean(&self, window: isize, axis: Axis) -> Result<Self, MisAlignment> {
+        self.rolling(window, axis, |mean, _| mean)
+    }
+
+    /// Sample standard deviations of every run of `window` consecutive cells along `axis`.
+    /// Each line shrinks to `length - window + 1` cells; fails unless `2 <= window <= length`.
+    pub fn rolling_std(&self, window: isize, axis: Axis) -> Result<Self, MisAlignment> {
+        if window < 2 {
+            return Err(MisAlignment);
+        }
+        self.rolling(window, axis, |_, m2| (m2 / (window - 1) as f64).sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(values: &[f64]) -> MatrixContent<f64> {
+        MatrixContent::new(Dimension::new(1, values.len() as isize), values.to_vec())
+    }
+
+    fn values(m: &MatrixContent<f64>) -> Vec<f64> {
+        m.entries().copied().collect()
+    }
+
+    /// Equality that treats two `NaN`s as equal.
+    fn same(actual: &[f64], expected: &[f64]) -> bool {
+        actual.len() == expected.len()
+            && actual
+                .iter()
+                .zip(expected)
+                .all(|(a, e)| a == e || (a.is_nan() && e.is_nan()) || (a - e).abs() < 1e-12)
+    }
+
+    #[test]
+    fn running_scans_along_each_axis() {
+        let m = MatrixContent::new(Dimension::new(3, 2), vec![1, 5, 2, 4, 3, 6]);
+        assert_eq!(
+            m.cumsum(Axis::Columns),
+            MatrixContent::new(Dimension::new(3, 2), vec![1, 6, 8, 4, 7, 13])
+        );
+        assert_eq!(
+            m.cumsum(Axis::Rows),
+            MatrixContent::new(Dimension::new(3, 2), vec![1, 5, 2, 5, 8, 8])
+        );
+        assert_eq!(
+            m.cumprod(Axis::Columns),
+            MatrixContent::new(Dimension::new(3, 2), vec![1, 5, 10, 4, 12, 72])
+        );
+        assert_eq!(
+            m.cummax(Axis::Columns),
+            MatrixContent::new(Dimension::new(3, 2), vec![1, 5, 5, 4, 4, 6])
+        );
+        assert_eq!(
+            m.cummin(Axis::Rows),
+            MatrixContent::new(Dimension::new(3, 2), vec![1, 5, 2, 1, 3, 2])
+        );
+    }
+
+    #[test]
+    fn repeated_differences_shorten_each_line() {
+        let m = MatrixContent::new(Dimension::new(4, 2), vec![1, 4, 9, 16, 0, 1, 8, 27]);
+        assert_eq!(
+            m.diff(1, Axis::Columns),
+            MatrixContent::new(Dimension::new(3, 2), vec![3, 5, 7, 1, 7, 19])
+        );
+        assert_eq!(
+            m.diff(2, Axis::Columns),
+            MatrixContent::new(Dimension::new(2, 2), vec![2, 2, 6, 12])
+        );
+        assert_eq!(
+            m.diff(1, Axis::Rows),
+            MatrixContent::new(Dimension::new(4, 1), vec![-1, -3, -1, 11])
+        );
+        assert_eq!(m.diff(5, Axis::Rows).height(), 0);
+    }
+
+    #[test]
+    fn results_are_row_major_along_either_axis() {
+        let m = MatrixContent::new(Dimension::new(3, 2), vec![1, 5, 2, 4, 3, 6]);
+        for layout in [Layout::RowMajor, Layout::ColumnMajor] {
+            let m = m.clone().into_layout(layout);
+            for axis in [Axis::Rows, Axis::Columns] {
+                assert_eq!(m.cumsum(axis).layout(), Layout::RowMajor);
+                assert_eq!(m.diff(1, axis).layout(), Layout::RowMajor);
+            }
+            assert_eq!(m.cumsum(Axis::Rows).as_slice(), &[1, 5, 2, 5, 8, 8]);
+            assert_eq!(m.diff(1, Axis::Rows).as_slice(), &[3, -2, 4]);
+        }
+    }
+
+    #[test]
+    fn rolling_moments_match_direct_computation() {
+        let line: Vec<f64> = (0..40).map(|i| 1e8 + ((i * 37) % 11) as f64).collect();
+        let m = column(&line);
+        let means = values(&m.rolling_mean(5, Axis::Rows).unwrap());
+        let stds = values(&m.rolling_std(5, Axis::Rows).unwrap());
+        assert_eq!(means.len(), 36);
+        for (start, window) in line.windows(5).enumerate() {
+            let mean = window.iter().sum::<f64>() / 5.0;
+            let variance = window.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / 4.0;
+            assert!((means[start] - mean).abs() < 1e-6);
+            assert!((stds[start] - variance.sqrt()).abs() < 1e-6);
+        }
+
+        let rows = m.clone().into_transpose();
+        assert_eq!(values(&rows.rolling_mean(5, Axis::Columns).unwrap()), means);
+
+        // Two columns, the second the first reversed: the output interleaves their windows.
+        let reversed: Vec<f64> = line.iter().rev().copied().collect();
+        let pair = MatrixContent::from_fn(Dimension::new(2, 40), |row, col| {
+            if col == 0 {
+                line[row as usize]
+            } else {
+                reversed[row as usize]
+            }
+        });
+        let pair_means = values(&pair.rolling_mean(5, Axis::Rows).unwrap());
+        let reversed_means = values(&column(&reversed).rolling_mean(5, Axis::Rows).unwrap());
+        assert_eq!(pair_means.len(), 72);
+        for (row, window) in pair_means.chunks(2).enumerate() {
+            assert_eq!(window, &[means[row], reversed_means[row]]);
+        }
+        assert!(rows.rolling_mean(41, Axis::Columns).is_err());
+        assert!(rows.rolling_mean(0, Axis::Columns).is_err());
+        assert!(rows.rolling_std(1, Axis::Columns).is_err());
+    }
+
+    #[test]
+    fn non_finite_values_only_affect_their_windows() {
+        let m = column(&[1.0, f64::NAN, 3.0, 4.0, 5.0]);
+        let means = values(&m.rolling_mean(2, Axis::Rows).unwrap());
+        assert!(same(&means, &[f64::NAN, f64::NAN, 3.5, 4.5]));
+        let stds = values(&m.rolling_std(2, Axis::Rows).unwrap());
+        let half = 0.5f64.sqrt();
+        assert!(same(&stds, &[f64::NAN, f64::NAN, half, half]));
+
+        let m = column(&[f64::INFINITY, 2.0, 4.0, f64::NEG_INFINITY, 1.0, 3.0]);
+        let means = values(&m.rolling_mean(2, Axis::Rows).unwrap());
+        assert!(same(
+            &means,
+            &[
+                f64::INFINITY,
+                3.0,
+                f64::NEG_INFINITY,
+                f64::NEG_INFINITY,
+                2.0
+            ]
+        ));
+
+        let m = column(&[f64::NAN, f64::NAN, 6.0, 8.0]);
+        let means = values(&m.rolling_mean(2, Axis::Rows).unwrap());
+        assert!(same(&means, &[f64::NAN, f64::NAN, 7.0]));
+
+        let m = MatrixContent::new(
+            Dimension::new(2, 3),
+            vec![1.0, f64::INFINITY, f64::NAN, 2.0, 3.0, 4.0],
+        );
+        let means = values(&m.rolling_mean(2, Axis::Rows).unwrap());
+        assert!(same(&means, &[f64::NAN, f64::INFINITY, f64::NAN, 3.0]));
+    }
+}
@@ -0,0 +1,104 @@
+use super::{MatrixContent, MisAlignment};
+
+/// `P A = L U` with partial pivoting, `L` unit lower triangular and `U` upper triangular, both
+/// packed into one matrix.
+#[derive(Debug, Clone)]
+pub struct LuDecomposition {
+    factors: MatrixContent<f64>,
+    /// Row `i` of `P A` is row `permutation[i]` of `A`.
+    permutation: Vec<isize>,
+    sign: f64,
+}
+
+impl MatrixContent<f64> {
+    /// Returns `None` if the matrix is not square or is singular.
+    pub fn lu(&self) -> Option<LuDecomposition> {
+        if self.width() != self.height() {
+            return None;
+        }
+
+        let n = self.height();
+        let mut factors = self.clone();
+        let mut permutation: Vec<isize> = (0..n).collect();
+        let mut sign = 1.0;
+        for col in 0..n {
+            let pivot = (col..n)
+                .max_by(|&a, &b| factors[(a, col)].abs().total_cmp(&factors[(b, col)].abs()))?;
+            if factors[(pivot, col)] == 0.0 {
+                return None;
+            }
+            if pivot != col {
+                factors.swap_rows(pivot, col);
+                permutation.swap(pivot as usize, col as usize);
+                sign = -sign;
+            }
+
+            let diagonal = factors[(col, col)];
+            for row in col + 1..n {
+                let multiplier = factors[(row, col)] / diagonal;
+                factors[(row, col)] = multiplier;
+                for k in col + 1..n {
+                    let update = multiplier * factors[(col, k)];
+                    factors[(row, k)] -= update;
+                }
+            }
+        }
+
+        Some(LuDecomposition {
+            factors,
+            permutation,
+            sign,
+        })
+    }
+}
+
+impl LuDecomposition {
+    pub fn size(&self) -> isize {
+        self.factors.height()
+    }
+
+    /// Solves `A x = b`.
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MisAlignment> {
+        let n = self.size();
+        if b.len() != n as usize {
+            return Err(MisAlignment);
+        }
+
+        let mut x: Vec<f64> = self.permutation.iter().map(|&i| b[i as usize]).collect();
+        for row in 0..n {
+            for k in 0..row {
+                x[row as usize] -= self.factors[(row, k)] * x[k as usize];
+            }
+        }
+        for row in (0..n).rev() {
+            for k in row + 1..n {
+                x[row as usize] -= self.factors[(row, k)] * x[k as usize];
+            }
+            x[row as usize] /= self.factors[(row, row)];
+        }
+        Ok(x)
+    }
+
+    /// Solves `A X = B` column by column.
+    pub fn solve_matrix(&self, b: &MatrixContent<f64>) -> Result<MatrixContent<f64>, MisAlignment> {
+        if b.height() != self.size() {
+            return Err(MisAlignment);
+        }
+
+        let columns = (0..b.width())
+            .map(|col| self.solve(&b.column_iter(col).copied().collect::<Vec<_>>()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(MatrixContent::from_fn(b.dimension.clone(), |row, col| {
+            columns[col as usize][row as usize]
+        }))
+    }
+
+    pub fn determinant(&self) -> f64 {
+        (0..self.size()).fold(self.sign, |acc, i| acc * self.factors[(i, i)])
+    }
+
+    pub fn inverse(&self) -> MatrixContent<f64> {
+        self.solve_matrix(&MatrixContent::identity(self.size()))
+            .expect("the identity has the size of the decomposition")
+    }
+}
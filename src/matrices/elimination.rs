@@ -0,0 +1,165 @@
+use crate::dimension::Dimension;
+use crate::scalar::Field;
+
+use super::MatrixContent;
+
+/// Reduced row echelon form together with the pivot column of each non-zero row.
+#[derive(Debug, Clone)]
+pub struct RowEchelon<T> {
+    pub reduced: MatrixContent<T>,
+    pub pivots: Vec<isize>,
+}
+
+impl<T: Field> MatrixContent<T> {
+    /// Gauss-Jordan elimination, applying every row operation to `companion` as well.
+    fn eliminate(&self, mut companion: Option<&mut MatrixContent<T>>) -> RowEchelon<T> {
+        let mut m = self.clone();
+        let mut pivots = Vec::new();
+        let mut pivot_row = 0;
+
+        for col in 0..m.width() {
+            if pivot_row == m.height() {
+                break;
+            }
+
+            let row = match (pivot_row..m.height()).find(|&row| !m[(row, col)].is_zero()) {
+                Some(row) => row,
+                None => continue,
+            };
+            m.swap_rows(pivot_row, row);
+            if let Some(c) = companion.as_deref_mut() {
+                c.swap_rows(pivot_row, row);
+            }
+
+            let scale = T::one() / m[(pivot_row, col)].clone();
+            scale_row(&mut m, pivot_row, &scale);
+            if let Some(c) = companion.as_deref_mut() {
+                scale_row(c, pivot_row, &scale);
+            }
+
+            for other in 0..m.height() {
+                if other != pivot_row && !m[(other, col)].is_zero() {
+                    let factor = m[(other, col)].clone();
+                    subtract_row_multiple(&mut m, other, pivot_row, &factor);
+                    if let Some(c) = companion.as_deref_mut() {
+                        subtract_row_multiple(c, other, pivot_row, &factor);
+                    }
+                }
+            }
+
+            pivots.push(col);
+            pivot_row += 1;
+        }
+
+        RowEchelon { reduced: m, pivots }
+    }
+
+    pub fn row_echelon(&self) -> RowEchelon<T> {
+        self.eliminate(None)
+    }
+
+    pub fn rank(&self) -> isize {
+        self.row_echelon().pivots.len() as isize
+    }
+
+    /// Inverse by Gauss-Jordan elimination, or `None` if the matrix is singular or not square.
+    pub fn inverse(&self) -> Option<MatrixContent<T>> {
+        if self.width() != self.height() {
+            return None;
+        }
+
+        let mut inverse = MatrixContent::identity(self.height());
+        let echelon = self.eliminate(Some(&mut inverse));
+        if echelon.pivots.len() as isize == self.height() {
+            Some(inverse)
+        } else {
+            None
+        }
+    }
+
+    /// Basis of the right nullspace `{x : A x = 0}`, one basis vector per row of the result.
+    pub fn nullspace(&self) -> MatrixContent<T> {
+        let RowEchelon { reduced, pivots } = self.row_echelon();
+        let free: Vec<isize> = (0..self.width()).filter(|c| !pivots.contains(c)).collect();
+
+        let mut basis =
+            MatrixContent::from_fn(Dimension::new(self.width(), free.len() as isize), |_, _| {
+                T::zero()
+            });
+        for (i, &free_col) in free.iter().enumerate() {
+            let i = i as isize;
+            basis[(i, free_col)] = T::one();
+            for (row, &pivot_col) in pivots.iter().enumerate() {
+                basis[(i, pivot_col)] = -reduced[(row as isize, free_col)].clone();
+            }
+        }
+        basis
+    }
+}
+
+fn scale_row<T: Field>(m: &mut MatrixContent<T>, row: isize, factor: &T) {
+    for col in 0..m.width() {
+        m[(row, col)] = m[(row, col)].clone() * factor.clone();
+    }
+}
+
+fn subtract_row_multiple<T: Field>(
+    m: &mut MatrixContent<T>,
+    target: isize,
+    source: isize,
+    factor: &T,
+) {
+    for col in 0..m.width() {
+        m[(target, col)] = m[(target, col)].clone() - factor.clone() * m[(source, col)].clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finite_field::Fp;
+    use crate::scalar::{One, Zero};
+
+    type F7 = Fp<7>;
+
+    fn matrix(height: isize, width: isize, values: &[i64]) -> MatrixContent<F7> {
+        MatrixContent::new(
+            Dimension::new(width, height),
+            values.iter().map(|&v| F7::from(v)).collect(),
+        )
+    }
+
+    #[test]
+    fn inverse_over_a_prime_field() {
+        let a = matrix(3, 3, &[1, 2, 3, 0, 1, 4, 5, 6, 0]);
+        let inverse = a.inverse().unwrap();
+        assert_eq!(&a * &inverse, MatrixContent::identity(3));
+        assert_eq!(&inverse * &a, MatrixContent::identity(3));
+        assert!(matrix(2, 2, &[1, 2, 2, 4]).inverse().is_none());
+        assert!(matrix(2, 3, &[1, 0, 0, 0, 1, 0]).inverse().is_none());
+    }
+
+    #[test]
+    fn rank_and_nullspace() {
+        // The third row is the sum of the first two.
+        let a = matrix(3, 4, &[1, 2, 0, 3, 0, 1, 1, 1, 1, 3, 1, 4]);
+        assert_eq!(a.rank(), 2);
+
+        let RowEchelon { reduced, pivots } = a.row_echelon();
+        assert_eq!(pivots, vec![0, 1]);
+        assert!(reduced[(0, 0)] == F7::one() && reduced[(1, 1)] == F7::one());
+
+        let nullspace = a.nullspace();
+        assert_eq!(nullspace.height(), 2);
+        assert_eq!(nullspace.rank(), 2);
+        for row in 0..nullspace.height() {
+            for a_row in 0..a.height() {
+                let mut dot = F7::zero();
+                for col in 0..a.width() {
+                    dot += a[(a_row, col)] * nullspace[(row, col)];
+                }
+                assert!(dot.is_zero());
+            }
+        }
+    }
+}
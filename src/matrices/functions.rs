@@ -0,0 +1,523 @@
+use super::{GenericMatrix, MatrixContent, MisAlignment};
+
+/// Padé coefficients `b_0..=b_m` of `exp` for the degrees used by [`GenericMatrix::expm`], with
+/// the largest 1-norm each degree is accurate to double precision for.
+const PADE: [(f64, &[f64]); 4] = [
+    (1.495585217958292e-2, &[120.0, 60.0, 12.0, 1.0]),
+    (
+        2.53939833006323e-1,
+        &[30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0],
+    ),
+    (
+        9.504178996162932e-1,
+        &[
+            17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0,
+        ],
+    ),
+    (
+        2.097847961257068e0,
+        &[
+            17643225600.0,
+            8821612800.0,
+            2075673600.0,
+            302702400.0,
+            30270240.0,
+            2162160.0,
+            110880.0,
+            3960.0,
+            90.0,
+            1.0,
+        ],
+    ),
+];
+
+const PADE_13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+
+const THETA_13: f64 = 5.371920351148152;
+
+impl GenericMatrix {
+    /// Matrix exponential by scaling and squaring with a Padé approximant of degree 3 to 13,
+    /// chosen from the 1-norm as in Higham (2005).
+    ///
+    /// A matrix with an infinite or NaN entry has no meaningful exponential, and the result is
+    /// then filled with NaN.
+    pub fn expm(&self) -> Result<GenericMatrix, MisAlignment> {
+        if self.width() != self.height() {
+            return Err(MisAlignment);
+        }
+
+        let a = &self.content;
+        if a.entries().any(|x| !x.is_finite()) {
+            return Ok(GenericMatrix::from_content(MatrixContent::from_fn(
+                self.dimension.clone(),
+                |_, _| f64::NAN,
+            )));
+        }
+        let norm = a.one_norm();
+        let identity = MatrixContent::identity(a.height());
+        let a2 = product(a, a);
+
+        for (theta, b) in PADE {
+            if norm <= theta {
+                // U = A (b_1 I + b_3 A^2 + ...), V = b_0 I + b_2 A^2 + ...
+                let mut odd = scaled(&identity, b[b.len() - 1]);
+                let mut even = scaled(&identity, b[b.len() - 2]);
+                for i in (0..b.len() - 2).rev().step_by(2) {
+                    even = combine(&product(&even, &a2), 1.0, &identity, b[i - 1]);
+                    odd = combine(&product(&odd, &a2), 1.0, &identity, b[i]);
+                }
+                return Ok(GenericMatrix::from_content(pade_quotient(
+                    &product(a, &odd),
+                    &even,
+                )));
+            }
+        }
+
+        let squarings = (norm / THETA_13).log2().ceil().max(0.0) as i32;
+        let scale = 0.5f64.powi(squarings);
+        let a = scaled(a, scale);
+        let a2 = scaled(&a2, scale * scale);
+        let a4 = product(&a2, &a2);
+        let a6 = product(&a4, &a2);
+        let b = PADE_13;
+
+        let inner_odd = combine3(&a6, b[13], &a4, b[11], &a2, b[9]);
+        let odd = combine(
+            &product(&a6, &inner_odd),
+            1.0,
+            &combine3(&a6, b[7], &a4, b[5], &a2, b[3]),
+            1.0,
+        );
+        let odd = product(&a, &combine(&odd, 1.0, &identity, b[1]));
+        let inner_even = combine3(&a6, b[12], &a4, b[10], &a2, b[8]);
+        let even = combine(
+            &product(&a6, &inner_even),
+            1.0,
+            &combine3(&a6, b[6], &a4, b[4], &a2, b[2]),
+            1.0,
+        );
+        let even = combine(&even, 1.0, &identity, b[0]);
+
+        let mut result = pade_quotient(&odd, &even);
+        for _ in 0..squarings {
+            result = product(&result, &result);
+        }
+        Ok(GenericMatrix::from_content(result))
+    }
+
+    /// Principal logarithm by inverse scaling and squaring: square roots are taken until the
+    /// matrix is close to the identity, where a Gauss-Legendre (Padé) approximant of
+    /// `log(I + X)` is accurate.
+    ///
+    /// Returns `None` if the matrix is not square, or has eigenvalues on the closed negative
+    /// real axis so that the principal logarithm does not exist.
+    pub fn logm(&self) -> Option<GenericMatrix> {
+        if self.width() != self.height() {
+            return None;
+        }
+
+        let n = self.height();
+        let identity = MatrixContent::identity(n);
+        let mut a = self.content.clone();
+        let mut roots = 0;
+        while combine(&a, 1.0, &identity, -1.0).one_norm() > 0.25 {
+            if roots == 64 {
+                return None;
+            }
+            a = denman_beavers(&a)?;
+            roots += 1;
+        }
+
+        let x = combine(&a, 1.0, &identity, -1.0);
+        let mut log = MatrixContent::from_fn(x.dimension.clone(), |_, _| 0.0);
+        for (node, weight) in gauss_legendre(8) {
+            // log(I + X) = integral over [0, 1] of X (I + t X)^-1 dt
+            let t = (node + 1.0) / 2.0;
+            let shifted = combine(&identity, 1.0, &x, t);
+            let term = shifted.lu()?.solve_matrix(&x).ok()?;
+            log = combine(&log, 1.0, &term, weight / 2.0);
+        }
+        Some(GenericMatrix::from_content(scaled(&log, 2f64.powi(roots))))
+    }
+
+    /// Principal square root by the Denman-Beavers iteration.
+    ///
+    /// Returns `None` if the matrix is not square, or is singular or has eigenvalues on the
+    /// negative real axis so that the iteration breaks down or does not converge.
+    pub fn sqrtm(&self) -> Option<GenericMatrix> {
+        if self.width() != self.height() {
+            return None;
+        }
+        denman_beavers(&self.content).map(GenericMatrix::from_content)
+    }
+
+    /// `A^n` by repeated squaring, inverting first for negative `n`.
+    ///
+    /// Returns `None` if the matrix is not square, or `n` is negative and the matrix is singular.
+    pub fn powi(&self, n: i32) -> Option<GenericMatrix> {
+        if self.width() != self.height() {
+            return None;
+        }
+
+        let mut base = if n < 0 {
+            self.content.lu()?.inverse()
+        } else {
+            self.content.clone()
+        };
+        let mut result = MatrixContent::identity(self.height());
+        let mut exponent = n.unsigned_abs();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = product(&result, &base);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = product(&base, &base);
+            }
+        }
+        Some(GenericMatrix::from_content(result))
+    }
+
+    /// `A^p` for a symmetric positive definite matrix, through its eigendecomposition
+    /// `V diag(l^p) V^T`.
+    ///
+    /// Returns `None` if the matrix is not symmetric positive definite.
+    pub fn powf(&self, p: f64) -> Option<GenericMatrix> {
+        let a = &self.content;
+        let n = a.height();
+        if a.width() != n {
+            return None;
+        }
+        let scale = a.one_norm();
+        for i in 0..n {
+            for j in 0..i {
+                if (a[(i, j)] - a[(j, i)]).abs() > 1e-12 * scale {
+                    return None;
+                }
+            }
+        }
+
+        let (values, vectors) = symmetric_eigen(a);
+        if values.iter().any(|&v| v <= 0.0) {
+            return None;
+        }
+        let powers: Vec<f64> = values.iter().map(|v| v.powf(p)).collect();
+        Some(GenericMatrix::from_content(MatrixContent::from_fn(
+            a.dimension.clone(),
+            |row, col| {
+                powers
+                    .iter()
+                    .enumerate()
+                    .map(|(k, power)| {
+                        vectors[(row, k as isize)] * power * vectors[(col, k as isize)]
+                    })
+                    .sum()
+            },
+        )))
+    }
+}
+
+/// Solves `(V - U) X = V + U` for the Padé approximant `X`.
+fn pade_quotient(u: &MatrixContent<f64>, v: &MatrixContent<f64>) -> MatrixContent<f64> {
+    let numerator = combine(v, 1.0, u, 1.0);
+    let denominator = combine(v, 1.0, u, -1.0);
+    // The denominator is well conditioned for the norms each degree is used for.
+    denominator
+        .lu()
+        .and_then(|lu| lu.solve_matrix(&numerator).ok())
+        .expect("the Padé denominator is nonsingular")
+}
+
+/// Denman-Beavers iteration `Y <- (Y + Z^-1) / 2`, `Z <- (Z + Y^-1) / 2` converging to
+/// `(A^1/2, A^-1/2)`.
+fn denman_beavers(a: &MatrixContent<f64>) -> Option<MatrixContent<f64>> {
+    let mut y = a.clone();
+    let mut z = MatrixContent::identity(a.height());
+    for _ in 0..100 {
+        let y_inverse = y.lu()?.inverse();
+        let z_inverse = z.lu()?.inverse();
+        let next = combine(&y, 0.5, &z_inverse, 0.5);
+        z = combine(&z, 0.5, &y_inverse, 0.5);
+
+        let change = combine(&next, 1.0, &y, -1.0).one_norm();
+        y = next;
+        if change <= 1e-14 * y.one_norm() {
+            return Some(y);
+        }
+    }
+    None
+}
+
+/// Eigenvalues and eigenvectors (as columns) of a symmetric matrix by cyclic Jacobi rotations.
+fn symmetric_eigen(a: &MatrixContent<f64>) -> (Vec<f64>, MatrixContent<f64>) {
+    let n = a.height();
+    let mut a = a.clone();
+    let mut v = MatrixContent::identity(n);
+    for _ in 0..100 {
+        let off: f64 = (0..n)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .filter(|(i, j)| i != j)
+            .map(|index| a[index] * a[index])
+            .sum();
+        let diagonal: f64 = (0..n).map(|i| a[(i, i)] * a[(i, i)]).sum();
+        if off <= f64::EPSILON * f64::EPSILON * diagonal {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[(p, q)] == 0.0 {
+                    continue;
+                }
+                let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * a[(p, q)]);
+                let t = theta.signum() / (theta.abs() + theta.hypot(1.0));
+                let c = 1.0 / t.hypot(1.0);
+                let s = c * t;
+
+                // A <- J^T A J with the rotation J acting on rows and columns p and q.
+                for k in 0..n {
+                    let (x, y) = (a[(k, p)], a[(k, q)]);
+                    a[(k, p)] = c * x - s * y;
+                    a[(k, q)] = s * x + c * y;
+                }
+                for k in 0..n {
+                    let (x, y) = (a[(p, k)], a[(q, k)]);
+                    a[(p, k)] = c * x - s * y;
+                    a[(q, k)] = s * x + c * y;
+                }
+                for k in 0..n {
+                    let (x, y) = (v[(k, p)], v[(k, q)]);
+                    v[(k, p)] = c * x - s * y;
+                    v[(k, q)] = s * x + c * y;
+                }
+            }
+        }
+    }
+
+    ((0..n).map(|i| a[(i, i)]).collect(), v)
+}
+
+/// Nodes and weights of the `n` point Gauss-Legendre rule on `[-1, 1]`, by Newton's method on
+/// the Legendre polynomial.
+fn gauss_legendre(n: usize) -> Vec<(f64, f64)> {
+    (0..n)
+        .map(|i| {
+            let mut x = (std::f64::consts::PI * (i as f64 + 0.75) / (n as f64 + 0.5)).cos();
+            let mut derivative = 1.0;
+            for _ in 0..100 {
+                let (mut p, mut previous) = (1.0, 0.0);
+                for k in 1..=n {
+                    let k = k as f64;
+                    (p, previous) = (((2.0 * k - 1.0) * x * p - (k - 1.0) * previous) / k, p);
+                }
+                derivative = n as f64 * (x * p - previous) / (x * x - 1.0);
+                let step = p / derivative;
+                x -= step;
+                if step.abs() < 1e-16 {
+                    break;
+                }
+            }
+            (x, 2.0 / ((1.0 - x * x) * derivative * derivative))
+        })
+        .collect()
+}
+
+fn product(a: &MatrixContent<f64>, b: &MatrixContent<f64>) -> MatrixContent<f64> {
+    a * b
+}
+
+fn scaled(a: &MatrixContent<f64>, factor: f64) -> MatrixContent<f64> {
+    a.clone() * factor
+}
+
+/// `alpha a + beta b`
+fn combine(
+    a: &MatrixContent<f64>,
+    alpha: f64,
+    b: &MatrixContent<f64>,
+    beta: f64,
+) -> MatrixContent<f64> {
+    MatrixContent::from_fn(a.dimension.clone(), |row, col| {
+        alpha * a[(row, col)] + beta * b[(row, col)]
+    })
+}
+
+/// `alpha a + beta b + gamma c`
+fn combine3(
+    a: &MatrixContent<f64>,
+    alpha: f64,
+    b: &MatrixContent<f64>,
+    beta: f64,
+    c: &MatrixContent<f64>,
+    gamma: f64,
+) -> MatrixContent<f64> {
+    combine(&combine(a, alpha, b, beta), 1.0, c, gamma)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_matrix_approx_eq;
+    use crate::dimension::Dimension;
+    use crate::random::SplitMix64;
+
+    fn matrix(rows: Vec<Vec<f64>>) -> GenericMatrix {
+        GenericMatrix::try_from(rows).unwrap()
+    }
+
+    fn random(rng: &mut SplitMix64, n: isize, scale: f64) -> GenericMatrix {
+        GenericMatrix::from_content(MatrixContent::from_fn(Dimension::new(n, n), |_, _| {
+            scale * rng.next_normal()
+        }))
+    }
+
+    /// `B B^T + I`, symmetric positive definite.
+    fn spd(rng: &mut SplitMix64, n: isize) -> GenericMatrix {
+        let b = random(rng, n, 1.0);
+        let bbt = product(&b.content, &b.content.clone().into_transpose());
+        GenericMatrix::from_content(combine(&bbt, 1.0, &MatrixContent::identity(n), 1.0))
+    }
+
+    #[test]
+    fn expm_of_rotation_generators() {
+        // Norms on both sides of every Padé degree threshold and of the scaling branch.
+        for t in [0.0, 0.01, 0.2, 0.9, 2.0, 10.0, 100.0] {
+            let generator = matrix(vec![vec![0.0, -t], vec![t, 0.0]]);
+            let rotation = matrix(vec![vec![t.cos(), -t.sin()], vec![t.sin(), t.cos()]]);
+            assert_matrix_approx_eq!(generator.expm().unwrap(), rotation, 1e-12, 1e-9, "t = {t}");
+        }
+    }
+
+    #[test]
+    fn expm_of_diagonal_and_nilpotent_matrices() {
+        let diagonal = matrix(vec![vec![1.0, 0.0], vec![0.0, -3.0]]);
+        let expected = matrix(vec![vec![1f64.exp(), 0.0], vec![0.0, (-3f64).exp()]]);
+        assert_matrix_approx_eq!(diagonal.expm().unwrap(), expected);
+
+        let nilpotent = matrix(vec![vec![0.0, 5.0], vec![0.0, 0.0]]);
+        let expected = matrix(vec![vec![1.0, 5.0], vec![0.0, 1.0]]);
+        assert_matrix_approx_eq!(nilpotent.expm().unwrap(), expected);
+
+        assert!(matrix(vec![vec![1.0, 2.0]]).expm().is_err());
+    }
+
+    #[test]
+    fn expm_of_non_finite_matrices_is_nan() {
+        for bad in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+            let a = matrix(vec![vec![1.0, bad], vec![0.0, 2.0]]);
+            let result = a.expm().unwrap();
+            assert_eq!((result.height(), result.width()), (2, 2));
+            assert!(result.entries().all(|x| x.is_nan()));
+        }
+    }
+
+    #[test]
+    fn logm_inverts_expm() {
+        let mut rng = SplitMix64::new(40);
+        for scale in [0.05, 0.3, 0.6] {
+            // Small enough that every eigenvalue has |Im| < pi, so logm(expm(B)) = B.
+            let b = random(&mut rng, 5, scale);
+            let a = b.expm().unwrap();
+            let log = a.logm().unwrap();
+            assert_matrix_approx_eq!(log, b, 1e-10, 1e-8, "scale = {scale}");
+            assert_matrix_approx_eq!(log.expm().unwrap(), a, 1e-10, 1e-8);
+        }
+
+        let a = spd(&mut rng, 6);
+        assert_matrix_approx_eq!(a.logm().unwrap().expm().unwrap(), a, 1e-10, 1e-8);
+    }
+
+    #[test]
+    fn logm_rejects_the_negative_real_axis() {
+        assert!(matrix(vec![vec![-1.0, 0.0], vec![0.0, 2.0]])
+            .logm()
+            .is_none());
+        assert!(matrix(vec![vec![0.0, 0.0], vec![0.0, 2.0]])
+            .logm()
+            .is_none());
+        assert!(matrix(vec![vec![1.0, 2.0]]).logm().is_none());
+    }
+
+    #[test]
+    fn sqrtm_squares_back() {
+        let mut rng = SplitMix64::new(41);
+        let a = spd(&mut rng, 5);
+        let root = a.sqrtm().unwrap();
+        assert_matrix_approx_eq!(product(&root, &root), a.content, 1e-10, 1e-10);
+
+        // Upper triangular with positive eigenvalues 4 and 9.
+        let a = matrix(vec![vec![4.0, 10.0], vec![0.0, 9.0]]);
+        let expected = matrix(vec![vec![2.0, 2.0], vec![0.0, 3.0]]);
+        assert_matrix_approx_eq!(a.sqrtm().unwrap(), expected);
+
+        assert!(matrix(vec![vec![0.0, 0.0], vec![0.0, 1.0]])
+            .sqrtm()
+            .is_none());
+    }
+
+    #[test]
+    fn integer_powers() {
+        let mut rng = SplitMix64::new(42);
+        let a = random(&mut rng, 4, 1.0);
+        let cube = product(&product(&a, &a), &a);
+        assert_matrix_approx_eq!(a.powi(3).unwrap(), cube);
+        assert_matrix_approx_eq!(a.powi(0).unwrap(), MatrixContent::identity(4));
+        assert_matrix_approx_eq!(a.powi(1).unwrap(), a);
+
+        let inverse_square = a.powi(-2).unwrap();
+        let identity = product(&inverse_square, &a.powi(2).unwrap());
+        assert_matrix_approx_eq!(identity, MatrixContent::identity(4), 1e-10, 1e-10);
+
+        let singular = matrix(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+        assert!(singular.powi(-1).is_none());
+        assert!(singular.powi(2).is_some());
+    }
+
+    #[test]
+    fn real_powers_of_symmetric_positive_definite_matrices() {
+        let mut rng = SplitMix64::new(43);
+        let a = spd(&mut rng, 5);
+        assert_matrix_approx_eq!(a.powf(1.0).unwrap(), a, 1e-10, 1e-10);
+        assert_matrix_approx_eq!(a.powf(0.5).unwrap(), a.sqrtm().unwrap(), 1e-10, 1e-10);
+        assert_matrix_approx_eq!(a.powf(-1.0).unwrap(), a.powi(-1).unwrap(), 1e-10, 1e-10);
+
+        let third = a.powf(1.0 / 3.0).unwrap();
+        assert_matrix_approx_eq!(
+            product(&product(&third, &third), &third),
+            a.content,
+            1e-10,
+            1e-10
+        );
+
+        let nonsymmetric = matrix(vec![vec![2.0, 1.0], vec![0.0, 2.0]]);
+        assert!(nonsymmetric.powf(0.5).is_none());
+        let indefinite = matrix(vec![vec![1.0, 2.0], vec![2.0, 1.0]]);
+        assert!(indefinite.powf(0.5).is_none());
+    }
+
+    #[test]
+    fn gauss_legendre_is_exact_for_polynomials() {
+        let rule = gauss_legendre(8);
+        assert!((rule.iter().map(|(_, w)| w).sum::<f64>() - 2.0).abs() < 1e-14);
+        // An n point rule integrates polynomials of degree 2n - 1 exactly.
+        for degree in [2, 8, 14] {
+            let integral: f64 = rule.iter().map(|(x, w)| w * x.powi(degree)).sum();
+            assert!((integral - 2.0 / (degree + 1) as f64).abs() < 1e-14);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::ops::Mul;
+
+use crate::dimension::Dimension;
+use crate::scalar::One;
+
+use super::{MatrixContent, MisAlignment};
+
+impl<T: Clone> MatrixContent<T> {
+    /// Square circulant matrix whose columns are successive cyclic shifts of `first_col`.
+    pub fn circulant(first_col: &[T]) -> Self {
+        let n = first_col.len() as isize;
+        Self::from_fn(Dimension::new(n, n), |row, col| {
+            first_col[(row - col).rem_euclid(n) as usize].clone()
+        })
+    }
+}
+
+impl<T: Clone + PartialEq> MatrixContent<T> {
+    /// Toeplitz matrix, constant along each diagonal: `a[i][j] = first_col[i - j]` on and below
+    /// the diagonal and `first_row[j - i]` above it. `first_row[0]` must equal `first_col[0]`.
+    pub fn toeplitz(first_col: &[T], first_row: &[T]) -> Result<Self, MisAlignment> {
+        if first_col.is_empty() || first_row.is_empty() || first_col[0] != first_row[0] {
+            return Err(MisAlignment);
+        }
+
+        let dimension = Dimension::new(first_row.len() as isize, first_col.len() as isize);
+        Ok(Self::from_fn(dimension, |row, col| {
+            if row >= col {
+                first_col[(row - col) as usize].clone()
+            } else {
+                first_row[(col - row) as usize].clone()
+            }
+        }))
+    }
+
+    /// Hankel matrix, constant along each anti-diagonal: the first column is `first_col` and
+    /// the last row is `last_row`. `last_row[0]` must equal the last entry of `first_col`.
+    pub fn hankel(first_col: &[T], last_row: &[T]) -> Result<Self, MisAlignment> {
+        if first_col.is_empty() || last_row.is_empty() || first_col.last() != last_row.first() {
+            return Err(MisAlignment);
+        }
+
+        let height = first_col.len() as isize;
+        let dimension = Dimension::new(last_row.len() as isize, height);
+        Ok(Self::from_fn(dimension, |row, col| {
+            let k = row + col;
+            if k < height {
+                first_col[k as usize].clone()
+            } else {
+                last_row[(k - height + 1) as usize].clone()
+            }
+        }))
+    }
+}
+
+impl<T: Clone + One + Mul<Output = T>> MatrixContent<T> {
+    /// Vandermonde matrix with increasing powers, `a[i][j] = xs[i]^j` for `j` in `0..=degree`.
+    ///
+    /// Solving `V c = y` in the least-squares sense fits a polynomial with coefficients `c`.
+    pub fn vandermonde(xs: &[T], degree: usize) -> Self {
+        let mut buffer = Vec::with_capacity(xs.len() * (degree + 1));
+        for x in xs {
+            let mut power = T::one();
+            for _ in 0..=degree {
+                buffer.push(power.clone());
+                power = power * x.clone();
+            }
+        }
+        Self::new(
+            Dimension::new(degree as isize + 1, xs.len() as isize),
+            buffer,
+        )
+    }
+}
+
+impl MatrixContent<f64> {
+    /// Hilbert matrix `a[i][j] = 1 / (i + j + 1)`, a classic severely ill-conditioned input.
+    pub fn hilbert(n: usize) -> Self {
+        let n = n as isize;
+        Self::from_fn(Dimension::new(n, n), |row, col| {
+            1.0 / (row + col + 1) as f64
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrices::testing::rows;
+
+    #[test]
+    fn toeplitz_diagonals() {
+        let t = MatrixContent::toeplitz(&[1, 2, 3], &[1, 4, 5, 6]).unwrap();
+        assert_eq!(
+            rows(&t),
+            vec![vec![1, 4, 5, 6], vec![2, 1, 4, 5], vec![3, 2, 1, 4]]
+        );
+        assert!(MatrixContent::toeplitz(&[1, 2], &[9, 4]).is_err());
+        assert!(MatrixContent::<i64>::toeplitz(&[], &[1]).is_err());
+    }
+
+    #[test]
+    fn hankel_anti_diagonals() {
+        let h = MatrixContent::hankel(&[1, 2, 3], &[3, 4, 5]).unwrap();
+        assert_eq!(rows(&h), vec![vec![1, 2, 3], vec![2, 3, 4], vec![3, 4, 5]]);
+        let wide = MatrixContent::hankel(&[1, 2], &[2, 3, 4, 5]).unwrap();
+        assert_eq!(rows(&wide), vec![vec![1, 2, 3, 4], vec![2, 3, 4, 5]]);
+        assert!(MatrixContent::hankel(&[1, 2], &[3, 4]).is_err());
+    }
+
+    #[test]
+    fn circulant_shifts() {
+        let c = MatrixContent::circulant(&[1, 2, 3]);
+        assert_eq!(rows(&c), vec![vec![1, 3, 2], vec![2, 1, 3], vec![3, 2, 1]]);
+        assert_eq!(MatrixContent::<i64>::circulant(&[]).height(), 0);
+    }
+
+    #[test]
+    fn vandermonde_powers() {
+        let v = MatrixContent::vandermonde(&[2, -1, 3], 3);
+        assert_eq!(
+            rows(&v),
+            vec![vec![1, 2, 4, 8], vec![1, -1, 1, -1], vec![1, 3, 9, 27]]
+        );
+    }
+
+    #[test]
+    fn hilbert_entries() {
+        let h = MatrixContent::hilbert(3);
+        assert_eq!(h[(0, 0)], 1.0);
+        assert_eq!(h[(1, 2)], 0.25);
+        assert_eq!(h[(2, 1)], h[(1, 2)]);
+        assert_eq!(h[(2, 2)], 0.2);
+    }
+}
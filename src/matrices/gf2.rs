@@ -0,0 +1,371 @@
+use std::ops::{Add, Mul};
+
+use crate::dimension::Dimension;
+
+use super::MatrixContent;
+
+const WORD_BITS: isize = 64;
+
+/// Bit-packed matrix over GF(2).
+///
+/// Each row is stored as a run of `u64` words so that addition is a word-wise XOR and a row
+/// operation touches 64 entries at once.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Gf2Matrix {
+    dimension: Dimension,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl Gf2Matrix {
+    pub fn zeros(height: isize, width: isize) -> Self {
+        let words_per_row = ((width + WORD_BITS - 1) / WORD_BITS) as usize;
+        Self {
+            dimension: Dimension::new(width, height),
+            words_per_row,
+            words: vec![0; words_per_row * height as usize],
+        }
+    }
+
+    pub fn identity(size: isize) -> Self {
+        let mut m = Self::zeros(size, size);
+        for i in 0..size {
+            m.set(i, i, true);
+        }
+        m
+    }
+
+    pub fn width(&self) -> isize {
+        self.dimension.width()
+    }
+
+    pub fn height(&self) -> isize {
+        self.dimension.height()
+    }
+
+    fn row_words(&self, row: isize) -> &[u64] {
+        let start = row as usize * self.words_per_row;
+        &self.words[start..start + self.words_per_row]
+    }
+
+    fn row_words_mut(&mut self, row: isize) -> &mut [u64] {
+        let start = row as usize * self.words_per_row;
+        &mut self.words[start..start + self.words_per_row]
+    }
+
+    fn check(&self, row: isize, col: isize) {
+        assert!(
+            (0..self.height()).contains(&row) && (0..self.width()).contains(&col),
+            "index out of the bounds of the matrix"
+        );
+    }
+
+    pub fn get(&self, row: isize, col: isize) -> bool {
+        self.check(row, col);
+        let word = self.row_words(row)[(col / WORD_BITS) as usize];
+        (word >> (col % WORD_BITS)) & 1 == 1
+    }
+
+    pub fn set(&mut self, row: isize, col: isize, value: bool) {
+        self.check(row, col);
+        let mask = 1u64 << (col % WORD_BITS);
+        let word = &mut self.row_words_mut(row)[(col / WORD_BITS) as usize];
+        if value {
+            *word |= mask;
+        } else {
+            *word &= !mask;
+        }
+    }
+
+    /// XORs row `source` into row `target`.
+    fn add_row(&mut self, target: isize, source: isize) {
+        let (target, source) = (target as usize, source as usize);
+        let width = self.words_per_row;
+        for i in 0..width {
+            let word = self.words[source * width + i];
+            self.words[target * width + i] ^= word;
+        }
+    }
+
+    fn swap_rows(&mut self, a: isize, b: isize) {
+        let width = self.words_per_row;
+        for i in 0..width {
+            self.words
+                .swap(a as usize * width + i, b as usize * width + i);
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut result = Self::zeros(self.width(), self.height());
+        for row in 0..self.height() {
+            for col in 0..self.width() {
+                if self.get(row, col) {
+                    result.set(col, row, true);
+                }
+            }
+        }
+        result
+    }
+
+    /// Gauss-Jordan elimination, applying every row operation to `companion` as well.
+    fn eliminate(&self, mut companion: Option<&mut Gf2Matrix>) -> (Gf2Matrix, Vec<isize>) {
+        let mut m = self.clone();
+        let mut pivots = Vec::new();
+        let mut pivot_row = 0;
+
+        for col in 0..m.width() {
+            if pivot_row == m.height() {
+                break;
+            }
+
+            let row = match (pivot_row..m.height()).find(|&row| m.get(row, col)) {
+                Some(row) => row,
+                None => continue,
+            };
+            if row != pivot_row {
+                m.swap_rows(pivot_row, row);
+                if let Some(c) = companion.as_deref_mut() {
+                    c.swap_rows(pivot_row, row);
+                }
+            }
+
+            for other in 0..m.height() {
+                if other != pivot_row && m.get(other, col) {
+                    m.add_row(other, pivot_row);
+                    if let Some(c) = companion.as_deref_mut() {
+                        c.add_row(other, pivot_row);
+                    }
+                }
+            }
+
+            pivots.push(col);
+            pivot_row += 1;
+        }
+
+        (m, pivots)
+    }
+
+    /// Reduced row echelon form together with the pivot column of each non-zero row.
+    pub fn row_echelon(&self) -> (Gf2Matrix, Vec<isize>) {
+        self.eliminate(None)
+    }
+
+    pub fn rank(&self) -> isize {
+        self.eliminate(None).1.len() as isize
+    }
+
+    /// Inverse by Gauss-Jordan elimination, or `None` if the matrix is singular or not square.
+    pub fn inverse(&self) -> Option<Gf2Matrix> {
+        if self.width() != self.height() {
+            return None;
+        }
+
+        let mut inverse = Gf2Matrix::identity(self.height());
+        let (_, pivots) = self.eliminate(Some(&mut inverse));
+        if pivots.len() as isize == self.height() {
+            Some(inverse)
+        } else {
+            None
+        }
+    }
+
+    /// Basis of the right nullspace `{x : A x = 0}`, one basis vector per row of the result.
+    ///
+    /// For a generator matrix this yields a parity-check matrix of the code.
+    pub fn nullspace(&self) -> Gf2Matrix {
+        let (reduced, pivots) = self.row_echelon();
+        let free: Vec<isize> = (0..self.width()).filter(|c| !pivots.contains(c)).collect();
+
+        let mut basis = Gf2Matrix::zeros(free.len() as isize, self.width());
+        for (i, &free_col) in free.iter().enumerate() {
+            let i = i as isize;
+            basis.set(i, free_col, true);
+            for (row, &pivot_col) in pivots.iter().enumerate() {
+                if reduced.get(row as isize, free_col) {
+                    basis.set(i, pivot_col, true);
+                }
+            }
+        }
+        basis
+    }
+}
+
+impl From<&MatrixContent<bool>> for Gf2Matrix {
+    fn from(value: &MatrixContent<bool>) -> Self {
+        let mut m = Gf2Matrix::zeros(value.height(), value.width());
+        for row in 0..value.height() {
+            for col in 0..value.width() {
+                if value[(row, col)] {
+                    m.set(row, col, true);
+                }
+            }
+        }
+        m
+    }
+}
+
+impl From<&Gf2Matrix> for MatrixContent<bool> {
+    fn from(value: &Gf2Matrix) -> Self {
+        MatrixContent::from_fn(value.dimension.clone(), |row, col| value.get(row, col))
+    }
+}
+
+impl<'a> Add<&'a Gf2Matrix> for &Gf2Matrix {
+    type Output = Gf2Matrix;
+
+    // Addition over GF(2) is exclusive or.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: &'a Gf2Matrix) -> Self::Output {
+        if self.dimension != rhs.dimension {
+            panic!("Dimensions must be equal in order to add two matrices");
+        }
+
+        let mut result = self.clone();
+        result
+            .words
+            .iter_mut()
+            .zip(rhs.words.iter())
+            .for_each(|(a, b)| *a ^= b);
+        result
+    }
+}
+
+impl<'a> Mul<&'a Gf2Matrix> for &Gf2Matrix {
+    type Output = Gf2Matrix;
+
+    /// Row-oriented product: each set bit `(i, k)` of the left matrix XORs row `k` of the right
+    /// matrix into row `i` of the result, 64 columns per word operation.
+    fn mul(self, rhs: &'a Gf2Matrix) -> Self::Output {
+        if self.width() != rhs.height() {
+            panic!("The width of the left matrix must equal the height of the right matrix in order to multiply them");
+        }
+
+        let mut result = Gf2Matrix::zeros(self.height(), rhs.width());
+        let width = rhs.words_per_row;
+        for row in 0..self.height() {
+            for (w, &word) in self.row_words(row).iter().enumerate() {
+                let mut bits = word;
+                while bits != 0 {
+                    let k = w as isize * WORD_BITS + bits.trailing_zeros() as isize;
+                    bits &= bits - 1;
+                    let source = rhs.row_words(k);
+                    let target =
+                        &mut result.words[row as usize * width..(row as usize + 1) * width];
+                    target.iter_mut().zip(source).for_each(|(t, s)| *t ^= s);
+                }
+            }
+        }
+        result
+    }
+}
+
+impl std::fmt::Display for Gf2Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::new();
+        for row in 0..self.height() {
+            for col in 0..self.width() {
+                output.push_str(if self.get(row, col) { "1, " } else { "0, " });
+            }
+            output.pop();
+            output.pop();
+            output.push('\n');
+        }
+        output.pop();
+        write!(f, "{}", output)
+    }
+}
+
+impl std::fmt::Debug for Gf2Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::SplitMix64;
+
+    fn from_rows(rows: &[&str]) -> Gf2Matrix {
+        let width = rows[0].len() as isize;
+        let mut m = Gf2Matrix::zeros(rows.len() as isize, width);
+        for (row, bits) in rows.iter().enumerate() {
+            for (col, bit) in bits.chars().enumerate() {
+                m.set(row as isize, col as isize, bit == '1');
+            }
+        }
+        m
+    }
+
+    fn random(rng: &mut SplitMix64, height: isize, width: isize) -> Gf2Matrix {
+        let mut m = Gf2Matrix::zeros(height, width);
+        for row in 0..height {
+            for col in 0..width {
+                m.set(row, col, rng.next_u64() >> 63 == 1);
+            }
+        }
+        m
+    }
+
+    #[test]
+    fn product_matches_bool_arithmetic() {
+        let mut rng = SplitMix64::new(3);
+        let (a, b) = (random(&mut rng, 9, 70), random(&mut rng, 70, 130));
+        let product = &a * &b;
+        for row in 0..9 {
+            for col in 0..130 {
+                let expected = (0..70).fold(false, |acc, k| acc ^ (a.get(row, k) & b.get(k, col)));
+                assert_eq!(product.get(row, col), expected);
+            }
+        }
+        assert_eq!(&a + &a, Gf2Matrix::zeros(9, 70));
+        assert_eq!(a.transpose().transpose(), a);
+    }
+
+    #[test]
+    fn hamming_code_parity_check() {
+        let generator = from_rows(&["1000110", "0100101", "0010011", "0001111"]);
+        assert_eq!(generator.rank(), 4);
+        let parity = generator.nullspace();
+        assert_eq!(parity.height(), 3);
+        assert_eq!(parity.rank(), 3);
+        assert_eq!(&generator * &parity.transpose(), Gf2Matrix::zeros(4, 3));
+    }
+
+    #[test]
+    fn inverse() {
+        let mut rng = SplitMix64::new(5);
+        let mut found = 0;
+        while found < 5 {
+            let a = random(&mut rng, 80, 80);
+            match a.inverse() {
+                Some(inverse) => {
+                    assert_eq!(&a * &inverse, Gf2Matrix::identity(80));
+                    found += 1;
+                }
+                None => assert!(a.rank() < 80),
+            }
+        }
+        assert!(from_rows(&["11", "11"]).inverse().is_none());
+    }
+
+    #[test]
+    fn conversion_round_trip() {
+        let m = from_rows(&["101", "011"]);
+        let dense = MatrixContent::<bool>::from(&m);
+        assert!(dense[(0, 0)] && !dense[(0, 1)] && dense[(1, 2)]);
+        assert_eq!(Gf2Matrix::from(&dense), m);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of the bounds of the matrix")]
+    fn set_rejects_padding_bits() {
+        Gf2Matrix::zeros(1, 3).set(0, 10, true);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of the bounds of the matrix")]
+    fn get_rejects_rows_past_the_end() {
+        Gf2Matrix::zeros(2, 3).get(2, 0);
+    }
+}
@@ -0,0 +1,456 @@
+use std::ops::{Index, IndexMut};
+
+use crate::dimension::Dimension;
+
+use super::{Layout, MatrixContent, MisAlignment};
+
+/// Row-major matrix backed by a `Vec`, for building a matrix up incrementally.
+///
+/// Appending a row reuses the spare capacity of the buffer, so streaming `n` rows in costs
+/// amortized `O(width)` each. Inserting or removing anywhere else, and every column operation,
+/// moves the cells after the change and costs `O(width * height)`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct GrowableMatrix<T> {
+    dimension: Dimension,
+    buffer: Vec<T>,
+}
+
+impl<T> GrowableMatrix<T> {
+    /// An empty `0 x 0` matrix; the first row or column pushed decides the other dimension.
+    pub fn new() -> Self {
+        Self::with_capacity(0, 0)
+    }
+
+    /// An empty matrix with `width` columns and room for `rows` rows.
+    pub fn with_capacity(width: isize, rows: isize) -> Self {
+        Self {
+            dimension: Dimension::new(width, 0),
+            buffer: Vec::with_capacity((width * rows) as usize),
+        }
+    }
+
+    pub fn width(&self) -> isize {
+        self.dimension.width()
+    }
+
+    pub fn height(&self) -> isize {
+        self.dimension.height()
+    }
+
+    pub fn dimension(&self) -> &Dimension {
+        &self.dimension
+    }
+
+    /// Makes room for at least `additional` more rows.
+    pub fn reserve_rows(&mut self, additional: isize) {
+        self.buffer.reserve((additional * self.width()) as usize);
+    }
+
+    /// The cells in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.buffer
+    }
+
+    /// The cells of a row. Negative indices count from the last row, as for `MatrixContent`.
+    ///
+    /// # Panics
+    ///
+    /// If the row is out of bounds.
+    pub fn row(&self, row: isize) -> &[T] {
+        let width = self.width() as usize;
+        let start = self.checked_row(row) * width;
+        &self.buffer[start..start + width]
+    }
+
+    /// Mutable cells of a row, indexed like [`GrowableMatrix::row`].
+    ///
+    /// # Panics
+    ///
+    /// If the row is out of bounds.
+    pub fn row_mut(&mut self, row: isize) -> &mut [T] {
+        let width = self.width() as usize;
+        let start = self.checked_row(row) * width;
+        &mut self.buffer[start..start + width]
+    }
+
+    /// Buffer row of `row`, counting negative indices from the end.
+    fn checked_row(&self, row: isize) -> usize {
+        let reflected = if row < 0 { self.height() + row } else { row };
+        assert!(
+            (0..self.height()).contains(&reflected),
+            "row {} out of bounds for a matrix with {} rows",
+            row,
+            self.height()
+        );
+        reflected as usize
+    }
+
+    /// Buffer column of `col`, counting negative indices from the end.
+    fn checked_column(&self, col: isize) -> usize {
+        let reflected = if col < 0 { self.width() + col } else { col };
+        assert!(
+            (0..self.width()).contains(&reflected),
+            "column {} out of bounds for a matrix with {} columns",
+            col,
+            self.width()
+        );
+        reflected as usize
+    }
+
+    fn is_unshaped(&self) -> bool {
+        self.width() == 0 && self.height() == 0
+    }
+
+    /// Appends a row, whose length must equal the width.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) -> Result<(), MisAlignment> {
+        let height = self.height();
+        self.insert_row(height, row)
+    }
+
+    /// Inserts a row so that it ends up at index `at`, shifting the following rows down.
+    pub fn insert_row(
+        &mut self,
+        at: isize,
+        row: impl IntoIterator<Item = T>,
+    ) -> Result<(), MisAlignment> {
+        if !(0..=self.height()).contains(&at) {
+            return Err(MisAlignment);
+        }
+
+        let row: Vec<T> = row.into_iter().collect();
+        if self.is_unshaped() {
+            self.dimension = Dimension::new(row.len() as isize, 0);
+        }
+        if row.len() != self.width() as usize {
+            return Err(MisAlignment);
+        }
+
+        let start = (at * self.width()) as usize;
+        self.buffer.splice(start..start, row);
+        self.dimension = Dimension::new(self.width(), self.height() + 1);
+        Ok(())
+    }
+
+    /// Appends a column, whose length must equal the height.
+    pub fn push_column(&mut self, column: impl IntoIterator<Item = T>) -> Result<(), MisAlignment> {
+        let width = self.width();
+        self.insert_column(width, column)
+    }
+
+    /// Inserts a column so that it ends up at index `at`, shifting the following columns right.
+    pub fn insert_column(
+        &mut self,
+        at: isize,
+        column: impl IntoIterator<Item = T>,
+    ) -> Result<(), MisAlignment> {
+        if !(0..=self.width()).contains(&at) {
+            return Err(MisAlignment);
+        }
+
+        let column: Vec<T> = column.into_iter().collect();
+        if self.is_unshaped() {
+            self.dimension = Dimension::new(0, column.len() as isize);
+        }
+        if column.len() != self.height() as usize {
+            return Err(MisAlignment);
+        }
+
+        let width = self.width() as usize;
+        let old = std::mem::take(&mut self.buffer);
+        self.buffer = Vec::with_capacity(old.len() + column.len());
+        let mut cells = old.into_iter();
+        for value in column {
+            self.buffer.extend(cells.by_ref().take(at as usize));
+            self.buffer.push(value);
+            self.buffer.extend(cells.by_ref().take(width - at as usize));
+        }
+        self.dimension = Dimension::new(self.width() + 1, self.height());
+        Ok(())
+    }
+
+    /// Removes and returns the row at index `at`.
+    pub fn remove_row(&mut self, at: isize) -> Result<Vec<T>, MisAlignment> {
+        if !(0..self.height()).contains(&at) {
+            return Err(MisAlignment);
+        }
+
+        let width = self.width() as usize;
+        let start = at as usize * width;
+        let removed = self.buffer.drain(start..start + width).collect();
+        self.dimension = Dimension::new(self.width(), self.height() - 1);
+        Ok(removed)
+    }
+
+    /// Removes and returns the column at index `at`.
+    pub fn remove_column(&mut self, at: isize) -> Result<Vec<T>, MisAlignment> {
+        if !(0..self.width()).contains(&at) {
+            return Err(MisAlignment);
+        }
+
+        let width = self.width() as usize;
+        let old = std::mem::take(&mut self.buffer);
+        let mut removed = Vec::with_capacity(self.height() as usize);
+        self.buffer = Vec::with_capacity(old.len() - self.height() as usize);
+        for (i, value) in old.into_iter().enumerate() {
+            if i % width == at as usize {
+                removed.push(value);
+            } else {
+                self.buffer.push(value);
+            }
+        }
+        self.dimension = Dimension::new(self.width() - 1, self.height());
+        Ok(removed)
+    }
+
+    /// Exchanges two rows in place. Negative indices count from the last row.
+    ///
+    /// # Panics
+    ///
+    /// If either row is out of bounds.
+    pub fn swap_rows(&mut self, a: isize, b: isize) {
+        let (a, b) = (self.checked_row(a), self.checked_row(b));
+        if a != b {
+            let width = self.width() as usize;
+            let (low, high) = (a.min(b), a.max(b));
+            let (head, tail) = self.buffer.split_at_mut(high * width);
+            head[low * width..(low + 1) * width].swap_with_slice(&mut tail[..width]);
+        }
+    }
+
+    /// Exchanges two columns in place. Negative indices count from the last column.
+    ///
+    /// # Panics
+    ///
+    /// If either column is out of bounds.
+    pub fn swap_columns(&mut self, a: isize, b: isize) {
+        let (a, b) = (self.checked_column(a), self.checked_column(b));
+        if a != b {
+            let width = self.width() as usize;
+            for row in self.buffer.chunks_exact_mut(width) {
+                row.swap(a, b);
+            }
+        }
+    }
+
+    /// Keeps only the rows for which `predicate` returns `true`, preserving their order.
+    pub fn retain_rows(&mut self, mut predicate: impl FnMut(&[T]) -> bool) {
+        let width = self.width() as usize;
+        let mut kept = 0;
+        for row in 0..self.height() as usize {
+            if predicate(&self.buffer[row * width..(row + 1) * width]) {
+                if kept != row {
+                    let (head, tail) = self.buffer.split_at_mut(row * width);
+                    head[kept * width..(kept + 1) * width].swap_with_slice(&mut tail[..width]);
+                }
+                kept += 1;
+            }
+        }
+        self.buffer.truncate(kept * width);
+        self.dimension = Dimension::new(self.width(), kept as isize);
+    }
+}
+
+impl<T> Default for GrowableMatrix<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Index<(isize, isize)> for GrowableMatrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (isize, isize)) -> &Self::Output {
+        let col = self.checked_column(col);
+        &self.row(row)[col]
+    }
+}
+
+impl<T> IndexMut<(isize, isize)> for GrowableMatrix<T> {
+    fn index_mut(&mut self, (row, col): (isize, isize)) -> &mut Self::Output {
+        let col = self.checked_column(col);
+        &mut self.row_mut(row)[col]
+    }
+}
+
+impl<T> From<MatrixContent<T>> for GrowableMatrix<T> {
+    fn from(value: MatrixContent<T>) -> Self {
+        let value = value.into_layout(Layout::RowMajor);
+        Self {
+            dimension: value.dimension.clone(),
+            buffer: value.into_buffer().into_vec(),
+        }
+    }
+}
+
+impl<T> From<GrowableMatrix<T>> for MatrixContent<T> {
+    fn from(value: GrowableMatrix<T>) -> Self {
+        MatrixContent::new(value.dimension, value.buffer)
+    }
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for GrowableMatrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::new();
+        for row in 0..self.height() {
+            for item in self.row(row) {
+                output.push_str(&format!("{}, ", item));
+            }
+            output.pop();
+            output.pop();
+            output.push('\n');
+        }
+        output.pop();
+        write!(f, "{}", output)
+    }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for GrowableMatrix<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::new();
+        for row in 0..self.height() {
+            for item in self.row(row) {
+                output.push_str(&format!("{:?}, ", item));
+            }
+            output.pop();
+            output.pop();
+            output.push('\n');
+        }
+        output.pop();
+        write!(f, "{}", output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(height: isize, width: isize) -> GrowableMatrix<i32> {
+        let mut matrix = GrowableMatrix::with_capacity(width, height);
+        for row in 0..height {
+            matrix
+                .push_row((0..width).map(|col| (row * 10 + col) as i32))
+                .unwrap();
+        }
+        matrix
+    }
+
+    #[test]
+    fn first_push_shapes_an_empty_matrix() {
+        let mut rows = GrowableMatrix::new();
+        rows.push_row([1, 2, 3]).unwrap();
+        rows.push_row([4, 5, 6]).unwrap();
+        assert_eq!((rows.width(), rows.height()), (3, 2));
+        assert_eq!(rows.as_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert!(rows.push_row([7, 8]).is_err());
+
+        let mut columns = GrowableMatrix::default();
+        columns.push_column([1, 2]).unwrap();
+        columns.push_column([3, 4]).unwrap();
+        assert_eq!((columns.width(), columns.height()), (2, 2));
+        assert_eq!(columns.as_slice(), &[1, 3, 2, 4]);
+        assert!(columns.push_column([5]).is_err());
+    }
+
+    #[test]
+    fn insert_and_remove_rows() {
+        let mut matrix = grid(3, 2);
+        matrix.insert_row(1, [-1, -2]).unwrap();
+        assert_eq!(matrix.as_slice(), &[0, 1, -1, -2, 10, 11, 20, 21]);
+        assert!(matrix.insert_row(5, [0, 0]).is_err());
+
+        assert_eq!(matrix.remove_row(0).unwrap(), vec![0, 1]);
+        assert_eq!(matrix.row(0), &[-1, -2]);
+        assert_eq!(matrix.height(), 3);
+        assert!(matrix.remove_row(3).is_err());
+    }
+
+    #[test]
+    fn insert_and_remove_columns() {
+        let mut matrix = grid(2, 3);
+        matrix.insert_column(0, [7, 8]).unwrap();
+        matrix.insert_column(4, [9, 9]).unwrap();
+        assert_eq!(matrix.as_slice(), &[7, 0, 1, 2, 9, 8, 10, 11, 12, 9]);
+        assert!(matrix.insert_column(6, [0, 0]).is_err());
+        assert!(matrix.insert_column(1, [0]).is_err());
+
+        assert_eq!(matrix.remove_column(2).unwrap(), vec![1, 11]);
+        assert_eq!(matrix.as_slice(), &[7, 0, 2, 9, 8, 10, 12, 9]);
+        assert_eq!(matrix.width(), 4);
+        assert!(matrix.remove_column(4).is_err());
+    }
+
+    #[test]
+    fn swap_and_retain() {
+        let mut matrix = grid(4, 2);
+        matrix.swap_rows(3, 0);
+        matrix.swap_columns(0, 1);
+        assert_eq!(matrix.as_slice(), &[31, 30, 11, 10, 21, 20, 1, 0]);
+
+        matrix.retain_rows(|row| row[0] > 5);
+        assert_eq!(matrix.as_slice(), &[31, 30, 11, 10, 21, 20]);
+        matrix.retain_rows(|row| row[1] != 30);
+        assert_eq!(matrix.as_slice(), &[11, 10, 21, 20]);
+        assert_eq!(matrix.height(), 2);
+    }
+
+    #[test]
+    fn indexing_and_conversions() {
+        let mut matrix = grid(2, 3);
+        matrix[(1, 2)] = 99;
+        matrix.row_mut(0)[0] = -5;
+        assert_eq!((matrix[(1, 2)], matrix[(0, 0)]), (99, -5));
+
+        let dense = MatrixContent::from(matrix.clone());
+        assert_eq!((dense.width(), dense.height()), (3, 2));
+        assert_eq!(dense[(1, 2)], 99);
+
+        let column_major = dense.clone().into_layout(Layout::ColumnMajor);
+        assert_eq!(GrowableMatrix::from(column_major), matrix);
+        assert_eq!(format!("{}", matrix), "-5, 1, 2\n10, 11, 99");
+    }
+
+    #[test]
+    fn negative_indices_count_from_the_end() {
+        let mut matrix = grid(3, 2);
+        assert_eq!(matrix.row(-1), &[20, 21]);
+        assert_eq!(matrix[(-3, -1)], 1);
+        matrix[(-1, -2)] = 7;
+        matrix.row_mut(-2)[1] = 8;
+        assert_eq!(matrix.as_slice(), &[0, 1, 10, 8, 7, 21]);
+
+        matrix.swap_rows(0, -1);
+        matrix.swap_columns(-1, 0);
+        assert_eq!(matrix.as_slice(), &[21, 7, 8, 10, 1, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 3 out of bounds for a matrix with 3 rows")]
+    fn row_past_the_end_panics() {
+        grid(3, 2).row(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "row -4 out of bounds for a matrix with 3 rows")]
+    fn row_before_the_start_panics() {
+        grid(3, 2).row(-4);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 2 out of bounds for a matrix with 2 columns")]
+    fn index_past_the_last_column_panics() {
+        // Without the check this would read the first cell of the next row.
+        let _ = grid(3, 2)[(0, 2)];
+    }
+
+    #[test]
+    #[should_panic(expected = "row 4 out of bounds for a matrix with 4 rows")]
+    fn swapping_a_missing_row_panics() {
+        grid(4, 2).swap_rows(0, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "column -3 out of bounds for a matrix with 2 columns")]
+    fn swapping_a_missing_column_panics() {
+        grid(4, 2).swap_columns(-3, 0);
+    }
+}
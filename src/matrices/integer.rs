@@ -0,0 +1,315 @@
+use crate::scalar::Integer;
+
+use super::{MatrixContent, MisAlignment};
+
+/// Row-style Hermite normal form `h = u * a` where `u` is unimodular.
+///
+/// `h` is upper echelon with positive pivots and every entry above a pivot reduced into
+/// `[0, pivot)`.
+#[derive(Debug, Clone)]
+pub struct HermiteNormalForm<T> {
+    pub h: MatrixContent<T>,
+    pub u: MatrixContent<T>,
+}
+
+/// Smith normal form `s = u * a * v` where `u` and `v` are unimodular.
+///
+/// `s` is diagonal with non-negative entries, each dividing the next.
+#[derive(Debug, Clone)]
+pub struct SmithNormalForm<T> {
+    pub s: MatrixContent<T>,
+    pub u: MatrixContent<T>,
+    pub v: MatrixContent<T>,
+}
+
+impl<T: Integer> MatrixContent<T> {
+    /// Determinant computed with fraction-free Bareiss elimination.
+    ///
+    /// Every intermediate value is itself a minor of the matrix, so no rounding ever occurs
+    /// and intermediate growth stays bounded by Hadamard's inequality.
+    pub fn bareiss_determinant(&self) -> Result<T, MisAlignment> {
+        if self.width() != self.height() {
+            return Err(MisAlignment);
+        }
+
+        let n = self.height();
+        if n == 0 {
+            return Ok(T::one());
+        }
+
+        let mut m = self.clone();
+        let mut negate = false;
+        let mut previous = T::one();
+
+        for k in 0..n - 1 {
+            if m[(k, k)].is_zero() {
+                match (k + 1..n).find(|&i| !m[(i, k)].is_zero()) {
+                    Some(i) => {
+                        m.swap_rows(k, i);
+                        negate = !negate;
+                    }
+                    None => return Ok(T::zero()),
+                }
+            }
+
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    let value = (m[(i, j)].clone() * m[(k, k)].clone()
+                        - m[(i, k)].clone() * m[(k, j)].clone())
+                        / previous.clone();
+                    m[(i, j)] = value;
+                }
+            }
+            previous = m[(k, k)].clone();
+        }
+
+        let determinant = m[(n - 1, n - 1)].clone();
+        Ok(if negate { -determinant } else { determinant })
+    }
+
+    pub fn hermite_normal_form(&self) -> HermiteNormalForm<T> {
+        let (rows, cols) = (self.height(), self.width());
+        let mut h = self.clone();
+        let mut u = MatrixContent::identity(rows);
+        let mut pivot_row = 0;
+
+        for col in 0..cols {
+            if pivot_row == rows {
+                break;
+            }
+
+            for row in pivot_row + 1..rows {
+                if !h[(row, col)].is_zero() {
+                    let a = h[(pivot_row, col)].clone();
+                    let b = h[(row, col)].clone();
+                    let (g, x, y) = a.extended_gcd(&b);
+                    let (p, q) = (-(b / g.clone()), a / g);
+                    combine_rows(&mut h, pivot_row, row, [&x, &y, &p, &q]);
+                    combine_rows(&mut u, pivot_row, row, [&x, &y, &p, &q]);
+                }
+            }
+
+            if h[(pivot_row, col)].is_zero() {
+                continue;
+            }
+
+            if h[(pivot_row, col)].is_negative() {
+                negate_row(&mut h, pivot_row);
+                negate_row(&mut u, pivot_row);
+            }
+
+            for row in 0..pivot_row {
+                let factor = h[(row, col)].div_floor(&h[(pivot_row, col)]);
+                if !factor.is_zero() {
+                    subtract_row_multiple(&mut h, row, pivot_row, &factor);
+                    subtract_row_multiple(&mut u, row, pivot_row, &factor);
+                }
+            }
+
+            pivot_row += 1;
+        }
+
+        HermiteNormalForm { h, u }
+    }
+
+    pub fn smith_normal_form(&self) -> SmithNormalForm<T> {
+        let (rows, cols) = (self.height(), self.width());
+        let mut s = self.clone();
+        let mut u = MatrixContent::identity(rows);
+        let mut v = MatrixContent::identity(cols);
+
+        for t in 0..rows.min(cols) {
+            let pivot = (t..rows)
+                .flat_map(|row| (t..cols).map(move |col| (row, col)))
+                .filter(|&index| !s[index].is_zero())
+                .min_by(|&a, &b| s[a].abs().cmp(&s[b].abs()));
+
+            let (pivot_row, pivot_col) = match pivot {
+                Some(index) => index,
+                None => break,
+            };
+            s.swap_rows(t, pivot_row);
+            u.swap_rows(t, pivot_row);
+            s.swap_columns(t, pivot_col);
+            v.swap_columns(t, pivot_col);
+
+            loop {
+                for row in t + 1..rows {
+                    if !s[(row, t)].is_zero() {
+                        let a = s[(t, t)].clone();
+                        let b = s[(row, t)].clone();
+                        let (g, x, y) = a.extended_gcd(&b);
+                        let (p, q) = (-(b / g.clone()), a / g);
+                        combine_rows(&mut s, t, row, [&x, &y, &p, &q]);
+                        combine_rows(&mut u, t, row, [&x, &y, &p, &q]);
+                    }
+                }
+
+                for col in t + 1..cols {
+                    if !s[(t, col)].is_zero() {
+                        let a = s[(t, t)].clone();
+                        let b = s[(t, col)].clone();
+                        let (g, x, y) = a.extended_gcd(&b);
+                        let (p, q) = (-(b / g.clone()), a / g);
+                        combine_columns(&mut s, t, col, [&x, &y, &p, &q]);
+                        combine_columns(&mut v, t, col, [&x, &y, &p, &q]);
+                    }
+                }
+
+                if (t + 1..rows).any(|row| !s[(row, t)].is_zero()) {
+                    continue;
+                }
+
+                // The pivot must divide every remaining entry; if one is left over, folding its
+                // row into the pivot row lets the next gcd step shrink the pivot further.
+                let offender = (t + 1..rows)
+                    .flat_map(|row| (t + 1..cols).map(move |col| (row, col)))
+                    .find(|&index| !(s[index].clone() % s[(t, t)].clone()).is_zero());
+                match offender {
+                    Some((row, _)) => {
+                        add_row(&mut s, t, row);
+                        add_row(&mut u, t, row);
+                    }
+                    None => break,
+                }
+            }
+
+            if s[(t, t)].is_negative() {
+                negate_row(&mut s, t);
+                negate_row(&mut u, t);
+            }
+        }
+
+        SmithNormalForm { s, u, v }
+    }
+}
+
+fn negate_row<T: Integer>(m: &mut MatrixContent<T>, row: isize) {
+    for col in 0..m.width() {
+        m[(row, col)] = -m[(row, col)].clone();
+    }
+}
+
+fn add_row<T: Integer>(m: &mut MatrixContent<T>, target: isize, source: isize) {
+    for col in 0..m.width() {
+        m[(target, col)] = m[(target, col)].clone() + m[(source, col)].clone();
+    }
+}
+
+fn subtract_row_multiple<T: Integer>(
+    m: &mut MatrixContent<T>,
+    target: isize,
+    source: isize,
+    factor: &T,
+) {
+    for col in 0..m.width() {
+        m[(target, col)] = m[(target, col)].clone() - factor.clone() * m[(source, col)].clone();
+    }
+}
+
+/// Replaces rows `(a, b)` with `(x * a + y * b, p * a + q * b)`.
+fn combine_rows<T: Integer>(m: &mut MatrixContent<T>, a: isize, b: isize, [x, y, p, q]: [&T; 4]) {
+    for col in 0..m.width() {
+        let (first, second) = (m[(a, col)].clone(), m[(b, col)].clone());
+        m[(a, col)] = x.clone() * first.clone() + y.clone() * second.clone();
+        m[(b, col)] = p.clone() * first + q.clone() * second;
+    }
+}
+
+/// Replaces columns `(a, b)` with `(x * a + y * b, p * a + q * b)`.
+fn combine_columns<T: Integer>(
+    m: &mut MatrixContent<T>,
+    a: isize,
+    b: isize,
+    [x, y, p, q]: [&T; 4],
+) {
+    for row in 0..m.height() {
+        let (first, second) = (m[(row, a)].clone(), m[(row, b)].clone());
+        m[(row, a)] = x.clone() * first.clone() + y.clone() * second.clone();
+        m[(row, b)] = p.clone() * first + q.clone() * second;
+    }
+}
+
+impl<T: Integer> SmithNormalForm<T> {
+    /// The invariant factors along the diagonal of `s`, excluding trailing zeros.
+    pub fn invariant_factors(&self) -> Vec<T> {
+        let size = self.s.height().min(self.s.width());
+        (0..size)
+            .map(|i| self.s[(i, i)].clone())
+            .take_while(|d| !d.is_zero())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint::BigInt;
+    use crate::dimension::Dimension;
+
+    fn matrix(height: isize, width: isize, values: &[i64]) -> MatrixContent<i64> {
+        MatrixContent::new(Dimension::new(width, height), values.to_vec())
+    }
+
+    fn product(a: &MatrixContent<i64>, b: &MatrixContent<i64>) -> MatrixContent<i64> {
+        MatrixContent::from_fn(Dimension::new(b.width(), a.height()), |row, col| {
+            (0..a.width()).map(|k| a[(row, k)] * b[(k, col)]).sum()
+        })
+    }
+
+    fn is_unimodular(m: &MatrixContent<i64>) -> bool {
+        m.bareiss_determinant().unwrap().abs() == 1
+    }
+
+    #[test]
+    fn bareiss_determinant() {
+        let a = matrix(3, 3, &[2, -3, 1, 2, 0, -1, 1, 4, 5]);
+        assert_eq!(a.bareiss_determinant().unwrap(), 49);
+        let pivoting = matrix(3, 3, &[0, 1, 2, 1, 0, 3, 4, -3, 8]);
+        assert_eq!(pivoting.bareiss_determinant().unwrap(), -2);
+        let singular = matrix(3, 3, &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(singular.bareiss_determinant().unwrap(), 0);
+        assert!(matrix(2, 3, &[1, 2, 3, 4, 5, 6])
+            .bareiss_determinant()
+            .is_err());
+
+        let big =
+            MatrixContent::from_fn(Dimension::new(3, 3), |row, col| BigInt::from(a[(row, col)]));
+        assert_eq!(big.bareiss_determinant().unwrap(), BigInt::from(49));
+    }
+
+    #[test]
+    fn hermite_normal_form() {
+        let a = matrix(3, 4, &[2, 3, 6, 2, 5, 6, 1, 6, 8, 3, 1, 1]);
+        let HermiteNormalForm { h, u } = a.hermite_normal_form();
+        assert_eq!(product(&u, &a), h);
+        assert!(is_unimodular(&u));
+
+        let mut last_pivot = -1;
+        for row in 0..h.height() {
+            let Some(pivot) = (0..h.width()).find(|&col| h[(row, col)] != 0) else {
+                assert!((row..h.height()).all(|r| (0..h.width()).all(|c| h[(r, c)] == 0)));
+                break;
+            };
+            assert!(pivot > last_pivot && h[(row, pivot)] > 0);
+            for above in 0..row {
+                assert!((0..h[(row, pivot)]).contains(&h[(above, pivot)]));
+            }
+            last_pivot = pivot;
+        }
+    }
+
+    #[test]
+    fn smith_normal_form() {
+        let a = matrix(3, 3, &[2, 4, 4, -6, 6, 12, 10, -4, -16]);
+        let snf = a.smith_normal_form();
+        assert_eq!(product(&product(&snf.u, &a), &snf.v), snf.s);
+        assert!(is_unimodular(&snf.u) && is_unimodular(&snf.v));
+        assert_eq!(snf.invariant_factors(), vec![2, 6, 12]);
+
+        let rank_deficient = matrix(2, 3, &[2, 4, 6, 1, 2, 3]);
+        let snf = rank_deficient.smith_normal_form();
+        assert_eq!(product(&product(&snf.u, &rank_deficient), &snf.v), snf.s);
+        assert_eq!(snf.invariant_factors(), vec![1]);
+    }
+}
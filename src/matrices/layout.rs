@@ -0,0 +1,246 @@
+use crate::dimension::Dimension;
+
+use super::{MatrixContent, MatrixIter};
+
+/// Order in which the cells of a [`MatrixContent`] are laid out in its buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Layout {
+    /// Consecutive cells of a row are adjacent, as in C.
+    #[default]
+    RowMajor,
+    /// Consecutive cells of a column are adjacent, as in Fortran.
+    ColumnMajor,
+}
+
+impl<T> MatrixContent<T> {
+    /// Wraps `buffer` without copying, interpreting it in the given `layout`.
+    pub fn with_layout(dimension: Dimension, buffer: Vec<T>, layout: Layout) -> Self {
+        Self {
+            dimension,
+            layout,
+            buffer: buffer.into_boxed_slice(),
+        }
+    }
+
+    /// Wraps a Fortran-ordered buffer without copying.
+    pub fn from_column_major(dimension: Dimension, buffer: Vec<T>) -> Self {
+        Self::with_layout(dimension, buffer, Layout::ColumnMajor)
+    }
+
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// The underlying buffer in storage order, see [`MatrixContent::layout`].
+    pub fn as_slice(&self) -> &[T] {
+        &self.buffer
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.buffer
+    }
+
+    /// Releases the underlying buffer in storage order without copying.
+    pub fn into_buffer(self) -> Box<[T]> {
+        self.buffer
+    }
+
+    /// Rearranges the buffer in place so that it is stored in `layout`.
+    pub fn into_layout(mut self, layout: Layout) -> Self {
+        if self.layout == layout {
+            return self;
+        }
+
+        // Follow each cycle of the permutation taking a cell's current position to its
+        // position in the new layout, swapping values along the way.
+        let (height, width) = (self.height() as usize, self.width() as usize);
+        let target = |index: usize| match layout {
+            Layout::ColumnMajor => (index % width) * height + index / width,
+            Layout::RowMajor => (index % height) * width + index / height,
+        };
+        let mut visited = vec![false; self.buffer.len()];
+        for start in 0..self.buffer.len() {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let mut next = target(start);
+            while next != start {
+                self.buffer.swap(start, next);
+                visited[next] = true;
+                next = target(next);
+            }
+        }
+
+        self.layout = layout;
+        self
+    }
+
+    /// Transposes by reinterpreting the buffer in the opposite layout, without moving any cell.
+    pub fn into_transpose(self) -> Self {
+        let layout = match self.layout {
+            Layout::RowMajor => Layout::ColumnMajor,
+            Layout::ColumnMajor => Layout::RowMajor,
+        };
+        Self {
+            dimension: Dimension::new(self.height(), self.width()),
+            layout,
+            buffer: self.buffer,
+        }
+    }
+
+    pub(crate) fn row_iter(&self, row: isize) -> MatrixIter<'_, T> {
+        let (height, width) = (self.height() as usize, self.width() as usize);
+        let row = self.reflect_row(row) as usize;
+        match self.layout {
+            Layout::RowMajor => self.strided(row * width, 1, width),
+            Layout::ColumnMajor => self.strided(row, height, width),
+        }
+    }
+
+    pub(crate) fn column_iter(&self, col: isize) -> MatrixIter<'_, T> {
+        let (height, width) = (self.height() as usize, self.width() as usize);
+        let col = self.reflect_col(col) as usize;
+        match self.layout {
+            Layout::RowMajor => self.strided(col, width, height),
+            Layout::ColumnMajor => self.strided(col * height, 1, height),
+        }
+    }
+}
+
+impl<T: Clone> MatrixContent<T> {
+    /// Copy of this matrix stored in `layout`.
+    pub fn to_layout(&self, layout: Layout) -> Self {
+        if self.layout == layout {
+            return self.clone();
+        }
+
+        let dimension = self.dimension.clone();
+        let mut buffer = Vec::with_capacity(self.buffer.len());
+        match layout {
+            Layout::RowMajor => {
+                for row in 0..self.height() {
+                    buffer.extend(self.row_iter(row).cloned());
+                }
+            }
+            Layout::ColumnMajor => {
+                for col in 0..self.width() {
+                    buffer.extend(self.column_iter(col).cloned());
+                }
+            }
+        }
+        Self::with_layout(dimension, buffer, layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrices::indices::{ColumnIndex, RowIndex};
+    use crate::matrices::Get;
+
+    fn sample() -> MatrixContent<isize> {
+        MatrixContent::from_fn(Dimension::new(4, 3), |row, col| row * 10 + col)
+    }
+
+    #[test]
+    fn column_major_buffers_are_indexed_in_place() {
+        let m = MatrixContent::from_column_major(Dimension::new(3, 2), vec![1, 4, 2, 5, 3, 6]);
+        assert_eq!(m.layout(), Layout::ColumnMajor);
+        assert_eq!(m[(0, 2)], 3);
+        assert_eq!(m[(1, 0)], 4);
+        assert_eq!(m[(-1, -1)], 6);
+        assert_eq!(
+            m,
+            MatrixContent::new(Dimension::new(3, 2), vec![1, 2, 3, 4, 5, 6])
+        );
+    }
+
+    #[test]
+    fn rows_and_columns_follow_the_layout() {
+        let row_major = sample();
+        let column_major = row_major.to_layout(Layout::ColumnMajor);
+        for m in [&row_major, &column_major] {
+            let row: Vec<isize> = m.get(RowIndex::from(1)).unwrap().iter().copied().collect();
+            assert_eq!(row, vec![10, 11, 12, 13]);
+            let col: Vec<isize> = m
+                .get(ColumnIndex::from(2))
+                .unwrap()
+                .iter()
+                .copied()
+                .collect();
+            assert_eq!(col, vec![2, 12, 22]);
+            assert_eq!(m.rows().count(), 3);
+            assert_eq!(m.columns().count(), 4);
+        }
+    }
+
+    #[test]
+    fn conversions_between_layouts() {
+        let m = sample();
+        let converted = m.clone().into_layout(Layout::ColumnMajor);
+        assert_eq!(
+            converted.as_slice(),
+            &[0, 10, 20, 1, 11, 21, 2, 12, 22, 3, 13, 23]
+        );
+        assert_eq!(converted, m);
+        assert_eq!(
+            converted.to_layout(Layout::RowMajor).as_slice(),
+            m.as_slice()
+        );
+        assert_eq!(
+            converted.into_layout(Layout::RowMajor).as_slice(),
+            m.as_slice()
+        );
+
+        let transpose = m.clone().into_transpose();
+        assert_eq!(transpose.dimension(), &Dimension::new(3, 4));
+        assert_eq!(transpose[(3, 2)], m[(2, 3)]);
+        assert_eq!(transpose.as_slice(), m.as_slice());
+    }
+
+    #[test]
+    fn flat_access_is_row_major_whatever_the_layout() {
+        let m = MatrixContent::from_column_major(Dimension::new(3, 2), vec![1, 4, 2, 5, 3, 6]);
+        assert_eq!(
+            m.entries().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!((m[1], m[3], m[-1]), (2, 4, 6));
+        assert_eq!(m.as_slice(), &[1, 4, 2, 5, 3, 6]);
+
+        let mut visited = Vec::new();
+        let mut m = m;
+        for (i, cell) in m.entries_mut().enumerate() {
+            visited.push(*cell);
+            *cell = i as isize;
+        }
+        assert_eq!(visited, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(m.as_slice(), &[0, 3, 1, 4, 2, 5]);
+
+        let sum = &m + &m.to_layout(Layout::RowMajor);
+        assert_eq!(
+            sum.entries().copied().collect::<Vec<_>>(),
+            vec![0, 2, 4, 6, 8, 10]
+        );
+    }
+
+    #[test]
+    fn entries_mut_visits_every_cell() {
+        let mut m = sample().to_layout(Layout::ColumnMajor);
+        m.entries_mut().for_each(|cell| *cell += 1);
+        assert_eq!(m[(2, 3)], 24);
+        assert_eq!(m.entries().count(), 12);
+
+        let mut single_row = MatrixContent::from_column_major(Dimension::new(3, 1), vec![1, 2, 3]);
+        assert_eq!(
+            single_row.entries_mut().map(|v| *v).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+
+        let mut empty = MatrixContent::<isize>::new(Dimension::new(0, 0), vec![]);
+        assert_eq!(empty.entries_mut().count(), 0);
+        let mut no_rows = MatrixContent::<isize>::new(Dimension::new(5, 0), vec![]);
+        assert_eq!(no_rows.entries_mut().count(), 0);
+    }
+}
@@ -35,3 +35,44 @@ macro_rules! matrix {
         }
     }
 }
+
+/// Asserts that two `f64` matrices are approximately equal in the sense of
+/// `MatrixContent::approx_eq`, printing both matrices with the differing cells in brackets
+/// otherwise. The tolerances default to `1e-12` absolute and `1e-9` relative.
+#[macro_export]
+macro_rules! assert_matrix_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_matrix_approx_eq!($left, $right, 1e-12, 1e-9)
+    };
+    ($left:expr, $right:expr, $abs_tol:expr, $rel_tol:expr $(,)?) => {{
+        let (left, right) = (&$left, &$right);
+        if !left.approx_eq(right, $abs_tol, $rel_tol) {
+            panic!(
+                "assertion failed: matrices are not approximately equal\n{}",
+                left.describe_differences(right, $abs_tol, $rel_tol)
+            );
+        }
+    }};
+    ($left:expr, $right:expr, $abs_tol:expr, $rel_tol:expr, $($arg:tt)+) => {{
+        let (left, right) = (&$left, &$right);
+        if !left.approx_eq(right, $abs_tol, $rel_tol) {
+            panic!(
+                "assertion failed: matrices are not approximately equal: {}\n{}",
+                format_args!($($arg)+),
+                left.describe_differences(right, $abs_tol, $rel_tol)
+            );
+        }
+    }};
+}
+
+/// Assembles a `MatrixContent` from a grid of blocks, e.g. `block![[a, b], [c, d]]`, through
+/// `MatrixContent::block`. Blocks may be `MatrixContent`, `Matrix` or `GenericMatrix` values;
+/// the result is `Err(MisAlignment)` if their shapes do not fit together.
+#[macro_export]
+macro_rules! block {
+    [$([$($block:expr),+ $(,)?]),+ $(,)?] => {
+        $crate::matrices::MatrixContent::block(&[
+            $(vec![$(::core::convert::AsRef::<$crate::matrices::MatrixContent<_>>::as_ref(&$block)),+]),+
+        ])
+    };
+}
@@ -1,16 +1,44 @@
+pub mod approx;
 pub mod columns;
+pub mod convolution;
+pub mod cumulative;
+pub mod decomposition;
+pub mod elimination;
+pub mod functions;
+mod generators;
+pub mod gf2;
+pub mod growable;
 pub mod indices;
+pub mod integer;
+pub mod layout;
+pub mod norms;
 mod ops;
 mod macros;
 pub mod rows;
+pub mod reshape;
+pub mod selection;
+pub mod semiring;
+pub mod sparse;
+pub mod structured;
+pub mod svd;
+pub mod smatrix;
+pub mod sorting;
+pub mod stacking;
+pub mod view;
+pub mod windows;
+#[cfg(test)]
+pub(crate) mod testing;
 
 use std::ops::{Index, IndexMut};
 
 use indices::MatrixIndex;
+pub use convolution::{Boundary, ConvolutionMode};
+pub use layout::Layout;
+pub use stacking::Axis;
+pub use windows::Remainder;
 
 use crate::dimension::Dimension;
-pub use ops::*;
-pub use macros::*;
+use crate::scalar::{One, Zero};
 
 use self::{
     columns::{ColumnsIter, MatrixColumn},
@@ -72,7 +100,7 @@ impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T> {
             if v.len() != width {
                 return Err(MisAlignment);
             }
-            vec.extend(v.into_iter())
+            vec.extend(v)
         }
 
         Ok(Matrix {
@@ -81,18 +109,39 @@ impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T> {
     }
 }
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct MatrixContent<T> {
     pub(crate) dimension: Dimension,
+    pub(crate) layout: Layout,
     pub(crate) buffer: Box<[T]>,
 }
 
 impl<T> MatrixContent<T> {
     pub fn new(dimension: Dimension, buffer: Vec<T>) -> Self {
-        Self {
-            dimension,
-            buffer: buffer.into_boxed_slice(),
+        Self::with_layout(dimension, buffer, Layout::RowMajor)
+    }
+
+    /// Builds a matrix by calling `f(row, col)` for every cell in row-major order.
+    pub fn from_fn(dimension: Dimension, mut f: impl FnMut(isize, isize) -> T) -> Self {
+        let mut buffer = Vec::with_capacity(dimension.len() as usize);
+        for row in 0..dimension.height() {
+            for col in 0..dimension.width() {
+                buffer.push(f(row, col));
+            }
         }
+        Self::new(dimension, buffer)
+    }
+
+    pub fn width(&self) -> isize {
+        self.dimension.width()
+    }
+
+    pub fn height(&self) -> isize {
+        self.dimension.height()
+    }
+
+    pub fn dimension(&self) -> &Dimension {
+        &self.dimension
     }
 
     fn reflect_row(&self, row: isize) -> isize {
@@ -115,23 +164,119 @@ impl<T> MatrixContent<T> {
         (self.reflect_row(row), self.reflect_col(col))
     }
 
-    pub fn entries(&self) -> MatrixEntries<T> {
+    /// Position of the cell `(row, col)` in the underlying buffer.
+    pub(crate) fn flat_index(&self, index: (isize, isize)) -> usize {
+        let (row, col) = self.reflect(index);
+        match self.layout {
+            Layout::RowMajor => (col + row * self.dimension.width()) as usize,
+            Layout::ColumnMajor => (row + col * self.dimension.height()) as usize,
+        }
+    }
+
+    /// Position in the underlying buffer of the cell at `index` in row-major order.
+    pub(crate) fn storage_index(&self, index: usize) -> usize {
+        match self.layout {
+            Layout::RowMajor => index,
+            Layout::ColumnMajor => {
+                let width = self.dimension.width() as usize;
+                (index % width) * self.dimension.height() as usize + index / width
+            }
+        }
+    }
+
+    /// Iterator over `count` buffer cells starting at `start` and `step` apart.
+    pub(crate) fn strided(&self, start: usize, step: usize, count: usize) -> MatrixIter<'_, T> {
+        MatrixIter {
+            mat: self,
+            pos: start,
+            step,
+            end: if count == 0 {
+                0
+            } else {
+                start + (count - 1) * step
+            },
+            remaining: count,
+        }
+    }
+
+    /// Exchanges two rows in place.
+    pub fn swap_rows(&mut self, a: isize, b: isize) {
+        if a != b {
+            for col in 0..self.width() {
+                let (i, j) = (self.flat_index((a, col)), self.flat_index((b, col)));
+                self.buffer.swap(i, j);
+            }
+        }
+    }
+
+    /// Exchanges two columns in place.
+    pub fn swap_columns(&mut self, a: isize, b: isize) {
+        if a != b {
+            for row in 0..self.height() {
+                let (i, j) = (self.flat_index((row, a)), self.flat_index((row, b)));
+                self.buffer.swap(i, j);
+            }
+        }
+    }
+
+    /// The cells in row-major order, whatever the layout. [`MatrixContent::as_slice`] gives
+    /// them in storage order.
+    pub fn entries(&self) -> MatrixEntries<'_, T> {
         MatrixEntries { mat: self, pos: 0 }
     }
 
-    pub fn entries_mut(&mut self) -> MatrixIterMut<T> {
-        unsafe { MatrixIterMut::new(0, self.buffer.len() - 1, &mut self.buffer, 1) }
+    /// Mutable references to the cells in row-major order, whatever the layout.
+    pub fn entries_mut(&mut self) -> MatrixIterMut<'_, T> {
+        if self.buffer.is_empty() {
+            return MatrixIterMut::empty();
+        }
+        match self.layout {
+            Layout::RowMajor => unsafe {
+                MatrixIterMut::new(0, self.buffer.len() - 1, &mut self.buffer, 1)
+            },
+            Layout::ColumnMajor => {
+                let (height, width) = (self.height() as usize, self.width() as usize);
+                MatrixIterMut::column_major(&mut self.buffer, height, width)
+            }
+        }
     }
 
-    pub fn rows(&self) -> RowsIter<T> {
+    pub fn rows(&self) -> RowsIter<'_, T> {
         RowsIter { mat: self, pos: 0 }
     }
 
-    pub fn columns(&self) -> ColumnsIter<T> {
+    pub fn columns(&self) -> ColumnsIter<'_, T> {
         ColumnsIter { mat: self, pos: 0 }
     }
 }
 
+impl<T: PartialEq> PartialEq for MatrixContent<T> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.dimension != other.dimension {
+            return false;
+        }
+        if self.layout == other.layout {
+            return self.buffer == other.buffer;
+        }
+        (0..self.height())
+            .all(|row| (0..self.width()).all(|col| self[(row, col)] == other[(row, col)]))
+    }
+}
+
+impl<T: Eq> Eq for MatrixContent<T> {}
+
+impl<T: Zero + One> MatrixContent<T> {
+    pub fn identity(size: isize) -> Self {
+        Self::from_fn(Dimension::new(size, size), |row, col| {
+            if row == col {
+                T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
 impl<T: std::fmt::Display> std::fmt::Display for MatrixContent<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut output = String::new();
@@ -182,10 +327,7 @@ impl<T> Index<(isize, isize)> for MatrixContent<T> {
     type Output = T;
 
     fn index(&self, index: (isize, isize)) -> &Self::Output {
-        let (row, col) = self.reflect(index);
-        let index = col as usize + (row * self.dimension.width()) as usize;
-
-        &self.buffer[index]
+        &self.buffer[self.flat_index(index)]
     }
 }
 
@@ -199,15 +341,13 @@ impl<T> Index<isize> for MatrixContent<T> {
             index
         };
 
-        self.buffer.index(index as usize)
+        self.buffer.index(self.storage_index(index as usize))
     }
 }
 
 impl<T> IndexMut<(isize, isize)> for MatrixContent<T> {
     fn index_mut(&mut self, index: (isize, isize)) -> &mut Self::Output {
-        let (row, col) = self.reflect(index);
-        let index = col as usize + (row * self.dimension.width()) as usize;
-
+        let index = self.flat_index(index);
         &mut self.buffer[index]
     }
 }
@@ -252,9 +392,11 @@ impl<'a, T: 'a> Get<'a, MatrixIndex> for MatrixContent<T> {
     type Output = &'a T;
     fn get(&'a self, MatrixIndex(row, col): MatrixIndex) -> Option<Self::Output> {
         let (row, col) = self.reflect((row, col));
-        if row < 0 || row >= self.dimension.height() {
-            None
-        } else if col < 0 || col >= self.dimension.width() {
+        if row < 0
+            || row >= self.dimension.height()
+            || col < 0
+            || col >= self.dimension.width()
+        {
             None
         } else {
             Some(self.index((row, col)))
@@ -266,9 +408,11 @@ impl<'a, T: 'a> GetMut<'a, MatrixIndex> for MatrixContent<T> {
     type Output = &'a mut T;
     fn get_mut(&'a mut self, MatrixIndex(row, col): MatrixIndex) -> Option<Self::Output> {
         let (row, col) = self.reflect((row, col));
-        if row < 0 || row >= self.dimension.height() {
-            None
-        } else if col < 0 || col >= self.dimension.width() {
+        if row < 0
+            || row >= self.dimension.height()
+            || col < 0
+            || col >= self.dimension.width()
+        {
             None
         } else {
             Some(self.index_mut((row, col)))
@@ -289,7 +433,7 @@ impl<T> TryFrom<Vec<Vec<T>>> for MatrixContent<T> {
             if v.len() != width {
                 return Err(MisAlignment);
             }
-            vec.extend(v.into_iter())
+            vec.extend(v)
         }
 
         Ok(Self::new(
@@ -305,15 +449,17 @@ pub struct MatrixIter<'a, T> {
     pos: usize,
     step: usize,
     end: usize,
+    remaining: usize,
 }
 
 impl<'a, T> Iterator for MatrixIter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.pos <= self.end {
+        if self.remaining > 0 && self.pos <= self.end {
             let index = self.pos;
             self.pos += self.step;
+            self.remaining -= 1;
             Some(self.mat.buffer.index(index))
         } else {
             None
@@ -333,7 +479,7 @@ impl<'a, T> Iterator for MatrixEntries<'a, T> {
         if self.pos < self.mat.buffer.len() {
             let pos = self.pos;
             self.pos += 1;
-            Some(self.mat.buffer.index(pos))
+            Some(self.mat.buffer.index(self.mat.storage_index(pos)))
         } else {
             None
         }
@@ -343,23 +489,60 @@ impl<'a, T> Iterator for MatrixEntries<'a, T> {
 #[derive(Debug, Clone)]
 pub struct MatrixIterMut<'a, T: 'a> {
     ptr: std::ptr::NonNull<T>,
-    end: *mut T,
+    pos: usize,
+    len: usize,
+    /// Number of cells visited `step` apart before moving `outer_step` past the first of them.
+    inner: usize,
     step: usize,
+    outer_step: usize,
     _marker: std::marker::PhantomData<&'a mut T>,
 }
 
 impl<'a, T> MatrixIterMut<'a, T> {
     /// Creates a mutable iterator over the elements in a mutable slice from start to end inclusive
     /// and with the steps indicated
+    ///
+    /// # Safety
+    ///
+    /// `start` and `end` must both be valid indices into `slice`.
     pub unsafe fn new(start: usize, end: usize, slice: &'a mut [T], step: usize) -> Self {
         assert!(start <= end);
         assert!(step > 0);
-        let ptr = slice.as_mut_ptr().add(start);
-        let end = slice.as_mut_ptr().add(end).add(step);
+        let len = (end - start) / step + 1;
         Self {
-            ptr: std::ptr::NonNull::new_unchecked(ptr),
-            end,
+            ptr: std::ptr::NonNull::new_unchecked(slice.as_mut_ptr().add(start)),
+            pos: 0,
+            len,
+            inner: len,
             step,
+            outer_step: 0,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Visits the cells of a column-major `height` by `width` buffer in row-major order.
+    fn column_major(slice: &'a mut [T], height: usize, width: usize) -> Self {
+        assert_eq!(slice.len(), height * width);
+        Self {
+            ptr: std::ptr::NonNull::new(slice.as_mut_ptr()).unwrap(),
+            pos: 0,
+            len: slice.len(),
+            inner: width,
+            step: height,
+            outer_step: 1,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// An iterator that yields nothing, for matrices without cells.
+    fn empty() -> Self {
+        Self {
+            ptr: std::ptr::NonNull::dangling(),
+            pos: 0,
+            len: 0,
+            inner: 1,
+            step: 1,
+            outer_step: 0,
             _marker: std::marker::PhantomData,
         }
     }
@@ -369,15 +552,14 @@ impl<'a, T> Iterator for MatrixIterMut<'a, T> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        unsafe {
-            let next_ptr = self.ptr.as_ptr().add(self.step);
-            if next_ptr <= self.end {
-                let curr_ptr = self.ptr.as_mut();
-                self.ptr = std::ptr::NonNull::new_unchecked(next_ptr);
-                Some(curr_ptr)
-            } else {
-                None
-            }
+        if self.pos < self.len {
+            let offset =
+                (self.pos / self.inner) * self.outer_step + (self.pos % self.inner) * self.step;
+            self.pos += 1;
+            // Every position maps to a distinct cell, so no two references alias.
+            unsafe { Some(&mut *self.ptr.as_ptr().add(offset)) }
+        } else {
+            None
         }
     }
 }
@@ -399,14 +581,14 @@ impl GenericMatrix {
         self.content.dimension.height()
     }
 
-    pub fn rows(&self) -> RowsIter<f64> {
+    pub fn rows(&self) -> RowsIter<'_, f64> {
         RowsIter {
             mat: &self.content,
             pos: 0,
         }
     }
 
-    pub fn columns(&self) -> ColumnsIter<f64> {
+    pub fn columns(&self) -> ColumnsIter<'_, f64> {
         ColumnsIter {
             mat: &self.content,
             pos: 0,
@@ -451,7 +633,7 @@ impl TryFrom<Vec<Vec<f64>>> for GenericMatrix {
             if v.len() != width {
                 return Err(MisAlignment);
             }
-            vec.extend(v.into_iter())
+            vec.extend(v)
         }
 
         Ok(GenericMatrix {
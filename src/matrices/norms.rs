@@ -0,0 +1,285 @@
+use crate::dimension::Dimension;
+
+use super::columns::MatrixColumn;
+use super::rows::MatrixRow;
+use super::{GenericMatrix, MatrixContent, MisAlignment};
+
+impl MatrixContent<f64> {
+    /// Square root of the sum of squared entries.
+    pub fn frobenius_norm(&self) -> f64 {
+        p_norm(self.buffer.iter(), 2.0)
+    }
+
+    /// Largest absolute column sum, the operator norm induced by the vector 1-norm.
+    pub fn one_norm(&self) -> f64 {
+        (0..self.width())
+            .map(|col| p_norm(self.column_iter(col), 1.0))
+            .fold(0.0, nan_max)
+    }
+
+    /// Largest absolute row sum, the operator norm induced by the vector infinity norm.
+    pub fn infinity_norm(&self) -> f64 {
+        (0..self.height())
+            .map(|row| p_norm(self.row_iter(row), 1.0))
+            .fold(0.0, nan_max)
+    }
+
+    /// Largest absolute entry.
+    pub fn max_abs(&self) -> f64 {
+        p_norm(self.buffer.iter(), f64::INFINITY)
+    }
+
+    /// Sum of the singular values.
+    pub fn nuclear_norm(&self) -> f64 {
+        GenericMatrix::from_content(self.clone())
+            .svd()
+            .singular_values
+            .iter()
+            .sum()
+    }
+
+    /// Sample covariance of the columns, treating each row as an observation.
+    pub fn covariance(&self) -> MatrixContent<f64> {
+        let (observations, features) = (self.height(), self.width());
+        let means: Vec<f64> = (0..features)
+            .map(|col| self.column_iter(col).sum::<f64>() / observations as f64)
+            .collect();
+        let denominator = (observations - 1).max(1) as f64;
+        let mut covariance = MatrixContent::from_fn(Dimension::new(features, features), |_, _| 0.0);
+        for i in 0..features {
+            for j in 0..=i {
+                let sum: f64 = self
+                    .column_iter(i)
+                    .zip(self.column_iter(j))
+                    .map(|(x, y)| (x - means[i as usize]) * (y - means[j as usize]))
+                    .sum();
+                covariance[(i, j)] = sum / denominator;
+                covariance[(j, i)] = sum / denominator;
+            }
+        }
+        covariance
+    }
+
+    /// Symmetric matrix of distances between every pair of rows.
+    pub fn pairwise_distances(&self, metric: Metric) -> Result<MatrixContent<f64>, MisAlignment> {
+        let (observations, features) = (self.height(), self.width());
+        if let Metric::Mahalanobis(inverse) = metric {
+            if inverse.width() != features || inverse.height() != features {
+                return Err(MisAlignment);
+            }
+        }
+
+        let rows: Vec<Vec<f64>> = (0..observations)
+            .map(|row| self.row_iter(row).copied().collect())
+            .collect();
+        let lengths: Vec<f64> = rows.iter().map(|row| p_norm(row.iter(), 2.0)).collect();
+
+        let mut distances =
+            MatrixContent::from_fn(Dimension::new(observations, observations), |_, _| 0.0);
+        for i in 0..rows.len() {
+            for j in 0..i {
+                let (x, y) = (&rows[i], &rows[j]);
+                let d: Vec<f64> = x.iter().zip(y).map(|(x, y)| x - y).collect();
+                let distance = match metric {
+                    Metric::Euclidean => p_norm(d.iter(), 2.0),
+                    Metric::Manhattan => p_norm(d.iter(), 1.0),
+                    Metric::Cosine => {
+                        let dot: f64 = x.iter().zip(y).map(|(x, y)| x * y).sum();
+                        let scale = lengths[i] * lengths[j];
+                        if scale == 0.0 {
+                            1.0
+                        } else {
+                            1.0 - dot / scale
+                        }
+                    }
+                    Metric::Mahalanobis(inverse) => {
+                        let quadratic: f64 = (0..features)
+                            .map(|r| {
+                                let projected: f64 =
+                                    inverse.row_iter(r).zip(&d).map(|(a, d)| a * d).sum();
+                                d[r as usize] * projected
+                            })
+                            .sum();
+                        quadratic.max(0.0).sqrt()
+                    }
+                };
+                distances[(i as isize, j as isize)] = distance;
+                distances[(j as isize, i as isize)] = distance;
+            }
+        }
+        Ok(distances)
+    }
+}
+
+/// Distance between observations for [`MatrixContent::pairwise_distances`].
+#[derive(Debug, Clone, Copy)]
+pub enum Metric<'a> {
+    Euclidean,
+    Manhattan,
+    /// `1 - cos(angle)`, taken to be 1 when either observation is the zero vector.
+    Cosine,
+    /// `sqrt(d^T S^-1 d)`, given the inverse covariance `S^-1`, for example from
+    /// [`MatrixContent::covariance`].
+    Mahalanobis(&'a MatrixContent<f64>),
+}
+
+impl<'a> MatrixRow<'a, f64> {
+    /// Vector p-norm of the row, with `f64::INFINITY` for the maximum norm.
+    pub fn norm(&self, p: f64) -> f64 {
+        p_norm(self.mat.row_iter(self.row), p)
+    }
+}
+
+impl<'a> MatrixColumn<'a, f64> {
+    /// Vector p-norm of the column, with `f64::INFINITY` for the maximum norm.
+    pub fn norm(&self, p: f64) -> f64 {
+        p_norm(self.mat.column_iter(self.col), p)
+    }
+}
+
+pub(crate) fn p_norm<'a>(values: impl Iterator<Item = &'a f64>, p: f64) -> f64 {
+    if p == f64::INFINITY {
+        values.fold(0.0, |acc, v| nan_max(acc, v.abs()))
+    } else if p == 1.0 {
+        values.map(|v| v.abs()).sum()
+    } else if p == 2.0 {
+        // Scaled to avoid overflow and underflow of the squares.
+        let values: Vec<f64> = values.copied().collect();
+        let scale = values.iter().fold(0.0, |acc, v| nan_max(acc, v.abs()));
+        if scale == 0.0 || scale.is_infinite() {
+            return scale;
+        }
+        scale
+            * values
+                .iter()
+                .map(|v| (v / scale).powi(2))
+                .sum::<f64>()
+                .sqrt()
+    } else {
+        values.map(|v| v.abs().powf(p)).sum::<f64>().powf(1.0 / p)
+    }
+}
+
+/// `f64::max` that returns NaN if either argument is NaN, instead of the other argument.
+fn nan_max(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        f64::NAN
+    } else {
+        a.max(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_matrix_approx_eq;
+    use crate::matrices::indices::{ColumnIndex, RowIndex};
+    use crate::matrices::Get;
+
+    /// `[1, -2, 3; -4, 5, -6]`
+    fn sample() -> MatrixContent<f64> {
+        MatrixContent::new(Dimension::new(3, 2), vec![1.0, -2.0, 3.0, -4.0, 5.0, -6.0])
+    }
+
+    fn rows(rows: &[&[f64]]) -> MatrixContent<f64> {
+        let height = rows.len() as isize;
+        let width = rows[0].len() as isize;
+        MatrixContent::new(Dimension::new(width, height), rows.concat())
+    }
+
+    #[test]
+    fn entrywise_and_induced_norms() {
+        let m = sample();
+        assert_eq!(m.frobenius_norm(), 91f64.sqrt());
+        assert_eq!(m.one_norm(), 9.0);
+        assert_eq!(m.infinity_norm(), 15.0);
+        assert_eq!(m.max_abs(), 6.0);
+
+        let singular_values = GenericMatrix::from_content(m.clone()).svd().singular_values;
+        assert!((m.nuclear_norm() - singular_values.iter().sum::<f64>()).abs() < 1e-12);
+        let diagonal = rows(&[&[3.0, 0.0], &[0.0, -4.0]]);
+        assert!((diagonal.nuclear_norm() - 7.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn vector_norms_of_rows_and_columns() {
+        let m = sample();
+        let row = m.get(RowIndex::from(1)).unwrap();
+        assert_eq!(row.norm(1.0), 15.0);
+        assert_eq!(row.norm(f64::INFINITY), 6.0);
+        assert!((row.norm(3.0) - 405f64.cbrt()).abs() < 1e-12);
+        let column = m.get(ColumnIndex::from(2)).unwrap();
+        assert_eq!(column.norm(2.0), 45f64.sqrt());
+    }
+
+    #[test]
+    fn two_norm_does_not_overflow_or_underflow() {
+        let huge = [3e200, 4e200];
+        assert!((p_norm(huge.iter(), 2.0) / 5e200 - 1.0).abs() < 1e-15);
+        let tiny = [3e-200, 4e-200];
+        assert!((p_norm(tiny.iter(), 2.0) / 5e-200 - 1.0).abs() < 1e-15);
+        assert_eq!(p_norm([f64::INFINITY, 1.0].iter(), 2.0), f64::INFINITY);
+        assert_eq!(p_norm([].iter(), 2.0), 0.0);
+    }
+
+    #[test]
+    fn nan_entries_propagate() {
+        let mut m = sample();
+        m[(1, 0)] = f64::NAN;
+        assert!(m.frobenius_norm().is_nan());
+        assert!(m.one_norm().is_nan());
+        assert!(m.infinity_norm().is_nan());
+        assert!(m.max_abs().is_nan());
+        assert!(p_norm([f64::NAN, f64::INFINITY].iter(), 2.0).is_nan());
+        assert!(p_norm([1.0, f64::NAN].iter(), 3.0).is_nan());
+    }
+
+    #[test]
+    fn covariance_of_observations() {
+        let observations = rows(&[&[1.0, 2.0], &[2.0, 4.0], &[3.0, 6.0], &[4.0, 8.0]]);
+        let covariance = observations.covariance();
+        let expected = rows(&[&[5.0 / 3.0, 10.0 / 3.0], &[10.0 / 3.0, 20.0 / 3.0]]);
+        assert_matrix_approx_eq!(covariance, expected, 1e-12, 1e-12);
+    }
+
+    #[test]
+    fn pairwise_distances_for_each_metric() {
+        let points = rows(&[&[0.0, 0.0], &[3.0, 4.0], &[-1.0, 0.0]]);
+
+        let euclidean = points.pairwise_distances(Metric::Euclidean).unwrap();
+        assert_eq!(
+            euclidean,
+            rows(&[
+                &[0.0, 5.0, 1.0],
+                &[5.0, 0.0, 32f64.sqrt()],
+                &[1.0, 32f64.sqrt(), 0.0]
+            ])
+        );
+
+        let manhattan = points.pairwise_distances(Metric::Manhattan).unwrap();
+        assert_eq!(
+            manhattan,
+            rows(&[&[0.0, 7.0, 1.0], &[7.0, 0.0, 8.0], &[1.0, 8.0, 0.0]])
+        );
+
+        let cosine = points.pairwise_distances(Metric::Cosine).unwrap();
+        assert_eq!(cosine[(0, 1)], 1.0);
+        assert!((cosine[(1, 2)] - 1.6).abs() < 1e-12);
+
+        let identity = MatrixContent::identity(2);
+        let mahalanobis = points
+            .pairwise_distances(Metric::Mahalanobis(&identity))
+            .unwrap();
+        assert_matrix_approx_eq!(mahalanobis, euclidean, 1e-12, 1e-12);
+        let scaling = rows(&[&[4.0, 0.0], &[0.0, 1.0]]);
+        let scaled = points
+            .pairwise_distances(Metric::Mahalanobis(&scaling))
+            .unwrap();
+        assert_eq!(scaled[(0, 2)], 2.0);
+
+        let wrong = MatrixContent::identity(3);
+        assert!(points
+            .pairwise_distances(Metric::Mahalanobis(&wrong))
+            .is_err());
+    }
+}
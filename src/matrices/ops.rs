@@ -1,8 +1,10 @@
 use std::ops::{Add, AddAssign, Mul, MulAssign};
 
+use crate::scalar::Zero;
+
 use super::{GenericMatrix, Matrix, MatrixContent};
 
-/// SCALAR MULTIPLICATION
+// SCALAR MULTIPLICATION
 
 impl<T: Clone + MulAssign> Mul<T> for MatrixContent<T> {
     type Output = Self;
@@ -26,7 +28,7 @@ impl Mul<f64> for GenericMatrix {
     type Output = Self;
 
     fn mul(mut self, rhs: f64) -> Self::Output {
-        self.entries_mut().for_each(|v| *v *= rhs.clone());
+        self.entries_mut().for_each(|v| *v *= rhs);
         self
     }
 }
@@ -87,3 +89,42 @@ impl<'a> Add<&'a GenericMatrix> for &GenericMatrix {
         }
     }
 }
+
+// MATRIX MULTIPLICATION
+
+impl<'a, T: Clone + Zero + Add<Output = T> + Mul<Output = T>> Mul<&'a MatrixContent<T>>
+    for &MatrixContent<T>
+{
+    type Output = MatrixContent<T>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: &'a MatrixContent<T>) -> Self::Output {
+        self.fold_product(
+            rhs,
+            T::zero(),
+            |a, b| a.clone() + b.clone(),
+            |a, b| a.clone() * b.clone(),
+        )
+        .expect("The width of the left matrix must equal the height of the right matrix in order to multiply them")
+    }
+}
+
+impl<'a, T: Clone + Zero + Add<Output = T> + Mul<Output = T>> Mul<&'a Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &'a Matrix<T>) -> Self::Output {
+        Matrix {
+            content: &self.content * &rhs.content,
+        }
+    }
+}
+
+impl<'a> Mul<&'a GenericMatrix> for &GenericMatrix {
+    type Output = GenericMatrix;
+
+    fn mul(self, rhs: &'a GenericMatrix) -> Self::Output {
+        GenericMatrix {
+            content: &self.content * &rhs.content,
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use crate::dimension::Dimension;
+
+use super::{Axis, Layout, MatrixContent, MisAlignment};
+
+impl<T> MatrixContent<T> {
+    /// Reinterprets the cells, read in row-major order, as a `height` by `width` matrix.
+    ///
+    /// Row-major matrices are reshaped without touching the buffer; column-major ones are first
+    /// rearranged in place. Fails if the number of cells changes.
+    pub fn reshape(self, height: isize, width: isize) -> Result<Self, MisAlignment> {
+        if height < 0 || width < 0 || height * width != self.dimension.len() {
+            return Err(MisAlignment);
+        }
+
+        let mut reshaped = self.into_layout(Layout::RowMajor);
+        reshaped.dimension = Dimension::new(width, height);
+        Ok(reshaped)
+    }
+
+    /// The cells in row-major order as a single row.
+    pub fn flatten(self) -> Self {
+        let len = self.dimension.len();
+        self.reshape(1, len).expect("a single row holds every cell")
+    }
+
+    /// Reverses the order of the rows, turning the matrix upside down.
+    pub fn flip_rows(&mut self) {
+        let height = self.height();
+        for row in 0..height / 2 {
+            self.swap_rows(row, height - 1 - row);
+        }
+    }
+
+    /// Reverses the order of the columns, mirroring the matrix left to right.
+    pub fn flip_cols(&mut self) {
+        let width = self.width();
+        for col in 0..width / 2 {
+            self.swap_columns(col, width - 1 - col);
+        }
+    }
+
+    /// Rotates by `k` quarter turns counterclockwise, or clockwise for negative `k`.
+    ///
+    /// Odd turns transpose through the layout flag, so no rotation copies the buffer.
+    pub fn rotate90(self, k: isize) -> Self {
+        match k.rem_euclid(4) {
+            0 => self,
+            1 => {
+                let mut rotated = self.into_transpose();
+                rotated.flip_rows();
+                rotated
+            }
+            2 => {
+                let mut rotated = self;
+                rotated.flip_rows();
+                rotated.flip_cols();
+                rotated
+            }
+            _ => {
+                let mut rotated = self.into_transpose();
+                rotated.flip_cols();
+                rotated
+            }
+        }
+    }
+
+    /// Cyclically shifts the rows down (`Axis::Rows`) or the columns right (`Axis::Columns`)
+    /// by `shift` places, wrapping around at the edge. Negative shifts go the other way.
+    pub fn roll(&mut self, shift: isize, axis: Axis) {
+        let (len, swap): (isize, fn(&mut Self, isize, isize)) = match axis {
+            Axis::Rows => (self.height(), Self::swap_rows),
+            Axis::Columns => (self.width(), Self::swap_columns),
+        };
+        if len == 0 {
+            return;
+        }
+
+        // Rotating right by `shift` is reversing everything, then each of the two parts.
+        let shift = shift.rem_euclid(len);
+        let mut reverse = |start: isize, end: isize| {
+            for i in 0..(end - start) / 2 {
+                swap(self, start + i, end - 1 - i);
+            }
+        };
+        reverse(0, len);
+        reverse(0, shift);
+        reverse(shift, len);
+    }
+}
+
+impl<T: Clone> MatrixContent<T> {
+    /// Copy of the matrix as `height` by `width`, keeping the overlapping top left block and
+    /// filling any new cells with `fill`. Fails if either size is negative.
+    pub fn resize(&self, height: isize, width: isize, fill: T) -> Result<Self, MisAlignment> {
+        if height < 0 || width < 0 {
+            return Err(MisAlignment);
+        }
+
+        Ok(MatrixContent::from_fn(
+            Dimension::new(width, height),
+            |row, col| {
+                if row < self.height() && col < self.width() {
+                    self[(row, col)].clone()
+                } else {
+                    fill.clone()
+                }
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrices::testing::rows;
+
+    /// `[0, 1, 2; 10, 11, 12]`
+    fn sample() -> MatrixContent<isize> {
+        MatrixContent::from_fn(Dimension::new(3, 2), |row, col| row * 10 + col)
+    }
+
+    #[test]
+    fn reshape_reads_cells_in_row_major_order() {
+        for layout in [Layout::RowMajor, Layout::ColumnMajor] {
+            let reshaped = sample().into_layout(layout).reshape(3, 2).unwrap();
+            assert_eq!(rows(&reshaped), vec![vec![0, 1], vec![2, 10], vec![11, 12]]);
+            let flat = sample().into_layout(layout).flatten();
+            assert_eq!(rows(&flat), vec![vec![0, 1, 2, 10, 11, 12]]);
+        }
+        assert!(sample().reshape(4, 2).is_err());
+        assert!(sample().reshape(-2, -3).is_err());
+        assert_eq!(sample().reshape(6, 1).unwrap().height(), 6);
+    }
+
+    #[test]
+    fn flips() {
+        let mut m = sample();
+        m.flip_rows();
+        assert_eq!(rows(&m), vec![vec![10, 11, 12], vec![0, 1, 2]]);
+        m.flip_cols();
+        assert_eq!(rows(&m), vec![vec![12, 11, 10], vec![2, 1, 0]]);
+    }
+
+    #[test]
+    fn quarter_turns() {
+        let m = sample();
+        assert_eq!(
+            rows(&m.clone().rotate90(1)),
+            vec![vec![2, 12], vec![1, 11], vec![0, 10]]
+        );
+        assert_eq!(
+            rows(&m.clone().rotate90(2)),
+            vec![vec![12, 11, 10], vec![2, 1, 0]]
+        );
+        assert_eq!(
+            rows(&m.clone().rotate90(-1)),
+            vec![vec![10, 0], vec![11, 1], vec![12, 2]]
+        );
+        assert_eq!(rows(&m.clone().rotate90(3)), rows(&m.clone().rotate90(-1)));
+        assert_eq!(rows(&m.clone().rotate90(4)), rows(&m));
+        assert_eq!(rows(&m.clone().rotate90(1).rotate90(-1)), rows(&m));
+    }
+
+    #[test]
+    fn roll_wraps_around() {
+        let mut m = MatrixContent::from_fn(Dimension::new(4, 3), |row, col| row * 10 + col);
+        m.roll(1, Axis::Rows);
+        assert_eq!(
+            m.column_iter(0).copied().collect::<Vec<_>>(),
+            vec![20, 0, 10]
+        );
+        m.roll(-1, Axis::Rows);
+        assert_eq!(
+            m.column_iter(0).copied().collect::<Vec<_>>(),
+            vec![0, 10, 20]
+        );
+
+        m.roll(6, Axis::Columns);
+        assert_eq!(m.row_iter(0).copied().collect::<Vec<_>>(), vec![2, 3, 0, 1]);
+        m.roll(-2, Axis::Columns);
+        m.roll(0, Axis::Columns);
+        assert_eq!(
+            m.row_iter(1).copied().collect::<Vec<_>>(),
+            vec![10, 11, 12, 13]
+        );
+
+        let mut empty = MatrixContent::<isize>::new(Dimension::new(2, 0), Vec::new());
+        empty.roll(3, Axis::Rows);
+        assert_eq!(empty.height(), 0);
+    }
+
+    #[test]
+    fn resize_keeps_the_top_left_block() {
+        let m = sample();
+        assert_eq!(
+            rows(&m.resize(3, 4, -1).unwrap()),
+            vec![
+                vec![0, 1, 2, -1],
+                vec![10, 11, 12, -1],
+                vec![-1, -1, -1, -1]
+            ]
+        );
+        assert_eq!(rows(&m.resize(1, 2, -1).unwrap()), vec![vec![0, 1]]);
+        assert_eq!(rows(&m.resize(0, 0, -1).unwrap()), Vec::<Vec<isize>>::new());
+        assert_eq!(m.resize(0, 3, -1).unwrap().width(), 3);
+        assert!(m.resize(-1, 2, -1).is_err());
+        assert!(m.resize(2, -1, -1).is_err());
+        assert!(m.resize(-1, -1, -1).is_err());
+    }
+}
@@ -7,14 +7,7 @@ pub struct MatrixRow<'a, T> {
 
 impl<'a, T> MatrixRow<'a, T> {
     pub fn iter(&'a self) -> MatrixIter<'a, T> {
-        let pos = (self.row * self.mat.dimension.width()) as usize;
-        let end = pos + self.mat.dimension.width() as usize - 1;
-        MatrixIter {
-            step: 1,
-            end,
-            pos,
-            mat: self.mat,
-        }
+        self.mat.row_iter(self.row)
     }
 }
 
@@ -70,14 +63,7 @@ impl<'a, T> IntoIterator for MatrixRow<'a, T> {
     type IntoIter = MatrixIter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let pos = (self.row * self.mat.dimension.width()) as usize;
-        let end = pos + self.mat.dimension.width() as usize - 1;
-        MatrixIter {
-            step: 1,
-            end,
-            pos,
-            mat: self.mat,
-        }
+        self.mat.row_iter(self.row)
     }
 }
 
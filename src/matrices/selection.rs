@@ -0,0 +1,149 @@
+use crate::dimension::Dimension;
+
+use super::{Get, MatrixContent, MisAlignment};
+
+impl<T> MatrixContent<T> {
+    /// Mask of the same shape marking the cells for which `predicate` holds.
+    pub fn mask(&self, mut predicate: impl FnMut(&T) -> bool) -> MatrixContent<bool> {
+        MatrixContent::from_fn(self.dimension.clone(), |row, col| {
+            predicate(&self[(row, col)])
+        })
+    }
+
+    fn resolve(indices: &[isize], len: isize) -> Result<Vec<isize>, MisAlignment> {
+        indices
+            .iter()
+            .map(|&index| {
+                let index = if index < 0 { len + index } else { index };
+                if (0..len).contains(&index) {
+                    Ok(index)
+                } else {
+                    Err(MisAlignment)
+                }
+            })
+            .collect()
+    }
+
+    /// Overwrites every cell selected by `mask` with `value`.
+    pub fn set_where(&mut self, mask: &MatrixContent<bool>, value: T) -> Result<(), MisAlignment>
+    where
+        T: Clone,
+    {
+        if self.dimension != mask.dimension {
+            return Err(MisAlignment);
+        }
+
+        for row in 0..self.height() {
+            for col in 0..self.width() {
+                if mask[(row, col)] {
+                    self[(row, col)] = value.clone();
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Clone> MatrixContent<T> {
+    /// Values of the cells selected by `mask`, in row-major order.
+    pub fn masked(&self, mask: &MatrixContent<bool>) -> Result<Vec<T>, MisAlignment> {
+        self.get(mask)
+            .map(|values| values.into_iter().cloned().collect())
+            .ok_or(MisAlignment)
+    }
+
+    /// Matrix made of the given rows, in the given order. Rows may repeat and negative indices
+    /// count from the bottom.
+    pub fn select_rows(&self, rows: &[isize]) -> Result<Self, MisAlignment> {
+        let rows = Self::resolve(rows, self.height())?;
+        let dimension = Dimension::new(self.width(), rows.len() as isize);
+        Ok(MatrixContent::from_fn(dimension, |row, col| {
+            self[(rows[row as usize], col)].clone()
+        }))
+    }
+
+    /// Matrix made of the given columns, in the given order. Columns may repeat and negative
+    /// indices count from the right.
+    pub fn select_columns(&self, columns: &[isize]) -> Result<Self, MisAlignment> {
+        let columns = Self::resolve(columns, self.width())?;
+        let dimension = Dimension::new(columns.len() as isize, self.height());
+        Ok(MatrixContent::from_fn(dimension, |row, col| {
+            self[(row, columns[col as usize])].clone()
+        }))
+    }
+}
+
+impl<'a, 'b, T: 'a> Get<'a, &'b MatrixContent<bool>> for MatrixContent<T> {
+    type Output = Vec<&'a T>;
+
+    /// References to the cells selected by the mask, in row-major order, or `None` when the mask
+    /// has a different shape.
+    fn get(&'a self, mask: &'b MatrixContent<bool>) -> Option<Self::Output> {
+        if self.dimension != mask.dimension {
+            return None;
+        }
+
+        let mut selected = Vec::new();
+        for row in 0..self.height() {
+            for col in 0..self.width() {
+                if mask[(row, col)] {
+                    selected.push(&self[(row, col)]);
+                }
+            }
+        }
+        Some(selected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> MatrixContent<i32> {
+        MatrixContent::new(Dimension::new(3, 3), vec![1, -2, 3, -4, 5, -6, 7, -8, 9])
+    }
+
+    #[test]
+    fn masks_select_in_row_major_order() {
+        let matrix = sample();
+        let positive = matrix.mask(|&value| value > 0);
+        assert!(positive[(0, 0)]);
+        assert!(!positive[(0, 1)]);
+        assert_eq!(matrix.masked(&positive).unwrap(), vec![1, 3, 5, 7, 9]);
+        assert_eq!(matrix.get(&positive).unwrap(), vec![&1, &3, &5, &7, &9]);
+
+        let wrong_shape = MatrixContent::new(Dimension::new(2, 1), vec![true, false]);
+        assert!(matrix.masked(&wrong_shape).is_err());
+        assert!(matrix.get(&wrong_shape).is_none());
+    }
+
+    #[test]
+    fn set_where_overwrites_selected_cells() {
+        let mut matrix = sample();
+        let negative = matrix.mask(|&value| value < 0);
+        matrix.set_where(&negative, 0).unwrap();
+        assert_eq!(
+            matrix,
+            MatrixContent::new(Dimension::new(3, 3), vec![1, 0, 3, 0, 5, 0, 7, 0, 9])
+        );
+
+        let wrong_shape = MatrixContent::new(Dimension::new(3, 1), vec![true; 3]);
+        assert!(matrix.set_where(&wrong_shape, 1).is_err());
+    }
+
+    #[test]
+    fn fancy_indexing_with_repeats_and_negative_indices() {
+        let matrix = sample();
+        assert_eq!(
+            matrix.select_rows(&[2, -3, 2]).unwrap(),
+            MatrixContent::new(Dimension::new(3, 3), vec![7, -8, 9, 1, -2, 3, 7, -8, 9])
+        );
+        assert_eq!(
+            matrix.select_columns(&[-1, 0]).unwrap(),
+            MatrixContent::new(Dimension::new(2, 3), vec![3, 1, -6, -4, 9, 7])
+        );
+        assert_eq!(matrix.select_rows(&[]).unwrap().height(), 0);
+        assert!(matrix.select_rows(&[3]).is_err());
+        assert!(matrix.select_columns(&[-4]).is_err());
+    }
+}
@@ -0,0 +1,366 @@
+use std::marker::PhantomData;
+use std::ops::{Add, Mul};
+
+use crate::dimension::Dimension;
+use crate::scalar::{One, Zero};
+
+use super::{MatrixContent, MisAlignment};
+
+/// The operations matrix multiplication is generic over.
+///
+/// `add` must be associative and commutative with identity `zero`, `mul` associative with
+/// identity `one`, and `mul` must distribute over `add`.
+pub trait Semiring {
+    type Element: Clone;
+
+    fn zero() -> Self::Element;
+    fn one() -> Self::Element;
+    fn add(a: &Self::Element, b: &Self::Element) -> Self::Element;
+    fn mul(a: &Self::Element, b: &Self::Element) -> Self::Element;
+}
+
+/// The usual `(+, *)` semiring of a numeric type.
+pub struct Arithmetic<T>(PhantomData<T>);
+
+impl<T: Clone + Zero + One + Add<Output = T> + Mul<Output = T>> Semiring for Arithmetic<T> {
+    type Element = T;
+
+    fn zero() -> T {
+        T::zero()
+    }
+
+    fn one() -> T {
+        T::one()
+    }
+
+    fn add(a: &T, b: &T) -> T {
+        a.clone() + b.clone()
+    }
+
+    fn mul(a: &T, b: &T) -> T {
+        a.clone() * b.clone()
+    }
+}
+
+/// Tropical `(min, +)` semiring over `f64`, with `+inf` meaning "no path".
+pub struct MinPlus;
+
+impl Semiring for MinPlus {
+    type Element = f64;
+
+    fn zero() -> f64 {
+        f64::INFINITY
+    }
+
+    fn one() -> f64 {
+        0.0
+    }
+
+    fn add(a: &f64, b: &f64) -> f64 {
+        a.min(*b)
+    }
+
+    fn mul(a: &f64, b: &f64) -> f64 {
+        a + b
+    }
+}
+
+/// Tropical `(max, +)` semiring over `f64`, with `-inf` meaning "no path".
+pub struct MaxPlus;
+
+impl Semiring for MaxPlus {
+    type Element = f64;
+
+    fn zero() -> f64 {
+        f64::NEG_INFINITY
+    }
+
+    fn one() -> f64 {
+        0.0
+    }
+
+    fn add(a: &f64, b: &f64) -> f64 {
+        a.max(*b)
+    }
+
+    fn mul(a: &f64, b: &f64) -> f64 {
+        a + b
+    }
+}
+
+/// Boolean `(or, and)` semiring.
+pub struct Boolean;
+
+impl Semiring for Boolean {
+    type Element = bool;
+
+    fn zero() -> bool {
+        false
+    }
+
+    fn one() -> bool {
+        true
+    }
+
+    fn add(a: &bool, b: &bool) -> bool {
+        *a || *b
+    }
+
+    fn mul(a: &bool, b: &bool) -> bool {
+        *a && *b
+    }
+}
+
+impl<T: Clone> MatrixContent<T> {
+    /// Matrix product with the given sum and product, each sum starting from `zero`.
+    pub(crate) fn fold_product(
+        &self,
+        rhs: &MatrixContent<T>,
+        zero: T,
+        add: impl Fn(&T, &T) -> T,
+        mul: impl Fn(&T, &T) -> T,
+    ) -> Result<MatrixContent<T>, MisAlignment> {
+        if self.width() != rhs.height() {
+            return Err(MisAlignment);
+        }
+
+        let (height, width) = (self.height(), rhs.width());
+        let mut buffer = vec![zero; (height * width) as usize];
+        for row in 0..height {
+            let out = &mut buffer[(row * width) as usize..((row + 1) * width) as usize];
+            for k in 0..self.width() {
+                let left = &self[(row, k)];
+                for (col, cell) in out.iter_mut().enumerate() {
+                    *cell = add(cell, &mul(left, &rhs[(k, col as isize)]));
+                }
+            }
+        }
+
+        Ok(MatrixContent::new(Dimension::new(width, height), buffer))
+    }
+
+    /// Matrix product where sums and products are taken in the semiring `S`.
+    pub fn semiring_mul<S: Semiring<Element = T>>(
+        &self,
+        rhs: &MatrixContent<T>,
+    ) -> Result<MatrixContent<T>, MisAlignment> {
+        self.fold_product(rhs, S::zero(), S::add, S::mul)
+    }
+
+    /// Raises a square matrix to a non-negative power in the semiring `S` by repeated squaring.
+    pub fn semiring_pow<S: Semiring<Element = T>>(
+        &self,
+        mut exponent: u32,
+    ) -> Result<MatrixContent<T>, MisAlignment> {
+        if self.width() != self.height() {
+            return Err(MisAlignment);
+        }
+
+        let mut result = MatrixContent::from_fn(self.dimension.clone(), |row, col| {
+            if row == col {
+                S::one()
+            } else {
+                S::zero()
+            }
+        });
+        let mut base = self.clone();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.semiring_mul::<S>(&base)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.semiring_mul::<S>(&base)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+impl MatrixContent<f64> {
+    /// All-pairs shortest path lengths of a weighted adjacency matrix.
+    ///
+    /// Entry `(i, j)` is the weight of the edge from `i` to `j`, with `f64::INFINITY` for a
+    /// missing edge. The diagonal is treated as zero-length self loops. Negative edge weights
+    /// are allowed but negative cycles are not detected.
+    pub fn all_pairs_shortest_paths(&self) -> Result<MatrixContent<f64>, MisAlignment> {
+        if self.width() != self.height() {
+            return Err(MisAlignment);
+        }
+
+        let mut distances = self.clone();
+        for i in 0..self.height() {
+            distances[(i, i)] = distances[(i, i)].min(0.0);
+        }
+
+        // With self loops in place, squaring doubles the path length covered each time.
+        let mut covered = 1;
+        while covered + 1 < self.height() {
+            distances = distances.semiring_mul::<MinPlus>(&distances)?;
+            covered *= 2;
+        }
+        Ok(distances)
+    }
+}
+
+impl MatrixContent<bool> {
+    /// Transitive closure of a directed graph: `(i, j)` is set when `j` is reachable from `i`
+    /// through one or more edges.
+    pub fn transitive_closure(&self) -> Result<MatrixContent<bool>, MisAlignment> {
+        if self.width() != self.height() {
+            return Err(MisAlignment);
+        }
+
+        let mut reach = self.clone();
+        for i in 0..self.height() {
+            reach[(i, i)] = true;
+        }
+
+        let mut covered = 1;
+        while covered + 1 < self.height() {
+            reach = reach.semiring_mul::<Boolean>(&reach)?;
+            covered *= 2;
+        }
+        self.semiring_mul::<Boolean>(&reach)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::SplitMix64;
+
+    fn random_graph(rng: &mut SplitMix64, n: isize, density: f64) -> MatrixContent<f64> {
+        MatrixContent::from_fn(Dimension::new(n, n), |_, _| {
+            if rng.next_f64() < density {
+                (1.0 + rng.next_f64() * 9.0).floor()
+            } else {
+                f64::INFINITY
+            }
+        })
+    }
+
+    fn floyd_warshall(graph: &MatrixContent<f64>) -> MatrixContent<f64> {
+        let n = graph.height();
+        let mut d = graph.clone();
+        for i in 0..n {
+            d[(i, i)] = d[(i, i)].min(0.0);
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    d[(i, j)] = d[(i, j)].min(d[(i, k)] + d[(k, j)]);
+                }
+            }
+        }
+        d
+    }
+
+    #[test]
+    fn arithmetic_semiring_matches_the_usual_product() {
+        let a = MatrixContent::new(Dimension::new(3, 2), vec![1, 2, 3, 4, 5, 6]);
+        let b = MatrixContent::new(Dimension::new(2, 3), vec![7, 8, 9, 10, 11, 12]);
+        let product = a.semiring_mul::<Arithmetic<i64>>(&b).unwrap();
+        assert_eq!(product, &a * &b);
+        assert_eq!(
+            product,
+            MatrixContent::new(Dimension::new(2, 2), vec![58, 64, 139, 154])
+        );
+        assert!(a.semiring_mul::<Arithmetic<i64>>(&a).is_err());
+
+        let square = MatrixContent::new(Dimension::new(2, 2), vec![1, 1, 1, 0]);
+        let fibonacci = square.semiring_pow::<Arithmetic<i64>>(10).unwrap();
+        assert_eq!(fibonacci[(0, 1)], 55);
+        assert_eq!(
+            square.semiring_pow::<Arithmetic<i64>>(0).unwrap(),
+            MatrixContent::identity(2)
+        );
+    }
+
+    /// A type with an additive identity but no multiplicative one.
+    #[derive(Debug, Clone, PartialEq)]
+    struct Even(i64);
+
+    impl Zero for Even {
+        fn zero() -> Self {
+            Even(0)
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl Add for Even {
+        type Output = Even;
+
+        fn add(self, rhs: Even) -> Even {
+            Even(self.0 + rhs.0)
+        }
+    }
+
+    impl Mul for Even {
+        type Output = Even;
+
+        fn mul(self, rhs: Even) -> Even {
+            Even(self.0 * rhs.0)
+        }
+    }
+
+    #[test]
+    fn product_does_not_need_a_multiplicative_identity() {
+        let a = MatrixContent::new(Dimension::new(2, 1), vec![Even(2), Even(4)]);
+        let b = MatrixContent::new(Dimension::new(1, 2), vec![Even(6), Even(-2)]);
+        assert_eq!(
+            &a * &b,
+            MatrixContent::new(Dimension::new(1, 1), vec![Even(4)])
+        );
+    }
+
+    #[test]
+    fn shortest_paths_match_floyd_warshall() {
+        let mut rng = SplitMix64::new(17);
+        for n in [1, 2, 5, 17, 40] {
+            let graph = random_graph(&mut rng, n, 0.15);
+            assert_eq!(
+                graph.all_pairs_shortest_paths().unwrap(),
+                floyd_warshall(&graph)
+            );
+        }
+    }
+
+    #[test]
+    fn max_plus_finds_heaviest_walks() {
+        // Of the two-edge walks from 0 to 2, 0 -> 1 -> 2 weighs 5 and 0 -> 2 -> 2 weighs 4.
+        let ninf = f64::NEG_INFINITY;
+        let graph = MatrixContent::new(
+            Dimension::new(3, 3),
+            vec![ninf, 2.0, 3.0, ninf, ninf, 3.0, ninf, ninf, 1.0],
+        );
+        let walks = graph.semiring_pow::<MaxPlus>(2).unwrap();
+        assert_eq!(walks[(0, 2)], 5.0);
+        assert_eq!(walks[(1, 2)], 4.0);
+        assert_eq!(walks[(0, 1)], ninf);
+    }
+
+    #[test]
+    fn transitive_closure_matches_reachability() {
+        let mut rng = SplitMix64::new(23);
+        let n = 30;
+        let graph = MatrixContent::from_fn(Dimension::new(n, n), |_, _| rng.next_f64() < 0.05);
+        let closure = graph.transitive_closure().unwrap();
+        for start in 0..n {
+            let mut reached = vec![false; n as usize];
+            let mut stack: Vec<isize> = (0..n).filter(|&j| graph[(start, j)]).collect();
+            while let Some(node) = stack.pop() {
+                if !reached[node as usize] {
+                    reached[node as usize] = true;
+                    stack.extend((0..n).filter(|&j| graph[(node, j)]));
+                }
+            }
+            for end in 0..n {
+                assert_eq!(closure[(start, end)], reached[end as usize]);
+            }
+        }
+    }
+}
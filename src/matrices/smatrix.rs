@@ -0,0 +1,227 @@
+use std::ops::{Add, Index, IndexMut, Mul, Sub};
+
+use crate::dimension::Dimension;
+use crate::scalar::{One, Zero};
+
+use super::{GenericMatrix, MatrixContent, MisAlignment};
+
+/// Stack allocated matrix whose shape is part of its type.
+///
+/// Shape mismatches in `+`, `-` and `*` are compile errors rather than panics, and small
+/// matrices such as 3x3 or 4x4 transforms carry no heap buffer or runtime [`Dimension`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SMatrix<T, const R: usize, const C: usize> {
+    data: [[T; C]; R],
+}
+
+pub type SMatrix2<T> = SMatrix<T, 2, 2>;
+pub type SMatrix3<T> = SMatrix<T, 3, 3>;
+pub type SMatrix4<T> = SMatrix<T, 4, 4>;
+
+impl<T, const R: usize, const C: usize> SMatrix<T, R, C> {
+    pub const fn from_rows(data: [[T; C]; R]) -> Self {
+        Self { data }
+    }
+
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        Self {
+            data: std::array::from_fn(|row| std::array::from_fn(|col| f(row, col))),
+        }
+    }
+
+    pub const fn width(&self) -> usize {
+        C
+    }
+
+    pub const fn height(&self) -> usize {
+        R
+    }
+
+    pub fn into_rows(self) -> [[T; C]; R] {
+        self.data
+    }
+}
+
+impl<T: Copy, const R: usize, const C: usize> SMatrix<T, R, C> {
+    pub fn transpose(&self) -> SMatrix<T, C, R> {
+        SMatrix::from_fn(|row, col| self.data[col][row])
+    }
+}
+
+impl<T: Copy + Zero, const R: usize, const C: usize> SMatrix<T, R, C> {
+    pub fn zeros() -> Self {
+        Self::from_fn(|_, _| T::zero())
+    }
+}
+
+impl<T: Copy + Zero + One, const N: usize> SMatrix<T, N, N> {
+    pub fn identity() -> Self {
+        Self::from_fn(|row, col| if row == col { T::one() } else { T::zero() })
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for SMatrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.data[row][col]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for SMatrix<T, R, C> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        &mut self.data[row][col]
+    }
+}
+
+impl<T: Copy + Add<Output = T>, const R: usize, const C: usize> Add for SMatrix<T, R, C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_fn(|row, col| self.data[row][col] + rhs.data[row][col])
+    }
+}
+
+impl<T: Copy + Sub<Output = T>, const R: usize, const C: usize> Sub for SMatrix<T, R, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_fn(|row, col| self.data[row][col] - rhs.data[row][col])
+    }
+}
+
+impl<T, const R: usize, const K: usize, const C: usize> Mul<SMatrix<T, K, C>> for SMatrix<T, R, K>
+where
+    T: Copy + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    type Output = SMatrix<T, R, C>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: SMatrix<T, K, C>) -> Self::Output {
+        SMatrix::from_fn(|row, col| {
+            (0..K).fold(T::zero(), |acc, k| {
+                acc + self.data[row][k] * rhs.data[k][col]
+            })
+        })
+    }
+}
+
+impl<T: Copy + Mul<Output = T>, const R: usize, const C: usize> Mul<T> for SMatrix<T, R, C> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::from_fn(|row, col| self.data[row][col] * rhs)
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<SMatrix<T, R, C>> for MatrixContent<T> {
+    fn from(value: SMatrix<T, R, C>) -> Self {
+        MatrixContent::new(
+            Dimension::new(C as isize, R as isize),
+            value.data.into_iter().flatten().collect(),
+        )
+    }
+}
+
+impl<const R: usize, const C: usize> From<SMatrix<f64, R, C>> for GenericMatrix {
+    fn from(value: SMatrix<f64, R, C>) -> Self {
+        GenericMatrix::from_content(value.into())
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> TryFrom<&MatrixContent<T>> for SMatrix<T, R, C> {
+    type Error = MisAlignment;
+
+    fn try_from(value: &MatrixContent<T>) -> Result<Self, Self::Error> {
+        if value.height() != R as isize || value.width() != C as isize {
+            return Err(MisAlignment);
+        }
+
+        Ok(Self::from_fn(|row, col| {
+            value[(row as isize, col as isize)].clone()
+        }))
+    }
+}
+
+impl<T: Clone, const R: usize, const C: usize> TryFrom<MatrixContent<T>> for SMatrix<T, R, C> {
+    type Error = MisAlignment;
+
+    fn try_from(value: MatrixContent<T>) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl<const R: usize, const C: usize> TryFrom<&GenericMatrix> for SMatrix<f64, R, C> {
+    type Error = MisAlignment;
+
+    fn try_from(value: &GenericMatrix) -> Result<Self, Self::Error> {
+        Self::try_from(&value.content)
+    }
+}
+
+impl<T: std::fmt::Display, const R: usize, const C: usize> std::fmt::Display for SMatrix<T, R, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::new();
+        for row in &self.data {
+            for item in row {
+                output.push_str(&format!("{}, ", item));
+            }
+            output.pop();
+            output.pop();
+            output.push('\n');
+        }
+        output.pop();
+        write!(f, "{}", output)
+    }
+}
+
+impl<T: std::fmt::Debug, const R: usize, const C: usize> std::fmt::Debug for SMatrix<T, R, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::new();
+        for row in &self.data {
+            for item in row {
+                output.push_str(&format!("{:?}, ", item));
+            }
+            output.pop();
+            output.pop();
+            output.push('\n');
+        }
+        output.pop();
+        write!(f, "{}", output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = SMatrix::<i64, 2, 3>::from_rows([[1, 2, 3], [4, 5, 6]]);
+        let b = SMatrix::<i64, 3, 2>::from_rows([[7, 8], [9, 10], [11, 12]]);
+        assert_eq!((a * b).into_rows(), [[58, 64], [139, 154]]);
+        assert_eq!(a.transpose(), SMatrix::from_rows([[1, 4], [2, 5], [3, 6]]));
+        assert_eq!((a + a).into_rows(), (a * 2).into_rows());
+        assert_eq!(a - a, SMatrix::zeros());
+        assert_eq!(SMatrix3::<i64>::identity() * b, b);
+        assert_eq!(a[(1, 2)], 6);
+    }
+
+    #[test]
+    fn product_matches_dense_multiplication() {
+        let a = SMatrix4::<f64>::from_fn(|row, col| (row * 4 + col) as f64 - 7.5);
+        let b = SMatrix4::<f64>::from_fn(|row, col| 1.0 / (row + col + 1) as f64);
+        let dense = &MatrixContent::from(a) * &MatrixContent::from(b);
+        assert_eq!(MatrixContent::from(a * b), dense);
+    }
+
+    #[test]
+    fn conversions_check_the_shape() {
+        let dense = MatrixContent::new(Dimension::new(3, 2), vec![1, 2, 3, 4, 5, 6]);
+        let fixed = SMatrix::<i64, 2, 3>::try_from(&dense).unwrap();
+        assert_eq!(fixed[(1, 0)], 4);
+        assert_eq!(MatrixContent::from(fixed), dense);
+        assert!(SMatrix::<i64, 3, 2>::try_from(&dense).is_err());
+        assert!(SMatrix2::<i64>::try_from(dense).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+use std::cmp::Ordering;
+
+use super::{columns::MatrixColumn, rows::MatrixRow, stacking::Axis, MatrixContent};
+
+/// Total order over a `PartialOrd` type: values that do not compare equal to themselves, such as
+/// `NaN`, sort after everything else.
+fn compare<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or_else(|| {
+        let unordered = |value: &T| value.partial_cmp(value).is_none();
+        unordered(a).cmp(&unordered(b))
+    })
+}
+
+/// First position in `0..len` whose value is not less than `value`.
+fn lower_bound<'a, T: PartialOrd + 'a>(
+    len: isize,
+    value: &T,
+    at: impl Fn(isize) -> &'a T,
+) -> isize {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if compare(at(mid), value) == Ordering::Less {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+impl<T> MatrixContent<T> {
+    /// Moves rows so that row `i` of the result is row `order[i]` of the original.
+    fn permute_rows(&mut self, order: &[isize]) {
+        let mut visited = vec![false; order.len()];
+        for start in 0..order.len() {
+            let mut current = start;
+            while !visited[current] {
+                visited[current] = true;
+                let next = order[current] as usize;
+                if next == start {
+                    break;
+                }
+                self.swap_rows(current as isize, next as isize);
+                current = next;
+            }
+        }
+    }
+
+    /// Stably sorts the rows by the key extracted from each of them.
+    pub fn sort_by_key<K: Ord>(&mut self, mut key: impl FnMut(&MatrixRow<'_, T>) -> K) {
+        let keys: Vec<K> = self.rows().map(|row| key(&row)).collect();
+        let mut order: Vec<isize> = (0..self.height()).collect();
+        order.sort_by(|&a, &b| keys[a as usize].cmp(&keys[b as usize]));
+        self.permute_rows(&order);
+    }
+
+    /// Cell coordinates of the first cell, in row-major order, for which `predicate` holds.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(isize, isize)> {
+        (0..self.height())
+            .flat_map(|row| (0..self.width()).map(move |col| (row, col)))
+            .find(|&index| predicate(&self[index]))
+    }
+
+    /// Cell coordinates of the first cell, in row-major order, equal to `value`.
+    pub fn find(&self, value: &T) -> Option<(isize, isize)>
+    where
+        T: PartialEq,
+    {
+        self.position(|item| item == value)
+    }
+}
+
+impl<T: PartialOrd> MatrixContent<T> {
+    /// Stably sorts the rows by their values in column `col`. `NaN`-like values go last.
+    pub fn sort_rows_by(&mut self, col: isize) {
+        let mut order: Vec<isize> = (0..self.height()).collect();
+        order.sort_by(|&a, &b| compare(&self[(a, col)], &self[(b, col)]));
+        self.permute_rows(&order);
+    }
+
+    /// Indices that would stably sort each column (`Axis::Rows`) or each row (`Axis::Columns`).
+    /// Cell `(i, j)` holds the index along `axis` of the value that belongs at that position.
+    pub fn argsort(&self, axis: Axis) -> MatrixContent<isize> {
+        let mut result = MatrixContent::from_fn(self.dimension.clone(), |row, col| match axis {
+            Axis::Rows => row,
+            Axis::Columns => col,
+        });
+        match axis {
+            Axis::Rows => {
+                for col in 0..self.width() {
+                    let mut order: Vec<isize> = (0..self.height()).collect();
+                    order.sort_by(|&a, &b| compare(&self[(a, col)], &self[(b, col)]));
+                    for (row, index) in order.into_iter().enumerate() {
+                        result[(row as isize, col)] = index;
+                    }
+                }
+            }
+            Axis::Columns => {
+                for row in 0..self.height() {
+                    let mut order: Vec<isize> = (0..self.width()).collect();
+                    order.sort_by(|&a, &b| compare(&self[(row, a)], &self[(row, b)]));
+                    for (col, index) in order.into_iter().enumerate() {
+                        result[(row, col as isize)] = index;
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<T: PartialEq + Clone> MatrixContent<T> {
+    /// The distinct rows, each kept at its first occurrence.
+    pub fn unique_rows(&self) -> Self {
+        let mut kept: Vec<isize> = Vec::new();
+        for row in 0..self.height() {
+            let seen = kept
+                .iter()
+                .any(|&other| (0..self.width()).all(|col| self[(row, col)] == self[(other, col)]));
+            if !seen {
+                kept.push(row);
+            }
+        }
+        self.select_rows(&kept)
+            .expect("kept rows are within the matrix")
+    }
+}
+
+impl<'a, T: PartialOrd> MatrixRow<'a, T> {
+    /// Index at which `value` would be inserted to keep this sorted row sorted, before any equal
+    /// values.
+    pub fn searchsorted(&self, value: &T) -> isize {
+        lower_bound(self.mat.width(), value, |col| &self.mat[(self.row, col)])
+    }
+}
+
+impl<'a, T: PartialOrd> MatrixColumn<'a, T> {
+    /// Index at which `value` would be inserted to keep this sorted column sorted, before any
+    /// equal values.
+    pub fn searchsorted(&self, value: &T) -> isize {
+        lower_bound(self.mat.height(), value, |row| &self.mat[(row, self.col)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimension::Dimension;
+    use crate::matrices::indices::{ColumnIndex, RowIndex};
+    use crate::matrices::Get;
+
+    fn rows(values: &[&[f64]]) -> MatrixContent<f64> {
+        let width = values.first().map_or(0, |row| row.len()) as isize;
+        let buffer = values.iter().flat_map(|row| row.iter().copied()).collect();
+        MatrixContent::new(Dimension::new(width, values.len() as isize), buffer)
+    }
+
+    #[test]
+    fn sort_rows_is_stable_and_puts_nan_last() {
+        let mut m = rows(&[
+            &[3.0, 0.0],
+            &[f64::NAN, 1.0],
+            &[1.0, 2.0],
+            &[3.0, 3.0],
+            &[-2.0, 4.0],
+        ]);
+        m.sort_rows_by(0);
+        let tags: Vec<f64> = (0..5).map(|row| m[(row, 1)]).collect();
+        assert_eq!(tags, vec![4.0, 2.0, 0.0, 3.0, 1.0]);
+        assert!(m[(4, 0)].is_nan());
+    }
+
+    #[test]
+    fn sort_by_key_permutes_whole_rows() {
+        let mut m = MatrixContent::from_fn(Dimension::new(3, 6), |row, col| {
+            ((row * 5 + 2) % 6) * 10 + col
+        });
+        m.sort_by_key(|row| row.iter().sum::<isize>());
+        let firsts: Vec<isize> = (0..6).map(|row| m[(row, 0)]).collect();
+        assert_eq!(firsts, vec![0, 10, 20, 30, 40, 50]);
+        assert!((0..6).all(|row| m[(row, 2)] == m[(row, 0)] + 2));
+    }
+
+    #[test]
+    fn argsort_along_each_axis() {
+        let m = rows(&[&[3.0, 1.0, 2.0], &[1.0, f64::NAN, 2.0], &[2.0, 0.0, 2.0]]);
+        assert_eq!(
+            m.argsort(Axis::Rows),
+            MatrixContent::new(Dimension::new(3, 3), vec![1, 2, 0, 2, 0, 1, 0, 1, 2])
+        );
+        assert_eq!(
+            m.argsort(Axis::Columns),
+            MatrixContent::new(Dimension::new(3, 3), vec![1, 2, 0, 0, 2, 1, 1, 0, 2])
+        );
+    }
+
+    #[test]
+    fn unique_rows_and_searches() {
+        let m = rows(&[
+            &[1.0, 2.0],
+            &[3.0, 4.0],
+            &[1.0, 2.0],
+            &[5.0, 6.0],
+            &[3.0, 4.0],
+        ]);
+        assert_eq!(
+            m.unique_rows(),
+            rows(&[&[1.0, 2.0], &[3.0, 4.0], &[5.0, 6.0]])
+        );
+        assert_eq!(m.find(&4.0), Some((1, 1)));
+        assert_eq!(m.find(&7.0), None);
+        assert_eq!(m.position(|&value| value > 4.0), Some((3, 0)));
+    }
+
+    #[test]
+    fn searchsorted_returns_the_leftmost_insertion_point() {
+        let m = rows(&[&[1.0, 2.0, 2.0, 4.0], &[0.0, 5.0, 6.0, 7.0]]);
+        let row = m.get(RowIndex::from(0)).unwrap();
+        assert_eq!(row.searchsorted(&0.5), 0);
+        assert_eq!(row.searchsorted(&2.0), 1);
+        assert_eq!(row.searchsorted(&3.0), 3);
+        assert_eq!(row.searchsorted(&9.0), 4);
+        let column = m.get(ColumnIndex::from(3)).unwrap();
+        assert_eq!(column.searchsorted(&5.0), 1);
+        assert_eq!(column.searchsorted(&f64::NAN), 2);
+    }
+}
@@ -0,0 +1,227 @@
+use std::ops::Add;
+
+use crate::dimension::Dimension;
+use crate::matrices::MatrixContent;
+use crate::scalar::Zero;
+
+use super::{CscMatrix, CsrMatrix};
+
+/// Coordinate format builder collecting `(row, column, value)` triplets in any order.
+///
+/// Triplets that share a position are kept separately until the matrix is finalized with
+/// [`CooMatrix::to_csr`], [`CooMatrix::to_csc`] or [`CooMatrix::to_dense`], at which point they
+/// are summed. This matches how finite element assembly scatters element contributions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T> {
+    dimension: Dimension,
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CooMatrix<T> {
+    pub fn new(height: usize, width: usize) -> Self {
+        Self::with_capacity(height, width, 0)
+    }
+
+    pub fn with_capacity(height: usize, width: usize, capacity: usize) -> Self {
+        Self {
+            dimension: Dimension::new(width as isize, height as isize),
+            rows: Vec::with_capacity(capacity),
+            columns: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn width(&self) -> isize {
+        self.dimension.width()
+    }
+
+    pub fn height(&self) -> isize {
+        self.dimension.height()
+    }
+
+    /// Number of triplets pushed so far, counting duplicates separately.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn push(&mut self, row: usize, col: usize, value: T) {
+        if row >= self.height() as usize || col >= self.width() as usize {
+            panic!(
+                "Triplet ({}, {}) lies outside a {}x{} matrix",
+                row,
+                col,
+                self.height(),
+                self.width()
+            );
+        }
+
+        self.rows.push(row);
+        self.columns.push(col);
+        self.values.push(value);
+    }
+
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.rows
+            .iter()
+            .zip(self.columns.iter())
+            .zip(self.values.iter())
+            .map(|((row, col), value)| (*row, *col, value))
+    }
+}
+
+impl<T> Extend<(usize, usize, T)> for CooMatrix<T> {
+    fn extend<I: IntoIterator<Item = (usize, usize, T)>>(&mut self, iter: I) {
+        for (row, col, value) in iter {
+            self.push(row, col, value);
+        }
+    }
+}
+
+impl<T: Clone + Add<Output = T>> CooMatrix<T> {
+    /// Compresses the triplets by row, summing duplicates.
+    ///
+    /// Triplets are bucketed by row with a counting sort and then sorted by column within each
+    /// row, so the cost is linear in the number of triplets plus the per-row sorting.
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let height = self.height() as usize;
+        let mut bucket_offsets = vec![0; height + 1];
+        for &row in &self.rows {
+            bucket_offsets[row + 1] += 1;
+        }
+        for i in 0..height {
+            bucket_offsets[i + 1] += bucket_offsets[i];
+        }
+
+        let mut next = bucket_offsets.clone();
+        let mut order = vec![0; self.len()];
+        for (i, &row) in self.rows.iter().enumerate() {
+            order[next[row]] = i;
+            next[row] += 1;
+        }
+
+        let mut row_offsets = vec![0];
+        let mut column_indices: Vec<usize> = Vec::with_capacity(self.len());
+        let mut values: Vec<T> = Vec::with_capacity(self.len());
+        for row in 0..height {
+            let bucket = &mut order[bucket_offsets[row]..bucket_offsets[row + 1]];
+            bucket.sort_by_key(|&i| self.columns[i]);
+
+            let start = values.len();
+            for &i in bucket.iter() {
+                let col = self.columns[i];
+                if values.len() > start && column_indices.last() == Some(&col) {
+                    let sum = values.pop().unwrap() + self.values[i].clone();
+                    values.push(sum);
+                } else {
+                    column_indices.push(col);
+                    values.push(self.values[i].clone());
+                }
+            }
+            row_offsets.push(values.len());
+        }
+
+        CsrMatrix {
+            dimension: self.dimension.clone(),
+            row_offsets,
+            column_indices,
+            values,
+        }
+    }
+
+    /// Compresses the triplets by column, summing duplicates.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let transposed = CooMatrix {
+            dimension: Dimension::new(self.height(), self.width()),
+            rows: self.columns.clone(),
+            columns: self.rows.clone(),
+            values: self.values.clone(),
+        };
+        CscMatrix {
+            transposed: transposed.to_csr(),
+        }
+    }
+}
+
+impl<T: Clone + Zero + Add<Output = T>> CooMatrix<T> {
+    /// Scatters the triplets into a dense matrix, summing duplicates.
+    pub fn to_dense(&self) -> MatrixContent<T> {
+        let mut dense = MatrixContent::from_fn(self.dimension.clone(), |_, _| T::zero());
+        for (row, col, value) in self.triplets() {
+            let index = (row as isize, col as isize);
+            dense[index] = dense[index].clone() + value.clone();
+        }
+        dense
+    }
+}
+
+impl<T: Clone> From<&CsrMatrix<T>> for CooMatrix<T> {
+    fn from(value: &CsrMatrix<T>) -> Self {
+        let mut coo =
+            CooMatrix::with_capacity(value.height() as usize, value.width() as usize, value.nnz());
+        coo.extend(
+            value
+                .triplets()
+                .map(|(row, col, value)| (row, col, value.clone())),
+        );
+        coo
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assembled() -> CooMatrix<i64> {
+        // Two overlapping 2x2 element matrices on a 3-node chain, pushed out of order.
+        let mut coo = CooMatrix::new(3, 3);
+        coo.extend([(1, 1, 1), (2, 2, 1), (1, 2, -1), (2, 1, -1)]);
+        coo.extend([(0, 0, 1), (0, 1, -1), (1, 0, -1), (1, 1, 1)]);
+        coo
+    }
+
+    #[test]
+    fn duplicates_are_summed_in_every_format() {
+        let coo = assembled();
+        assert_eq!(coo.len(), 8);
+        let expected =
+            MatrixContent::new(Dimension::new(3, 3), vec![1, -1, 0, -1, 2, -1, 0, -1, 1]);
+        assert_eq!(coo.to_dense(), expected);
+
+        let csr = coo.to_csr();
+        assert_eq!(csr.nnz(), 7);
+        assert_eq!(csr.row_offsets(), &[0, 2, 5, 7]);
+        assert_eq!(csr.column_indices(), &[0, 1, 0, 1, 2, 1, 2]);
+        assert_eq!(MatrixContent::from(&csr), expected);
+        assert_eq!(MatrixContent::from(&coo.to_csc()), expected);
+    }
+
+    #[test]
+    fn round_trip_through_csr() {
+        let csr = assembled().to_csr();
+        let coo = CooMatrix::from(&csr);
+        assert_eq!(coo.len(), csr.nnz());
+        assert_eq!(coo.to_csr(), csr);
+    }
+
+    #[test]
+    fn empty_rows_and_matrices() {
+        let mut coo = CooMatrix::new(4, 2);
+        assert!(coo.is_empty());
+        assert_eq!(coo.to_csr().row_offsets(), &[0, 0, 0, 0, 0]);
+        coo.push(3, 1, 2.5);
+        assert_eq!(coo.to_csr().row_offsets(), &[0, 0, 0, 0, 1]);
+        assert_eq!(coo.to_csc().column_offsets(), &[0, 0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside a 2x2 matrix")]
+    fn push_rejects_out_of_range_triplets() {
+        CooMatrix::new(2, 2).push(0, 2, 1);
+    }
+}
@@ -0,0 +1,256 @@
+use std::ops::{Add, Mul};
+
+use crate::dimension::Dimension;
+use crate::matrices::{MatrixContent, MisAlignment};
+use crate::scalar::Zero;
+
+use super::{CsrMatrix, SparseIter};
+
+/// Compressed sparse column matrix.
+///
+/// Column-compressed storage of a matrix is exactly row-compressed storage of its transpose,
+/// so this wraps the [`CsrMatrix`] of the transpose and reinterprets its rows as columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix<T> {
+    pub(crate) transposed: CsrMatrix<T>,
+}
+
+impl<T> CscMatrix<T> {
+    /// Wraps raw CSC arrays after checking that they describe a valid matrix.
+    pub fn from_parts(
+        height: usize,
+        width: usize,
+        column_offsets: Vec<usize>,
+        row_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self, MisAlignment> {
+        Ok(Self {
+            transposed: CsrMatrix::from_parts(width, height, column_offsets, row_indices, values)?,
+        })
+    }
+
+    pub fn width(&self) -> isize {
+        self.transposed.height()
+    }
+
+    pub fn height(&self) -> isize {
+        self.transposed.width()
+    }
+
+    /// Number of explicitly stored entries.
+    pub fn nnz(&self) -> usize {
+        self.transposed.nnz()
+    }
+
+    pub fn column_offsets(&self) -> &[usize] {
+        self.transposed.row_offsets()
+    }
+
+    pub fn row_indices(&self) -> &[usize] {
+        self.transposed.column_indices()
+    }
+
+    pub fn values(&self) -> &[T] {
+        self.transposed.values()
+    }
+
+    /// The stored entries of column `col` as `(row, &value)` pairs.
+    pub fn column(&self, col: usize) -> SparseIter<'_, T> {
+        self.transposed.row(col)
+    }
+
+    /// Every stored entry as `(row, column, &value)` in column-major order.
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.transposed
+            .triplets()
+            .map(|(col, row, value)| (row, col, value))
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.transposed.get(col, row)
+    }
+}
+
+impl<T: Clone> CscMatrix<T> {
+    pub fn transpose(&self) -> CscMatrix<T> {
+        CscMatrix {
+            transposed: self.transposed.transpose(),
+        }
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        self.transposed.transpose()
+    }
+}
+
+impl<T: Clone + Add<Output = T>> CscMatrix<T> {
+    /// Builds a matrix from `(row, column, value)` triplets in any order, summing duplicates.
+    pub fn from_triplets(
+        height: usize,
+        width: usize,
+        triplets: &[(usize, usize, T)],
+    ) -> Result<Self, MisAlignment> {
+        let swapped: Vec<_> = triplets
+            .iter()
+            .map(|(row, col, value)| (*col, *row, value.clone()))
+            .collect();
+        Ok(Self {
+            transposed: CsrMatrix::from_triplets(width, height, &swapped)?,
+        })
+    }
+}
+
+impl<T: Clone + Zero> From<&MatrixContent<T>> for CscMatrix<T> {
+    fn from(value: &MatrixContent<T>) -> Self {
+        let mut column_offsets = vec![0];
+        let mut row_indices = Vec::new();
+        let mut values = Vec::new();
+        for col in 0..value.width() {
+            for row in 0..value.height() {
+                let entry = &value[(row, col)];
+                if !entry.is_zero() {
+                    row_indices.push(row as usize);
+                    values.push(entry.clone());
+                }
+            }
+            column_offsets.push(values.len());
+        }
+
+        Self {
+            transposed: CsrMatrix {
+                dimension: Dimension::new(value.height(), value.width()),
+                row_offsets: column_offsets,
+                column_indices: row_indices,
+                values,
+            },
+        }
+    }
+}
+
+impl<T: Clone + Zero> From<&CscMatrix<T>> for MatrixContent<T> {
+    fn from(value: &CscMatrix<T>) -> Self {
+        let dimension = Dimension::new(value.width(), value.height());
+        let mut dense = MatrixContent::from_fn(dimension, |_, _| T::zero());
+        for (row, col, entry) in value.triplets() {
+            dense[(row as isize, col as isize)] = entry.clone();
+        }
+        dense
+    }
+}
+
+impl<T: Clone + Zero + Add<Output = T> + Mul<Output = T>> CscMatrix<T> {
+    /// Sparse matrix-vector product `A x`, scattering each column into the result.
+    pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>, MisAlignment> {
+        if x.len() != self.width() as usize {
+            return Err(MisAlignment);
+        }
+
+        let mut y = vec![T::zero(); self.height() as usize];
+        for (col, x) in x.iter().enumerate() {
+            for (row, value) in self.column(col) {
+                y[row] = y[row].clone() + value.clone() * x.clone();
+            }
+        }
+        Ok(y)
+    }
+}
+
+impl<'a, T: Clone + Zero + Add<Output = T> + Mul<Output = T>> Mul<&'a MatrixContent<T>>
+    for &CscMatrix<T>
+{
+    type Output = MatrixContent<T>;
+
+    fn mul(self, rhs: &'a MatrixContent<T>) -> Self::Output {
+        if self.width() != rhs.height() {
+            panic!("The width of the left matrix must equal the height of the right matrix in order to multiply them");
+        }
+
+        let dimension = Dimension::new(rhs.width(), self.height());
+        let mut result = MatrixContent::from_fn(dimension, |_, _| T::zero());
+        for k in 0..self.width() {
+            for (row, value) in self.column(k as usize) {
+                let row = row as isize;
+                for col in 0..rhs.width() {
+                    result[(row, col)] =
+                        result[(row, col)].clone() + value.clone() * rhs[(k, col)].clone();
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<'a, T: Clone + Zero + Add<Output = T> + Mul<Output = T>> Mul<&'a CscMatrix<T>>
+    for &CscMatrix<T>
+{
+    type Output = CscMatrix<T>;
+
+    /// Computed as `(B^T A^T)^T`, which is a row-compressed product of the stored transposes.
+    fn mul(self, rhs: &'a CscMatrix<T>) -> Self::Output {
+        if self.width() != rhs.height() {
+            panic!("The width of the left matrix must equal the height of the right matrix in order to multiply them");
+        }
+
+        CscMatrix {
+            transposed: &rhs.transposed * &self.transposed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> MatrixContent<i64> {
+        MatrixContent::new(
+            Dimension::new(4, 3),
+            vec![0, 2, 0, 1, 3, 0, 0, 0, 0, 4, 5, 0],
+        )
+    }
+
+    #[test]
+    fn columns_are_stored_contiguously() {
+        let csc = CscMatrix::from(&sample());
+        assert_eq!(csc.column_offsets(), &[0, 1, 3, 4, 5]);
+        assert_eq!(csc.row_indices(), &[1, 0, 2, 2, 0]);
+        assert_eq!(csc.values(), &[3, 2, 4, 5, 1]);
+        assert_eq!(csc.column(1).collect::<Vec<_>>(), vec![(0, &2), (2, &4)]);
+        assert_eq!(csc.get(2, 2), Some(&5));
+        assert_eq!(csc.get(1, 1), None);
+        assert_eq!(MatrixContent::from(&csc), sample());
+        assert_eq!(MatrixContent::from(&csc.to_csr()), sample());
+        assert_eq!(
+            MatrixContent::from(&csc.transpose()),
+            sample().into_transpose()
+        );
+    }
+
+    #[test]
+    fn triplets_and_products() {
+        let csc = CscMatrix::from_triplets(
+            3,
+            4,
+            &[
+                (1, 0, 3),
+                (0, 1, 1),
+                (0, 1, 1),
+                (2, 1, 4),
+                (2, 2, 5),
+                (0, 3, 1),
+            ],
+        )
+        .unwrap();
+        assert_eq!(MatrixContent::from(&csc), sample());
+        assert!(CscMatrix::from_triplets(3, 4, &[(0, 4, 1)]).is_err());
+
+        let x = [1, -1, 2, 3];
+        assert_eq!(csc.mul_vec(&x).unwrap(), vec![1, 3, 6]);
+        let dense = MatrixContent::new(Dimension::new(2, 4), vec![1, 0, 0, 1, 2, 2, -1, 3]);
+        assert_eq!(&csc * &dense, &sample() * &dense);
+        let square = CscMatrix::from(&sample().into_transpose());
+        assert_eq!(
+            MatrixContent::from(&(&csc * &square)),
+            &sample() * &sample().into_transpose()
+        );
+    }
+}
@@ -0,0 +1,350 @@
+use std::ops::{Add, Mul};
+
+use crate::dimension::Dimension;
+use crate::matrices::{MatrixContent, MisAlignment};
+use crate::scalar::Zero;
+
+use super::{CooMatrix, CscMatrix, SparseIter};
+
+/// Compressed sparse row matrix.
+///
+/// The column indices and values of row `i` are stored in
+/// `row_offsets[i]..row_offsets[i + 1]`, with column indices strictly increasing in each row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T> {
+    pub(crate) dimension: Dimension,
+    pub(crate) row_offsets: Vec<usize>,
+    pub(crate) column_indices: Vec<usize>,
+    pub(crate) values: Vec<T>,
+}
+
+impl<T> CsrMatrix<T> {
+    /// Wraps raw CSR arrays after checking that they describe a valid matrix.
+    pub fn from_parts(
+        height: usize,
+        width: usize,
+        row_offsets: Vec<usize>,
+        column_indices: Vec<usize>,
+        values: Vec<T>,
+    ) -> Result<Self, MisAlignment> {
+        let valid = row_offsets.len() == height + 1
+            && row_offsets[0] == 0
+            && row_offsets[height] == column_indices.len()
+            && column_indices.len() == values.len()
+            && row_offsets.windows(2).all(|w| w[0] <= w[1])
+            && row_offsets.windows(2).all(|w| {
+                let row = &column_indices[w[0]..w[1]];
+                row.windows(2).all(|c| c[0] < c[1]) && row.iter().all(|&c| c < width)
+            });
+        if !valid {
+            return Err(MisAlignment);
+        }
+
+        Ok(Self {
+            dimension: Dimension::new(width as isize, height as isize),
+            row_offsets,
+            column_indices,
+            values,
+        })
+    }
+
+    pub fn width(&self) -> isize {
+        self.dimension.width()
+    }
+
+    pub fn height(&self) -> isize {
+        self.dimension.height()
+    }
+
+    /// Number of explicitly stored entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.row_offsets
+    }
+
+    pub fn column_indices(&self) -> &[usize] {
+        &self.column_indices
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// The stored entries of row `row` as `(column, &value)` pairs.
+    pub fn row(&self, row: usize) -> SparseIter<'_, T> {
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        SparseIter {
+            indices: self.column_indices[range.clone()].iter(),
+            values: self.values[range].iter(),
+        }
+    }
+
+    /// Every stored entry as `(row, column, &value)` in row-major order.
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        (0..self.height() as usize)
+            .flat_map(move |row| self.row(row).map(move |(col, value)| (row, col, value)))
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        let range = self.row_offsets[row]..self.row_offsets[row + 1];
+        self.column_indices[range.clone()]
+            .binary_search(&col)
+            .ok()
+            .map(|i| &self.values[range.start + i])
+    }
+}
+
+impl<T: Clone> CsrMatrix<T> {
+    pub fn transpose(&self) -> CsrMatrix<T> {
+        let (height, width) = (self.height() as usize, self.width() as usize);
+        let mut row_offsets = vec![0; width + 1];
+        for &col in &self.column_indices {
+            row_offsets[col + 1] += 1;
+        }
+        for i in 0..width {
+            row_offsets[i + 1] += row_offsets[i];
+        }
+
+        // Visiting rows in order keeps the column indices of each transposed row sorted.
+        let mut next = row_offsets.clone();
+        let mut column_indices = vec![0; self.nnz()];
+        let mut values = vec![None; self.nnz()];
+        for row in 0..height {
+            for (col, value) in self.row(row) {
+                column_indices[next[col]] = row;
+                values[next[col]] = Some(value.clone());
+                next[col] += 1;
+            }
+        }
+
+        CsrMatrix {
+            dimension: Dimension::new(height as isize, width as isize),
+            row_offsets,
+            column_indices,
+            values: values.into_iter().map(Option::unwrap).collect(),
+        }
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        CscMatrix {
+            transposed: self.transpose(),
+        }
+    }
+}
+
+impl<T: Clone + Add<Output = T>> CsrMatrix<T> {
+    /// Builds a matrix from `(row, column, value)` triplets in any order, summing duplicates.
+    pub fn from_triplets(
+        height: usize,
+        width: usize,
+        triplets: &[(usize, usize, T)],
+    ) -> Result<Self, MisAlignment> {
+        if triplets
+            .iter()
+            .any(|&(row, col, _)| row >= height || col >= width)
+        {
+            return Err(MisAlignment);
+        }
+
+        let mut coo = CooMatrix::with_capacity(height, width, triplets.len());
+        coo.extend(triplets.iter().cloned());
+        Ok(coo.to_csr())
+    }
+}
+
+impl<T: Clone + Zero> From<&MatrixContent<T>> for CsrMatrix<T> {
+    fn from(value: &MatrixContent<T>) -> Self {
+        let mut row_offsets = vec![0];
+        let mut column_indices = Vec::new();
+        let mut values = Vec::new();
+        for row in 0..value.height() {
+            for col in 0..value.width() {
+                let entry = &value[(row, col)];
+                if !entry.is_zero() {
+                    column_indices.push(col as usize);
+                    values.push(entry.clone());
+                }
+            }
+            row_offsets.push(values.len());
+        }
+
+        Self {
+            dimension: value.dimension.clone(),
+            row_offsets,
+            column_indices,
+            values,
+        }
+    }
+}
+
+impl<T: Clone + Zero> From<&CsrMatrix<T>> for MatrixContent<T> {
+    fn from(value: &CsrMatrix<T>) -> Self {
+        let mut dense = MatrixContent::from_fn(value.dimension.clone(), |_, _| T::zero());
+        for (row, col, entry) in value.triplets() {
+            dense[(row as isize, col as isize)] = entry.clone();
+        }
+        dense
+    }
+}
+
+impl<T: Clone + Zero + Add<Output = T> + Mul<Output = T>> CsrMatrix<T> {
+    /// Sparse matrix-vector product `A x`.
+    pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>, MisAlignment> {
+        if x.len() != self.width() as usize {
+            return Err(MisAlignment);
+        }
+
+        Ok((0..self.height() as usize)
+            .map(|row| {
+                self.row(row).fold(T::zero(), |acc, (col, value)| {
+                    acc + value.clone() * x[col].clone()
+                })
+            })
+            .collect())
+    }
+}
+
+impl<'a, T: Clone + Zero + Add<Output = T> + Mul<Output = T>> Mul<&'a MatrixContent<T>>
+    for &CsrMatrix<T>
+{
+    type Output = MatrixContent<T>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: &'a MatrixContent<T>) -> Self::Output {
+        if self.width() != rhs.height() {
+            panic!("The width of the left matrix must equal the height of the right matrix in order to multiply them");
+        }
+
+        MatrixContent::from_fn(Dimension::new(rhs.width(), self.height()), |row, col| {
+            self.row(row as usize).fold(T::zero(), |acc, (k, value)| {
+                acc + value.clone() * rhs[(k as isize, col)].clone()
+            })
+        })
+    }
+}
+
+impl<'a, T: Clone + Zero + Add<Output = T> + Mul<Output = T>> Mul<&'a CsrMatrix<T>>
+    for &CsrMatrix<T>
+{
+    type Output = CsrMatrix<T>;
+
+    /// Gustavson's row-by-row product, accumulating each output row in a dense scratch row.
+    fn mul(self, rhs: &'a CsrMatrix<T>) -> Self::Output {
+        if self.width() != rhs.height() {
+            panic!("The width of the left matrix must equal the height of the right matrix in order to multiply them");
+        }
+
+        let width = rhs.width() as usize;
+        let mut accumulator: Vec<Option<T>> = vec![None; width];
+        let mut touched = Vec::new();
+        let mut row_offsets = vec![0];
+        let mut column_indices = Vec::new();
+        let mut values = Vec::new();
+
+        for row in 0..self.height() as usize {
+            for (k, left) in self.row(row) {
+                for (col, right) in rhs.row(k) {
+                    let product = left.clone() * right.clone();
+                    accumulator[col] = Some(match accumulator[col].take() {
+                        Some(sum) => sum + product,
+                        None => {
+                            touched.push(col);
+                            product
+                        }
+                    });
+                }
+            }
+
+            touched.sort_unstable();
+            for col in touched.drain(..) {
+                let sum = accumulator[col].take().unwrap();
+                if !sum.is_zero() {
+                    column_indices.push(col);
+                    values.push(sum);
+                }
+            }
+            row_offsets.push(values.len());
+        }
+
+        CsrMatrix {
+            dimension: Dimension::new(width as isize, self.height()),
+            row_offsets,
+            column_indices,
+            values,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::SplitMix64;
+
+    /// Dense matrix of small integers with roughly a fifth of the cells non-zero.
+    fn random_dense(rng: &mut SplitMix64, height: isize, width: isize) -> MatrixContent<i64> {
+        MatrixContent::from_fn(Dimension::new(width, height), |_, _| {
+            if rng.next_f64() < 0.2 {
+                (rng.next_u64() % 19) as i64 - 9
+            } else {
+                0
+            }
+        })
+    }
+
+    #[test]
+    fn triplets_sum_duplicates() {
+        let triplets = [(1, 2, 5), (0, 0, 1), (1, 2, -2), (2, 1, 4), (0, 0, 3)];
+        let csr = CsrMatrix::from_triplets(3, 3, &triplets).unwrap();
+        assert_eq!(csr.nnz(), 3);
+        assert_eq!(csr.row_offsets(), &[0, 1, 2, 3]);
+        assert_eq!(csr.column_indices(), &[0, 2, 1]);
+        assert_eq!(csr.values(), &[4, 3, 4]);
+        assert_eq!(csr.get(1, 2), Some(&3));
+        assert_eq!(csr.get(1, 1), None);
+        assert_eq!(csr.row(2).collect::<Vec<_>>(), vec![(1, &4)]);
+        assert!(CsrMatrix::from_triplets(3, 3, &[(3, 0, 1)]).is_err());
+    }
+
+    #[test]
+    fn from_parts_validates_the_arrays() {
+        assert!(CsrMatrix::from_parts(2, 3, vec![0, 1, 2], vec![2, 0], vec![1, 2]).is_ok());
+        assert!(CsrMatrix::from_parts(2, 3, vec![0, 1], vec![2], vec![1]).is_err());
+        assert!(CsrMatrix::from_parts(2, 3, vec![0, 2, 2], vec![2, 1], vec![1, 2]).is_err());
+        assert!(CsrMatrix::from_parts(2, 3, vec![0, 1, 2], vec![3, 0], vec![1, 2]).is_err());
+        assert!(CsrMatrix::from_parts(2, 3, vec![0, 1, 2], vec![2, 0], vec![1]).is_err());
+    }
+
+    #[test]
+    fn dense_round_trip_and_transpose() {
+        let mut rng = SplitMix64::new(1);
+        let dense = random_dense(&mut rng, 7, 11);
+        let csr = CsrMatrix::from(&dense);
+        assert_eq!(csr.nnz(), dense.entries().filter(|&&v| v != 0).count());
+        assert_eq!(MatrixContent::from(&csr), dense);
+        assert_eq!(
+            MatrixContent::from(&csr.transpose()),
+            dense.clone().into_transpose()
+        );
+        assert_eq!(MatrixContent::from(&csr.to_csc()), dense);
+    }
+
+    #[test]
+    fn products_match_dense_arithmetic() {
+        let mut rng = SplitMix64::new(2);
+        let a = random_dense(&mut rng, 9, 13);
+        let b = random_dense(&mut rng, 13, 6);
+        let csr = CsrMatrix::from(&a);
+
+        assert_eq!(&csr * &b, &a * &b);
+        assert_eq!(MatrixContent::from(&(&csr * &CsrMatrix::from(&b))), &a * &b);
+
+        let x: Vec<i64> = (0..13).map(|i| i * 3 - 7).collect();
+        let column = MatrixContent::new(Dimension::new(1, 13), x.clone());
+        let expected: Vec<i64> = (&a * &column).entries().copied().collect();
+        assert_eq!(csr.mul_vec(&x).unwrap(), expected);
+        assert!(csr.mul_vec(&x[1..]).is_err());
+    }
+}
@@ -0,0 +1,23 @@
+pub mod coo;
+pub mod csc;
+pub mod csr;
+
+pub use coo::CooMatrix;
+pub use csc::CscMatrix;
+pub use csr::CsrMatrix;
+
+/// Iterator over the stored entries of one row of a [`CsrMatrix`] or one column of a
+/// [`CscMatrix`], yielding `(index, &value)` pairs in increasing index order.
+#[derive(Clone)]
+pub struct SparseIter<'a, T> {
+    pub(crate) indices: std::slice::Iter<'a, usize>,
+    pub(crate) values: std::slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for SparseIter<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((*self.indices.next()?, self.values.next()?))
+    }
+}
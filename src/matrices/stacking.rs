@@ -0,0 +1,213 @@
+use crate::dimension::Dimension;
+
+use super::{GenericMatrix, Matrix, MatrixContent, MisAlignment};
+
+/// Direction an operation runs in, following the row and column index of a cell.
+///
+/// `Axis::Rows` runs along the row index, i.e. down each column: concatenating along it stacks
+/// matrices vertically. `Axis::Columns` runs along the column index, across each row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    Rows,
+    Columns,
+}
+
+impl<T: Clone> MatrixContent<T> {
+    /// Places matrices of equal height side by side.
+    pub fn hstack(parts: &[&MatrixContent<T>]) -> Result<Self, MisAlignment> {
+        let height = parts.first().ok_or(MisAlignment)?.height();
+        if parts.iter().any(|part| part.height() != height) {
+            return Err(MisAlignment);
+        }
+
+        let width = parts.iter().map(|part| part.width()).sum();
+        let mut buffer = Vec::with_capacity((width * height) as usize);
+        for row in 0..height {
+            for part in parts {
+                buffer.extend(part.row_iter(row).cloned());
+            }
+        }
+        Ok(MatrixContent::new(Dimension::new(width, height), buffer))
+    }
+
+    /// Places matrices of equal width on top of each other.
+    pub fn vstack(parts: &[&MatrixContent<T>]) -> Result<Self, MisAlignment> {
+        let width = parts.first().ok_or(MisAlignment)?.width();
+        if parts.iter().any(|part| part.width() != width) {
+            return Err(MisAlignment);
+        }
+
+        let height = parts.iter().map(|part| part.height()).sum();
+        let mut buffer = Vec::with_capacity((width * height) as usize);
+        for part in parts {
+            for row in 0..part.height() {
+                buffer.extend(part.row_iter(row).cloned());
+            }
+        }
+        Ok(MatrixContent::new(Dimension::new(width, height), buffer))
+    }
+
+    /// [`MatrixContent::vstack`] for `Axis::Rows`, [`MatrixContent::hstack`] for
+    /// `Axis::Columns`.
+    pub fn concat(parts: &[&MatrixContent<T>], axis: Axis) -> Result<Self, MisAlignment> {
+        match axis {
+            Axis::Rows => Self::vstack(parts),
+            Axis::Columns => Self::hstack(parts),
+        }
+    }
+
+    /// Assembles a matrix from a grid of blocks. The blocks in each block row must share their
+    /// height, and every block row must add up to the same width. See also [`block!`].
+    ///
+    /// [`block!`]: crate::block
+    pub fn block(blocks: &[Vec<&MatrixContent<T>>]) -> Result<Self, MisAlignment> {
+        let rows = blocks
+            .iter()
+            .map(|row| Self::hstack(row))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::vstack(&rows.iter().collect::<Vec<_>>())
+    }
+
+    /// Cuts the matrix into horizontal slabs before each of the given rows, which must be
+    /// increasing and within `0..=height`. `n` split points give `n + 1` pieces.
+    pub fn split_rows(&self, at: &[isize]) -> Result<Vec<Self>, MisAlignment> {
+        let bounds = split_bounds(at, self.height())?;
+        Ok(bounds
+            .windows(2)
+            .map(|w| {
+                MatrixContent::from_fn(Dimension::new(self.width(), w[1] - w[0]), |row, col| {
+                    self[(w[0] + row, col)].clone()
+                })
+            })
+            .collect())
+    }
+
+    /// Cuts the matrix into vertical slabs before each of the given columns, which must be
+    /// increasing and within `0..=width`. `n` split points give `n + 1` pieces.
+    pub fn split_cols(&self, at: &[isize]) -> Result<Vec<Self>, MisAlignment> {
+        let bounds = split_bounds(at, self.width())?;
+        Ok(bounds
+            .windows(2)
+            .map(|w| {
+                MatrixContent::from_fn(Dimension::new(w[1] - w[0], self.height()), |row, col| {
+                    self[(row, w[0] + col)].clone()
+                })
+            })
+            .collect())
+    }
+}
+
+/// `0`, the split points and `len`, after checking that the points are in order and in range.
+fn split_bounds(at: &[isize], len: isize) -> Result<Vec<isize>, MisAlignment> {
+    let bounds: Vec<isize> = std::iter::once(0)
+        .chain(at.iter().copied())
+        .chain(std::iter::once(len))
+        .collect();
+    if bounds.windows(2).any(|w| w[0] > w[1]) {
+        return Err(MisAlignment);
+    }
+    Ok(bounds)
+}
+
+impl<T> AsRef<MatrixContent<T>> for MatrixContent<T> {
+    fn as_ref(&self) -> &MatrixContent<T> {
+        self
+    }
+}
+
+impl<T> AsRef<MatrixContent<T>> for Matrix<T> {
+    fn as_ref(&self) -> &MatrixContent<T> {
+        &self.content
+    }
+}
+
+impl AsRef<MatrixContent<f64>> for GenericMatrix {
+    fn as_ref(&self) -> &MatrixContent<f64> {
+        &self.content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block;
+    use crate::matrices::testing::rows;
+    use crate::matrices::Layout;
+
+    fn grid(height: isize, width: isize, offset: isize) -> MatrixContent<isize> {
+        MatrixContent::from_fn(Dimension::new(width, height), |row, col| {
+            offset + row * 10 + col
+        })
+    }
+
+    #[test]
+    fn stacking_mixed_layouts() {
+        let a = grid(2, 2, 0);
+        let b = grid(2, 1, 100).to_layout(Layout::ColumnMajor);
+        let wide = MatrixContent::hstack(&[&a, &b]).unwrap();
+        assert_eq!(rows(&wide), vec![vec![0, 1, 100], vec![10, 11, 110]]);
+        assert_eq!(
+            MatrixContent::concat(&[&a, &b], Axis::Columns).unwrap(),
+            wide
+        );
+
+        let c = grid(1, 2, 200).to_layout(Layout::ColumnMajor);
+        let tall = MatrixContent::vstack(&[&a, &c]).unwrap();
+        assert_eq!(rows(&tall), vec![vec![0, 1], vec![10, 11], vec![200, 201]]);
+        assert_eq!(MatrixContent::concat(&[&a, &c], Axis::Rows).unwrap(), tall);
+    }
+
+    #[test]
+    fn stacking_rejects_mismatched_shapes() {
+        let a = grid(2, 2, 0);
+        let b = grid(3, 2, 0);
+        assert!(MatrixContent::hstack(&[&a, &b]).is_err());
+        assert!(MatrixContent::vstack(&[&a, &grid(2, 3, 0)]).is_err());
+        assert!(MatrixContent::<isize>::hstack(&[]).is_err());
+        assert!(MatrixContent::<isize>::vstack(&[]).is_err());
+    }
+
+    #[test]
+    fn block_assembly() {
+        let a = GenericMatrix::from_content(MatrixContent::identity(2));
+        let b = MatrixContent::from_fn(Dimension::new(1, 2), |row, _| row as f64 + 5.0);
+        let c = Matrix::from_content(MatrixContent::from_fn(Dimension::new(3, 1), |_, col| {
+            -(col as f64)
+        }));
+        let assembled = block![[a, b], [c]].unwrap();
+        let expected = MatrixContent::new(
+            Dimension::new(3, 3),
+            vec![1.0, 0.0, 5.0, 0.0, 1.0, 6.0, 0.0, -1.0, -2.0],
+        );
+        assert_eq!(assembled, expected);
+
+        assert!(block![[a, b], [b]].is_err());
+        assert!(block![[a, c]].is_err());
+    }
+
+    #[test]
+    fn splitting_inverts_stacking() {
+        let m = grid(4, 5, 0);
+        let slabs = m.split_rows(&[1, 1, 3]).unwrap();
+        assert_eq!(
+            slabs.iter().map(|s| s.height()).collect::<Vec<_>>(),
+            vec![1, 0, 2, 1]
+        );
+        assert_eq!(
+            MatrixContent::vstack(&slabs.iter().collect::<Vec<_>>()).unwrap(),
+            m
+        );
+
+        let slabs = m.split_cols(&[2]).unwrap();
+        assert_eq!(rows(&slabs[1])[0], vec![2, 3, 4]);
+        assert_eq!(
+            MatrixContent::hstack(&slabs.iter().collect::<Vec<_>>()).unwrap(),
+            m
+        );
+
+        assert_eq!(m.split_rows(&[]).unwrap(), vec![m.clone()]);
+        assert!(m.split_rows(&[3, 2]).is_err());
+        assert!(m.split_rows(&[5]).is_err());
+        assert!(m.split_cols(&[-1]).is_err());
+    }
+}
@@ -0,0 +1,280 @@
+use std::ops::{Index, IndexMut, Range};
+
+use crate::matrices::{MatrixContent, MisAlignment};
+use crate::scalar::Zero;
+
+use super::Structured;
+
+/// Square matrix whose non-zero entries lie within `lower` diagonals below and `upper` diagonals
+/// above the main diagonal.
+///
+/// Row `i` stores the `lower + upper + 1` cells of columns `i - lower..=i + upper`; cells that
+/// fall outside the matrix are padding and always zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandedMatrix<T> {
+    size: usize,
+    lower: usize,
+    upper: usize,
+    band: Vec<T>,
+    zero: T,
+}
+
+impl<T: Zero + Clone> BandedMatrix<T> {
+    pub fn zeros(size: usize, lower: usize, upper: usize) -> Self {
+        Self {
+            size,
+            lower,
+            upper,
+            band: vec![T::zero(); size * (lower + upper + 1)],
+            zero: T::zero(),
+        }
+    }
+
+    /// Copies the band of a square matrix, ignoring everything outside it.
+    pub fn from_dense(
+        value: &MatrixContent<T>,
+        lower: usize,
+        upper: usize,
+    ) -> Result<Self, MisAlignment> {
+        if value.width() != value.height() {
+            return Err(MisAlignment);
+        }
+
+        let mut banded = Self::zeros(value.height() as usize, lower, upper);
+        for row in 0..banded.size {
+            for col in banded.row_support(row) {
+                banded[(row, col)] = value[(row as isize, col as isize)].clone();
+            }
+        }
+        Ok(banded)
+    }
+
+    /// Tridiagonal matrix from its sub-diagonal, diagonal and super-diagonal, the outer two
+    /// being one shorter than the diagonal unless all three are empty.
+    pub fn tridiagonal(sub: &[T], diagonal: &[T], sup: &[T]) -> Result<Self, MisAlignment> {
+        let size = diagonal.len();
+        let off_diagonal = size.saturating_sub(1);
+        if sub.len() != off_diagonal || sup.len() != off_diagonal {
+            return Err(MisAlignment);
+        }
+
+        let mut banded = Self::zeros(size, 1, 1);
+        for i in 0..size {
+            banded[(i, i)] = diagonal[i].clone();
+            if i + 1 < size {
+                banded[(i + 1, i)] = sub[i].clone();
+                banded[(i, i + 1)] = sup[i].clone();
+            }
+        }
+        Ok(banded)
+    }
+}
+
+impl<T> BandedMatrix<T> {
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn lower(&self) -> usize {
+        self.lower
+    }
+
+    pub fn upper(&self) -> usize {
+        self.upper
+    }
+
+    fn in_band(&self, row: usize, col: usize) -> bool {
+        col + self.lower >= row && col <= row + self.upper
+    }
+
+    fn offset(&self, row: usize, col: usize) -> usize {
+        row * (self.lower + self.upper + 1) + col + self.lower - row
+    }
+
+    /// Mutable access to an entry inside the band, or `None` outside it.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.size && col < self.size && self.in_band(row, col) {
+            let offset = self.offset(row, col);
+            Some(&mut self.band[offset])
+        } else {
+            None
+        }
+    }
+}
+
+impl BandedMatrix<f64> {
+    /// Solves `A x = b` with the Thomas algorithm when tridiagonal, otherwise with Gaussian
+    /// elimination restricted to the band. Both run in O(n) for a fixed bandwidth.
+    ///
+    /// No pivoting is performed, which is stable for diagonally dominant or symmetric positive
+    /// definite matrices; `None` is returned if a zero pivot is met.
+    pub fn solve(&self, b: &[f64]) -> Option<Vec<f64>> {
+        if b.len() != self.size {
+            return None;
+        }
+        if self.lower == 1 && self.upper == 1 {
+            return self.solve_tridiagonal(b);
+        }
+
+        let mut m = self.clone();
+        let mut x = b.to_vec();
+        for k in 0..self.size {
+            let pivot = m[(k, k)];
+            if pivot == 0.0 {
+                return None;
+            }
+            for row in k + 1..(k + self.lower + 1).min(self.size) {
+                let factor = m[(row, k)] / pivot;
+                if factor == 0.0 {
+                    continue;
+                }
+                for col in k..(k + self.upper + 1).min(self.size) {
+                    m[(row, col)] -= factor * m[(k, col)];
+                }
+                x[row] -= factor * x[k];
+            }
+        }
+
+        for row in (0..self.size).rev() {
+            let end = (row + self.upper + 1).min(self.size);
+            let sum: f64 = (row + 1..end).map(|col| m[(row, col)] * x[col]).sum();
+            x[row] = (x[row] - sum) / m[(row, row)];
+        }
+        Some(x)
+    }
+
+    /// Thomas algorithm for tridiagonal systems.
+    fn solve_tridiagonal(&self, b: &[f64]) -> Option<Vec<f64>> {
+        let n = self.size;
+        if n == 0 {
+            return Some(Vec::new());
+        }
+
+        let mut c = vec![0.0; n];
+        let mut d = vec![0.0; n];
+        let mut denominator = self[(0, 0)];
+        for i in 0..n {
+            if i > 0 {
+                denominator = self[(i, i)] - self[(i, i - 1)] * c[i - 1];
+            }
+            if denominator == 0.0 {
+                return None;
+            }
+            if i + 1 < n {
+                c[i] = self[(i, i + 1)] / denominator;
+            }
+            let previous = if i > 0 {
+                self[(i, i - 1)] * d[i - 1]
+            } else {
+                0.0
+            };
+            d[i] = (b[i] - previous) / denominator;
+        }
+
+        for i in (0..n - 1).rev() {
+            d[i] -= c[i] * d[i + 1];
+        }
+        Some(d)
+    }
+}
+
+impl<T> Index<(usize, usize)> for BandedMatrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.size && col < self.size, "index out of bounds");
+        if self.in_band(row, col) {
+            &self.band[self.offset(row, col)]
+        } else {
+            &self.zero
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for BandedMatrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(row, col)
+            .expect("Only entries inside the band of a banded matrix can be assigned")
+    }
+}
+
+impl<T> Structured<T> for BandedMatrix<T> {
+    fn row_support(&self, row: usize) -> Range<usize> {
+        row.saturating_sub(self.lower)..(row + self.upper + 1).min(self.size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimension::Dimension;
+
+    fn residual(a: &BandedMatrix<f64>, x: &[f64], b: &[f64]) -> f64 {
+        a.mul_vec(x)
+            .unwrap()
+            .iter()
+            .zip(b)
+            .map(|(ax, b)| (ax - b).abs())
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn tridiagonal_thomas_solve() {
+        let n = 50;
+        let a = BandedMatrix::tridiagonal(&vec![-1.0; n - 1], &vec![2.5; n], &vec![-1.0; n - 1])
+            .unwrap();
+        assert_eq!(
+            (a[(3, 2)], a[(3, 3)], a[(3, 4)], a[(3, 5)]),
+            (-1.0, 2.5, -1.0, 0.0)
+        );
+
+        let b: Vec<f64> = (0..n).map(|i| (i as f64).sin()).collect();
+        let x = a.solve(&b).unwrap();
+        assert!(residual(&a, &x, &b) < 1e-12);
+        assert!(a.solve(&b[1..]).is_none());
+    }
+
+    #[test]
+    fn tridiagonal_shapes() {
+        let empty = BandedMatrix::<f64>::tridiagonal(&[], &[], &[]).unwrap();
+        assert_eq!(empty.size(), 0);
+        assert_eq!(empty.solve(&[]).unwrap(), Vec::<f64>::new());
+        assert_eq!(
+            BandedMatrix::tridiagonal(&[], &[3.0], &[]).unwrap()[(0, 0)],
+            3.0
+        );
+        assert!(BandedMatrix::tridiagonal(&[1.0], &[1.0], &[]).is_err());
+        assert!(BandedMatrix::tridiagonal(&[1.0], &[], &[1.0]).is_err());
+    }
+
+    #[test]
+    fn general_band_solve_and_product() {
+        let n = 12;
+        let dense = MatrixContent::from_fn(Dimension::new(n, n), |row, col| {
+            let offset = col - row;
+            if row == col {
+                10.0 + row as f64
+            } else if (-2..=1).contains(&offset) {
+                1.0 / (row + 2 * col + 1) as f64
+            } else {
+                0.0
+            }
+        });
+        let a = BandedMatrix::from_dense(&dense, 2, 1).unwrap();
+        assert_eq!(MatrixContent::from(&a), dense);
+
+        let b: Vec<f64> = (0..n).map(|i| i as f64 - 4.0).collect();
+        let x = a.solve(&b).unwrap();
+        assert!(residual(&a, &x, &b) < 1e-12);
+
+        let rhs = MatrixContent::from_fn(Dimension::new(3, n), |row, col| (row * col) as f64);
+        assert_eq!(&a * &rhs, &dense * &rhs);
+    }
+
+    #[test]
+    #[should_panic(expected = "inside the band")]
+    fn assigning_outside_the_band_panics() {
+        let mut a = BandedMatrix::<f64>::zeros(4, 1, 0);
+        a[(0, 1)] = 1.0;
+    }
+}
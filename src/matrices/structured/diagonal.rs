@@ -0,0 +1,107 @@
+use std::ops::{Index, Range};
+
+use crate::scalar::Zero;
+
+use super::Structured;
+
+/// Square matrix that stores only its main diagonal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagonalMatrix<T> {
+    diagonal: Vec<T>,
+    zero: T,
+}
+
+impl<T: Zero> DiagonalMatrix<T> {
+    pub fn new(diagonal: Vec<T>) -> Self {
+        Self {
+            diagonal,
+            zero: T::zero(),
+        }
+    }
+}
+
+impl<T> DiagonalMatrix<T> {
+    pub fn size(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    pub fn diagonal(&self) -> &[T] {
+        &self.diagonal
+    }
+
+    pub fn diagonal_mut(&mut self) -> &mut [T] {
+        &mut self.diagonal
+    }
+}
+
+impl DiagonalMatrix<f64> {
+    /// Solves `D x = b` in O(n), or returns `None` if a diagonal entry is zero.
+    pub fn solve(&self, b: &[f64]) -> Option<Vec<f64>> {
+        if b.len() != self.size() || self.diagonal.contains(&0.0) {
+            return None;
+        }
+        Some(b.iter().zip(&self.diagonal).map(|(b, d)| b / d).collect())
+    }
+
+    pub fn inverse(&self) -> Option<DiagonalMatrix<f64>> {
+        if self.diagonal.contains(&0.0) {
+            return None;
+        }
+        Some(DiagonalMatrix::new(
+            self.diagonal.iter().map(|d| 1.0 / d).collect(),
+        ))
+    }
+}
+
+impl<T> Index<(usize, usize)> for DiagonalMatrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(
+            row < self.size() && col < self.size(),
+            "index out of bounds"
+        );
+        if row == col {
+            &self.diagonal[row]
+        } else {
+            &self.zero
+        }
+    }
+}
+
+impl<T> Structured<T> for DiagonalMatrix<T> {
+    fn row_support(&self, row: usize) -> Range<usize> {
+        row..row + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimension::Dimension;
+    use crate::matrices::MatrixContent;
+
+    #[test]
+    fn implicit_zeros_and_products() {
+        let d = DiagonalMatrix::new(vec![2.0, -1.0, 4.0]);
+        assert_eq!(d[(1, 1)], -1.0);
+        assert_eq!(d[(0, 2)], 0.0);
+        assert_eq!(d.mul_vec(&[1.0, 2.0, 3.0]).unwrap(), vec![2.0, -2.0, 12.0]);
+        assert!(d.mul_vec(&[1.0]).is_err());
+
+        let dense = MatrixContent::from(&d);
+        let rhs = MatrixContent::from_fn(Dimension::new(2, 3), |row, col| (row + col) as f64);
+        assert_eq!(&d * &rhs, &dense * &rhs);
+    }
+
+    #[test]
+    fn solve_and_inverse() {
+        let d = DiagonalMatrix::new(vec![2.0, -1.0, 4.0]);
+        assert_eq!(d.solve(&[1.0, 1.0, 1.0]).unwrap(), vec![0.5, -1.0, 0.25]);
+        assert_eq!(d.inverse().unwrap().diagonal(), &[0.5, -1.0, 0.25]);
+
+        let singular = DiagonalMatrix::new(vec![1.0, 0.0]);
+        assert!(singular.solve(&[1.0, 1.0]).is_none());
+        assert!(singular.inverse().is_none());
+    }
+}
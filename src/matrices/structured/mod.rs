@@ -0,0 +1,86 @@
+pub mod banded;
+pub mod diagonal;
+pub mod symmetric;
+pub mod toeplitz;
+pub mod triangular;
+
+use std::ops::{Add, Mul, Range};
+
+use crate::dimension::Dimension;
+use crate::scalar::Zero;
+
+use super::{MatrixContent, MisAlignment};
+
+pub use banded::BandedMatrix;
+pub use diagonal::DiagonalMatrix;
+pub use symmetric::SymmetricMatrix;
+pub use toeplitz::{levinson_durbin, CirculantMatrix, ToeplitzMatrix};
+pub use triangular::{LowerTriangular, UpperTriangular};
+
+/// Square matrices that only store the entries inside a known sparsity pattern.
+pub(crate) trait Structured<T>: std::ops::Index<(usize, usize), Output = T> {
+    /// Columns of `row` that may hold a non-zero entry.
+    fn row_support(&self, row: usize) -> Range<usize>;
+}
+
+macro_rules! impl_structured_ops {
+    ($($name:ident),*) => {
+        $(
+            impl<T: Clone + Zero + Add<Output = T> + Mul<Output = T>> $name<T> {
+                /// Matrix-vector product touching only the stored entries.
+                pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>, MisAlignment> {
+                    if x.len() != self.size() {
+                        return Err(MisAlignment);
+                    }
+
+                    Ok((0..self.size())
+                        .map(|row| {
+                            self.row_support(row).fold(T::zero(), |acc, col| {
+                                acc + self[(row, col)].clone() * x[col].clone()
+                            })
+                        })
+                        .collect())
+                }
+            }
+
+            impl<'a, T: Clone + Zero + Add<Output = T> + Mul<Output = T>> Mul<&'a MatrixContent<T>>
+                for &$name<T>
+            {
+                type Output = MatrixContent<T>;
+
+                #[allow(clippy::suspicious_arithmetic_impl)]
+                fn mul(self, rhs: &'a MatrixContent<T>) -> Self::Output {
+                    if self.size() as isize != rhs.height() {
+                        panic!("The width of the left matrix must equal the height of the right matrix in order to multiply them");
+                    }
+
+                    MatrixContent::from_fn(
+                        Dimension::new(rhs.width(), self.size() as isize),
+                        |row, col| {
+                            self.row_support(row as usize).fold(T::zero(), |acc, k| {
+                                acc + self[(row as usize, k)].clone() * rhs[(k as isize, col)].clone()
+                            })
+                        },
+                    )
+                }
+            }
+
+            impl<T: Clone> From<&$name<T>> for MatrixContent<T> {
+                fn from(value: &$name<T>) -> Self {
+                    let size = value.size() as isize;
+                    MatrixContent::from_fn(Dimension::new(size, size), |row, col| {
+                        value[(row as usize, col as usize)].clone()
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_structured_ops!(
+    DiagonalMatrix,
+    UpperTriangular,
+    LowerTriangular,
+    BandedMatrix,
+    SymmetricMatrix
+);
@@ -0,0 +1,151 @@
+use std::ops::{Index, IndexMut, Range};
+
+use crate::matrices::{MatrixContent, MisAlignment};
+
+use super::{LowerTriangular, Structured};
+
+/// Symmetric matrix storing only its lower triangle, packed row by row.
+///
+/// Indexing either triangle reads the same stored cell, so assigning `(i, j)` also assigns
+/// `(j, i)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricMatrix<T> {
+    size: usize,
+    packed: Vec<T>,
+}
+
+impl<T> SymmetricMatrix<T> {
+    /// Wraps the rows of the lower triangle, concatenated: row `i` holds columns `0..=i`.
+    pub fn from_packed(size: usize, packed: Vec<T>) -> Result<Self, MisAlignment> {
+        if packed.len() != size * (size + 1) / 2 {
+            return Err(MisAlignment);
+        }
+        Ok(Self { size, packed })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    fn offset(&self, row: usize, col: usize) -> usize {
+        let (row, col) = if col > row { (col, row) } else { (row, col) };
+        row * (row + 1) / 2 + col
+    }
+}
+
+impl<T: Clone> SymmetricMatrix<T> {
+    /// Copies the lower triangle of a square matrix, ignoring everything above the diagonal.
+    pub fn from_dense(value: &MatrixContent<T>) -> Result<Self, MisAlignment> {
+        if value.width() != value.height() {
+            return Err(MisAlignment);
+        }
+        let size = value.height();
+        let packed = (0..size)
+            .flat_map(|row| (0..=row).map(move |col| (row, col)))
+            .map(|index| value[index].clone())
+            .collect();
+        Self::from_packed(size as usize, packed)
+    }
+}
+
+impl SymmetricMatrix<f64> {
+    /// Cholesky factor `L` with `A = L L^T`, or `None` if the matrix is not positive definite.
+    pub fn cholesky(&self) -> Option<LowerTriangular<f64>> {
+        let n = self.size;
+        let mut l = LowerTriangular::from_packed(n, vec![0.0; self.packed.len()]).ok()?;
+        for row in 0..n {
+            for col in 0..=row {
+                let sum: f64 = (0..col).map(|k| l[(row, k)] * l[(col, k)]).sum();
+                let value = self[(row, col)] - sum;
+                if row == col {
+                    if value <= 0.0 {
+                        return None;
+                    }
+                    l[(row, col)] = value.sqrt();
+                } else {
+                    l[(row, col)] = value / l[(col, col)];
+                }
+            }
+        }
+        Some(l)
+    }
+
+    /// Solves `A x = b` through the Cholesky factorization, or returns `None` if the matrix is
+    /// not positive definite.
+    pub fn solve(&self, b: &[f64]) -> Option<Vec<f64>> {
+        let l = self.cholesky()?;
+        let y = l.solve(b)?;
+        l.transpose().solve(&y)
+    }
+}
+
+impl<T> Index<(usize, usize)> for SymmetricMatrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.size && col < self.size, "index out of bounds");
+        &self.packed[self.offset(row, col)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for SymmetricMatrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.size && col < self.size, "index out of bounds");
+        let offset = self.offset(row, col);
+        &mut self.packed[offset]
+    }
+}
+
+impl<T> Structured<T> for SymmetricMatrix<T> {
+    fn row_support(&self, _row: usize) -> Range<usize> {
+        0..self.size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_matrix_approx_eq;
+    use crate::dimension::Dimension;
+
+    /// `B^T B + n I`, which is symmetric positive definite.
+    fn spd(n: isize) -> MatrixContent<f64> {
+        let b = MatrixContent::from_fn(Dimension::new(n, n), |row, col| {
+            ((row * 7 + col * 3) % 5) as f64 - 2.0
+        });
+        let product = &b.clone().into_transpose() * &b;
+        &product + &(MatrixContent::identity(n) * n as f64)
+    }
+
+    #[test]
+    fn both_triangles_share_storage() {
+        let mut s = SymmetricMatrix::from_packed(3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!((s[(0, 2)], s[(2, 0)], s[(2, 1)]), (4, 4, 5));
+        s[(0, 1)] = 9;
+        assert_eq!(s[(1, 0)], 9);
+        assert!(SymmetricMatrix::from_packed(3, vec![1]).is_err());
+    }
+
+    #[test]
+    fn cholesky_factor_reproduces_the_matrix() {
+        let dense = spd(6);
+        let s = SymmetricMatrix::from_dense(&dense).unwrap();
+        assert_eq!(MatrixContent::from(&s), dense);
+
+        let l = MatrixContent::from(&s.cholesky().unwrap());
+        let reconstructed = &l * &l.clone().into_transpose();
+        assert_matrix_approx_eq!(reconstructed, dense);
+
+        let b: Vec<f64> = (0..6).map(|i| i as f64 + 0.5).collect();
+        let x = s.solve(&b).unwrap();
+        let ax = s.mul_vec(&x).unwrap();
+        assert!(ax.iter().zip(&b).all(|(ax, b)| (ax - b).abs() < 1e-12));
+    }
+
+    #[test]
+    fn indefinite_matrices_have_no_cholesky_factor() {
+        let s = SymmetricMatrix::from_packed(2, vec![1.0, 2.0, 1.0]).unwrap();
+        assert!(s.cholesky().is_none());
+        assert!(s.solve(&[1.0, 1.0]).is_none());
+    }
+}
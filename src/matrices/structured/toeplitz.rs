@@ -0,0 +1,347 @@
+use crate::complex::Complex;
+use crate::dimension::Dimension;
+use crate::fft::{fft, ifft};
+use crate::matrices::{MatrixContent, MisAlignment};
+
+/// Square Toeplitz matrix stored by its first column and first row.
+///
+/// Matrix-vector products run in O(n log n) by embedding the matrix in a circulant one, and
+/// systems are solved in O(n^2) with the Levinson recursion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToeplitzMatrix {
+    first_col: Vec<f64>,
+    first_row: Vec<f64>,
+}
+
+/// Square circulant matrix stored by its first column.
+///
+/// Circulant matrices are diagonalized by the DFT, so both products and solves run in
+/// O(n log n).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CirculantMatrix {
+    first_col: Vec<f64>,
+}
+
+impl ToeplitzMatrix {
+    /// `first_row[0]` must equal `first_col[0]`, and both must have the same length.
+    pub fn new(first_col: Vec<f64>, first_row: Vec<f64>) -> Result<Self, MisAlignment> {
+        if first_col.len() != first_row.len() || first_col.first() != first_row.first() {
+            return Err(MisAlignment);
+        }
+        Ok(Self {
+            first_col,
+            first_row,
+        })
+    }
+
+    /// Symmetric Toeplitz matrix, such as the autocorrelation matrix of a stationary series.
+    pub fn symmetric(first_col: Vec<f64>) -> Self {
+        Self {
+            first_row: first_col.clone(),
+            first_col,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.first_col.len()
+    }
+
+    /// Entry `(row, col)`, which only depends on `row - col`.
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        if row >= col {
+            self.first_col[row - col]
+        } else {
+            self.first_row[col - row]
+        }
+    }
+
+    pub fn mul_vec(&self, x: &[f64]) -> Result<Vec<f64>, MisAlignment> {
+        let n = self.size();
+        if x.len() != n {
+            return Err(MisAlignment);
+        }
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+
+        // The first column of the embedding circulant is [c_0 .. c_{n-1}, 0 .., r_{n-1} .. r_1].
+        let m = (2 * n - 1).next_power_of_two();
+        let mut column = vec![0.0; m];
+        column[..n].copy_from_slice(&self.first_col);
+        for k in 1..n {
+            column[m - k] = self.first_row[k];
+        }
+        let mut padded = vec![0.0; m];
+        padded[..n].copy_from_slice(x);
+
+        let mut y = circular_convolution(&column, &padded);
+        y.truncate(n);
+        Ok(y)
+    }
+
+    /// Solves `T x = b` with the Levinson recursion in O(n^2).
+    ///
+    /// Returns `None` if any leading principal minor is singular, which the recursion cannot
+    /// step over even when the full matrix is invertible.
+    pub fn solve(&self, b: &[f64]) -> Option<Vec<f64>> {
+        let n = self.size();
+        if b.len() != n {
+            return None;
+        }
+        if n == 0 {
+            return Some(Vec::new());
+        }
+        if self.first_col[0] == 0.0 {
+            return None;
+        }
+
+        let t0 = self.first_col[0];
+        let mut forward = vec![1.0 / t0];
+        let mut backward = vec![1.0 / t0];
+        let mut x = vec![b[0] / t0];
+
+        for k in 1..n {
+            let error_forward: f64 = (0..k).map(|i| self.first_col[k - i] * forward[i]).sum();
+            let error_backward: f64 = (0..k).map(|i| self.first_row[i + 1] * backward[i]).sum();
+            let denominator = 1.0 - error_forward * error_backward;
+            if denominator == 0.0 {
+                return None;
+            }
+
+            let mut next_forward = vec![0.0; k + 1];
+            let mut next_backward = vec![0.0; k + 1];
+            for i in 0..=k {
+                let f = if i < k { forward[i] } else { 0.0 };
+                let b = if i > 0 { backward[i - 1] } else { 0.0 };
+                next_forward[i] = (f - error_forward * b) / denominator;
+                next_backward[i] = (b - error_backward * f) / denominator;
+            }
+            forward = next_forward;
+            backward = next_backward;
+
+            let error_x: f64 = (0..k).map(|i| self.first_col[k - i] * x[i]).sum();
+            x.push(0.0);
+            for i in 0..=k {
+                x[i] += (b[k] - error_x) * backward[i];
+            }
+        }
+        Some(x)
+    }
+}
+
+/// Levinson-Durbin recursion for the Yule-Walker equations of an autoregressive model.
+///
+/// Given autocorrelations `r[0..=order]`, returns the coefficients `a[1..=order]` of the
+/// model `x_t = sum a_k x_{t-k} + e_t` together with the variance of `e_t`, or `None` if
+/// `r[0]` is zero or the recursion becomes unstable.
+pub fn levinson_durbin(r: &[f64], order: usize) -> Option<(Vec<f64>, f64)> {
+    if r.len() <= order || r[0] == 0.0 {
+        return None;
+    }
+
+    let mut coefficients: Vec<f64> = Vec::with_capacity(order);
+    let mut error = r[0];
+    for k in 1..=order {
+        let correlation: f64 = r[k]
+            - (0..k - 1)
+                .map(|j| coefficients[j] * r[k - 1 - j])
+                .sum::<f64>();
+        let reflection = correlation / error;
+
+        let previous = coefficients.clone();
+        for j in 0..k - 1 {
+            coefficients[j] = previous[j] - reflection * previous[k - 2 - j];
+        }
+        coefficients.push(reflection);
+
+        error *= 1.0 - reflection * reflection;
+        if error <= 0.0 {
+            return None;
+        }
+    }
+    Some((coefficients, error))
+}
+
+impl CirculantMatrix {
+    /// Rejects an empty first column, which has no cyclic shifts.
+    pub fn new(first_col: Vec<f64>) -> Result<Self, MisAlignment> {
+        if first_col.is_empty() {
+            return Err(MisAlignment);
+        }
+        Ok(Self { first_col })
+    }
+
+    pub fn size(&self) -> usize {
+        self.first_col.len()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        let n = self.size();
+        self.first_col[(row + n - col) % n]
+    }
+
+    /// The eigenvalues, which are the DFT of the first column.
+    pub fn eigenvalues(&self) -> Vec<Complex> {
+        let column: Vec<Complex> = self.first_col.iter().map(|v| Complex::from(*v)).collect();
+        fft(&column)
+    }
+
+    pub fn mul_vec(&self, x: &[f64]) -> Result<Vec<f64>, MisAlignment> {
+        if x.len() != self.size() {
+            return Err(MisAlignment);
+        }
+        Ok(circular_convolution(&self.first_col, x))
+    }
+
+    /// Solves `C x = b` by dividing by the eigenvalues in the Fourier domain, or returns `None`
+    /// if the matrix is numerically singular.
+    pub fn solve(&self, b: &[f64]) -> Option<Vec<f64>> {
+        let n = self.size();
+        if b.len() != n {
+            return None;
+        }
+
+        let eigenvalues = self.eigenvalues();
+        let largest = eigenvalues.iter().map(|v| v.norm()).fold(0.0, f64::max);
+        let threshold = largest * n as f64 * f64::EPSILON;
+        if eigenvalues.iter().any(|v| v.norm() <= threshold) {
+            return None;
+        }
+
+        let rhs: Vec<Complex> = b.iter().map(|v| Complex::from(*v)).collect();
+        let quotient: Vec<Complex> = fft(&rhs)
+            .into_iter()
+            .zip(eigenvalues)
+            .map(|(b, lambda)| b / lambda)
+            .collect();
+        Some(ifft(&quotient).into_iter().map(|v| v.re).collect())
+    }
+}
+
+fn circular_convolution(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let a: Vec<Complex> = a.iter().map(|v| Complex::from(*v)).collect();
+    let b: Vec<Complex> = b.iter().map(|v| Complex::from(*v)).collect();
+    let product: Vec<Complex> = fft(&a)
+        .into_iter()
+        .zip(fft(&b))
+        .map(|(x, y)| x * y)
+        .collect();
+    ifft(&product).into_iter().map(|v| v.re).collect()
+}
+
+impl From<&ToeplitzMatrix> for MatrixContent<f64> {
+    fn from(value: &ToeplitzMatrix) -> Self {
+        let n = value.size() as isize;
+        MatrixContent::from_fn(Dimension::new(n, n), |row, col| {
+            value.get(row as usize, col as usize)
+        })
+    }
+}
+
+impl From<&CirculantMatrix> for MatrixContent<f64> {
+    fn from(value: &CirculantMatrix) -> Self {
+        MatrixContent::circulant(&value.first_col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrices::testing::max_difference;
+    use crate::random::SplitMix64;
+
+    fn dense_mul_vec(a: &MatrixContent<f64>, x: &[f64]) -> Vec<f64> {
+        let column = MatrixContent::new(Dimension::new(1, x.len() as isize), x.to_vec());
+        let product = a * &column;
+        (0..a.height()).map(|row| product[(row, 0)]).collect()
+    }
+
+    fn random_toeplitz(rng: &mut SplitMix64, n: usize) -> ToeplitzMatrix {
+        let mut first_col: Vec<f64> = (0..n).map(|_| rng.next_normal()).collect();
+        let mut first_row: Vec<f64> = (0..n).map(|_| rng.next_normal()).collect();
+        // A dominant diagonal keeps every leading minor well away from singular.
+        first_col[0] = 2.0 * n as f64;
+        first_row[0] = first_col[0];
+        ToeplitzMatrix::new(first_col, first_row).unwrap()
+    }
+
+    #[test]
+    fn rejects_mismatched_corners() {
+        assert!(ToeplitzMatrix::new(vec![1.0, 2.0], vec![3.0, 2.0]).is_err());
+        assert!(ToeplitzMatrix::new(vec![1.0, 2.0], vec![1.0]).is_err());
+        assert!(CirculantMatrix::new(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn toeplitz_product_matches_dense() {
+        let mut rng = SplitMix64::new(340);
+        for n in [1, 2, 5, 16, 33] {
+            let t = random_toeplitz(&mut rng, n);
+            let dense = MatrixContent::from(&t);
+            assert_eq!(
+                dense,
+                MatrixContent::toeplitz(&t.first_col, &t.first_row).unwrap()
+            );
+
+            let x: Vec<f64> = (0..n).map(|_| rng.next_normal()).collect();
+            let y = t.mul_vec(&x).unwrap();
+            assert!(
+                max_difference(&y, &dense_mul_vec(&dense, &x)) < 1e-10,
+                "n = {n}"
+            );
+        }
+        assert!(random_toeplitz(&mut rng, 3).mul_vec(&[1.0]).is_err());
+    }
+
+    #[test]
+    fn levinson_solve() {
+        let mut rng = SplitMix64::new(341);
+        for n in [1, 4, 20] {
+            let t = random_toeplitz(&mut rng, n);
+            let b: Vec<f64> = (0..n).map(|_| rng.next_normal()).collect();
+            let x = t.solve(&b).unwrap();
+            assert!(
+                max_difference(&t.mul_vec(&x).unwrap(), &b) < 1e-10,
+                "n = {n}"
+            );
+        }
+
+        // Invertible, but its leading 1x1 minor is zero.
+        let swap = ToeplitzMatrix::symmetric(vec![0.0, 1.0]);
+        assert!(swap.solve(&[1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn levinson_durbin_recovers_an_ar1_model() {
+        // Autocorrelations of x_t = 0.6 x_{t-1} + e_t with unit noise variance.
+        let phi: f64 = 0.6;
+        let variance = 1.0 / (1.0 - phi * phi);
+        let r: Vec<f64> = (0..4).map(|k| variance * phi.powi(k)).collect();
+
+        let (coefficients, noise) = levinson_durbin(&r, 3).unwrap();
+        assert!(max_difference(&coefficients, &[0.6, 0.0, 0.0]) < 1e-12);
+        assert!((noise - 1.0).abs() < 1e-12);
+        assert!(levinson_durbin(&r, 4).is_none());
+        assert!(levinson_durbin(&[0.0, 1.0], 1).is_none());
+    }
+
+    #[test]
+    fn circulant_product_solve_and_spectrum() {
+        let c = CirculantMatrix::new(vec![4.0, 1.0, 0.0, 2.0, -1.0]).unwrap();
+        let dense = MatrixContent::from(&c);
+        assert_eq!(c.get(0, 1), -1.0);
+        assert_eq!(dense[(0, 1)], -1.0);
+
+        let x = [1.0, -2.0, 0.5, 3.0, 1.0];
+        let y = c.mul_vec(&x).unwrap();
+        assert!(max_difference(&y, &dense_mul_vec(&dense, &x)) < 1e-12);
+        assert!(max_difference(&c.solve(&y).unwrap(), &x) < 1e-12);
+
+        // The row sum is the eigenvalue of the constant eigenvector.
+        let eigenvalues = c.eigenvalues();
+        assert!((eigenvalues[0] - Complex::from(6.0)).norm() < 1e-12);
+
+        let singular = CirculantMatrix::new(vec![1.0, -1.0]).unwrap();
+        assert!(singular.solve(&[1.0, 1.0]).is_none());
+    }
+}
@@ -0,0 +1,329 @@
+use std::ops::{Index, IndexMut, Range};
+
+use crate::matrices::{MatrixContent, MisAlignment};
+use crate::scalar::Zero;
+
+use super::Structured;
+
+/// Square matrix with zeros below the main diagonal, storing the upper triangle row by row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpperTriangular<T> {
+    size: usize,
+    packed: Vec<T>,
+    zero: T,
+}
+
+/// Square matrix with zeros above the main diagonal, storing the lower triangle row by row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowerTriangular<T> {
+    size: usize,
+    packed: Vec<T>,
+    zero: T,
+}
+
+fn packed_len(size: usize) -> usize {
+    size * (size + 1) / 2
+}
+
+impl<T: Zero> UpperTriangular<T> {
+    /// Wraps the rows of the upper triangle, concatenated: row `i` holds columns `i..size`.
+    pub fn from_packed(size: usize, packed: Vec<T>) -> Result<Self, MisAlignment> {
+        if packed.len() != packed_len(size) {
+            return Err(MisAlignment);
+        }
+        Ok(Self {
+            size,
+            packed,
+            zero: T::zero(),
+        })
+    }
+}
+
+impl<T: Zero + Clone> UpperTriangular<T> {
+    /// Copies the upper triangle of a square matrix, ignoring everything below the diagonal.
+    pub fn from_dense(value: &MatrixContent<T>) -> Result<Self, MisAlignment> {
+        if value.width() != value.height() {
+            return Err(MisAlignment);
+        }
+        let size = value.height();
+        let packed = (0..size)
+            .flat_map(|row| (row..size).map(move |col| (row, col)))
+            .map(|index| value[index].clone())
+            .collect();
+        Self::from_packed(size as usize, packed)
+    }
+
+    pub fn transpose(&self) -> LowerTriangular<T> {
+        let packed = (0..self.size)
+            .flat_map(|row| (0..=row).map(move |col| (col, row)))
+            .map(|index| self[index].clone())
+            .collect();
+        LowerTriangular {
+            size: self.size,
+            packed,
+            zero: T::zero(),
+        }
+    }
+}
+
+impl<T> UpperTriangular<T> {
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    fn offset(&self, row: usize, col: usize) -> usize {
+        row * (2 * self.size + 1 - row) / 2 + (col - row)
+    }
+
+    /// Mutable access to a stored entry, or `None` below the diagonal.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row <= col && col < self.size {
+            let offset = self.offset(row, col);
+            Some(&mut self.packed[offset])
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Zero> LowerTriangular<T> {
+    /// Wraps the rows of the lower triangle, concatenated: row `i` holds columns `0..=i`.
+    pub fn from_packed(size: usize, packed: Vec<T>) -> Result<Self, MisAlignment> {
+        if packed.len() != packed_len(size) {
+            return Err(MisAlignment);
+        }
+        Ok(Self {
+            size,
+            packed,
+            zero: T::zero(),
+        })
+    }
+}
+
+impl<T: Zero + Clone> LowerTriangular<T> {
+    /// Copies the lower triangle of a square matrix, ignoring everything above the diagonal.
+    pub fn from_dense(value: &MatrixContent<T>) -> Result<Self, MisAlignment> {
+        if value.width() != value.height() {
+            return Err(MisAlignment);
+        }
+        let size = value.height();
+        let packed = (0..size)
+            .flat_map(|row| (0..=row).map(move |col| (row, col)))
+            .map(|index| value[index].clone())
+            .collect();
+        Self::from_packed(size as usize, packed)
+    }
+
+    pub fn transpose(&self) -> UpperTriangular<T> {
+        let packed = (0..self.size)
+            .flat_map(|row| (row..self.size).map(move |col| (col, row)))
+            .map(|index| self[index].clone())
+            .collect();
+        UpperTriangular {
+            size: self.size,
+            packed,
+            zero: T::zero(),
+        }
+    }
+}
+
+impl<T> LowerTriangular<T> {
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    fn offset(&self, row: usize, col: usize) -> usize {
+        row * (row + 1) / 2 + col
+    }
+
+    /// Mutable access to a stored entry, or `None` above the diagonal.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if col <= row && row < self.size {
+            let offset = self.offset(row, col);
+            Some(&mut self.packed[offset])
+        } else {
+            None
+        }
+    }
+}
+
+impl UpperTriangular<f64> {
+    /// Solves `U x = b` by back substitution, or returns `None` if a diagonal entry is zero.
+    pub fn solve(&self, b: &[f64]) -> Option<Vec<f64>> {
+        if b.len() != self.size {
+            return None;
+        }
+
+        let mut x = b.to_vec();
+        for row in (0..self.size).rev() {
+            let pivot = self[(row, row)];
+            if pivot == 0.0 {
+                return None;
+            }
+            let sum: f64 = (row + 1..self.size)
+                .map(|col| self[(row, col)] * x[col])
+                .sum();
+            x[row] = (x[row] - sum) / pivot;
+        }
+        Some(x)
+    }
+}
+
+impl LowerTriangular<f64> {
+    /// Solves `L x = b` by forward substitution, or returns `None` if a diagonal entry is zero.
+    pub fn solve(&self, b: &[f64]) -> Option<Vec<f64>> {
+        if b.len() != self.size {
+            return None;
+        }
+
+        let mut x = b.to_vec();
+        for row in 0..self.size {
+            let pivot = self[(row, row)];
+            if pivot == 0.0 {
+                return None;
+            }
+            let sum: f64 = (0..row).map(|col| self[(row, col)] * x[col]).sum();
+            x[row] = (x[row] - sum) / pivot;
+        }
+        Some(x)
+    }
+}
+
+impl<T> Index<(usize, usize)> for UpperTriangular<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.size && col < self.size, "index out of bounds");
+        if row <= col {
+            &self.packed[self.offset(row, col)]
+        } else {
+            &self.zero
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for LowerTriangular<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.size && col < self.size, "index out of bounds");
+        if col <= row {
+            &self.packed[self.offset(row, col)]
+        } else {
+            &self.zero
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for LowerTriangular<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(row, col).expect(
+            "Only entries on or below the diagonal of a lower triangular matrix can be assigned",
+        )
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for UpperTriangular<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        self.get_mut(row, col).expect(
+            "Only entries on or above the diagonal of an upper triangular matrix can be assigned",
+        )
+    }
+}
+
+impl<T> Structured<T> for UpperTriangular<T> {
+    fn row_support(&self, row: usize) -> Range<usize> {
+        row..self.size
+    }
+}
+
+impl<T> Structured<T> for LowerTriangular<T> {
+    fn row_support(&self, row: usize) -> Range<usize> {
+        0..row + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimension::Dimension;
+
+    fn dense() -> MatrixContent<f64> {
+        MatrixContent::new(
+            Dimension::new(3, 3),
+            vec![2.0, 1.0, -1.0, 3.0, 4.0, 5.0, -2.0, 6.0, 7.0],
+        )
+    }
+
+    fn residual(a: &MatrixContent<f64>, x: &[f64], b: &[f64]) -> f64 {
+        let x = MatrixContent::new(Dimension::new(1, x.len() as isize), x.to_vec());
+        let ax = a * &x;
+        b.iter()
+            .enumerate()
+            .map(|(i, b)| (ax[(i as isize, 0)] - b).abs())
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn packed_storage_and_indexing() {
+        let upper = UpperTriangular::from_packed(3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(
+            (upper[(0, 2)], upper[(1, 1)], upper[(2, 2)], upper[(2, 0)]),
+            (3, 4, 6, 0)
+        );
+        let lower = LowerTriangular::from_packed(3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(
+            (lower[(1, 0)], lower[(2, 1)], lower[(2, 2)], lower[(0, 2)]),
+            (2, 5, 6, 0)
+        );
+        assert!(UpperTriangular::from_packed(3, vec![1, 2]).is_err());
+        assert!(LowerTriangular::from_packed(2, vec![1, 2]).is_err());
+        assert_eq!(upper.transpose().transpose(), upper);
+        assert_eq!(lower.transpose()[(0, 2)], 4);
+    }
+
+    #[test]
+    fn from_dense_ignores_the_other_triangle() {
+        let upper = UpperTriangular::from_dense(&dense()).unwrap();
+        let lower = LowerTriangular::from_dense(&dense()).unwrap();
+        assert_eq!(upper[(0, 1)], 1.0);
+        assert_eq!(upper[(1, 0)], 0.0);
+        assert_eq!(lower[(2, 1)], 6.0);
+        assert_eq!(lower[(1, 2)], 0.0);
+
+        let sum = &MatrixContent::from(&upper) + &MatrixContent::from(&lower);
+        let diagonal = MatrixContent::from_fn(Dimension::new(3, 3), |row, col| {
+            if row == col {
+                dense()[(row, col)]
+            } else {
+                0.0
+            }
+        });
+        assert_eq!(&sum + &(diagonal * -1.0), dense());
+    }
+
+    #[test]
+    fn substitution_solves() {
+        let b = [1.0, -2.0, 3.0];
+        let upper = UpperTriangular::from_dense(&dense()).unwrap();
+        let x = upper.solve(&b).unwrap();
+        assert!(residual(&MatrixContent::from(&upper), &x, &b) < 1e-12);
+        assert_eq!(upper.mul_vec(&x).unwrap().len(), 3);
+
+        let lower = LowerTriangular::from_dense(&dense()).unwrap();
+        let x = lower.solve(&b).unwrap();
+        assert!(residual(&MatrixContent::from(&lower), &x, &b) < 1e-12);
+
+        let mut singular = lower.clone();
+        singular[(1, 1)] = 0.0;
+        assert!(singular.solve(&b).is_none());
+        assert!(lower.solve(&b[..2]).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "on or above the diagonal")]
+    fn assigning_below_an_upper_triangle_panics() {
+        let mut upper = UpperTriangular::from_dense(&dense()).unwrap();
+        upper[(2, 0)] = 1.0;
+    }
+}
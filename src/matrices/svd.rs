@@ -0,0 +1,427 @@
+use crate::dimension::Dimension;
+use crate::iterative::{axpy, dot, norm};
+use crate::operator::LinearOperator;
+use crate::random::SplitMix64;
+
+use super::{GenericMatrix, MatrixContent, MisAlignment};
+
+/// Thin singular value decomposition `A = U diag(s) V^T` with singular values in decreasing
+/// order.
+///
+/// Columns of `u` belonging to zero singular values are zero rather than completed to an
+/// orthonormal set.
+#[derive(Debug, Clone)]
+pub struct Svd {
+    pub u: GenericMatrix,
+    pub singular_values: Vec<f64>,
+    pub vt: GenericMatrix,
+}
+
+/// Rank-k approximation together with how far it is from the original matrix.
+#[derive(Debug, Clone)]
+pub struct LowRankApproximation {
+    pub matrix: GenericMatrix,
+    pub svd: Svd,
+    /// `||A - A_k||_F`
+    pub frobenius_error: f64,
+    /// `||A - A_k||_2`, the largest discarded singular value.
+    pub spectral_error: f64,
+    /// `||A - A_k||_F / ||A||_F`
+    pub relative_error: f64,
+}
+
+impl Svd {
+    pub fn rank(&self) -> usize {
+        self.singular_values.len()
+    }
+
+    /// `U diag(s) V^T`
+    pub fn reconstruct(&self) -> GenericMatrix {
+        let (height, width) = (self.u.height(), self.vt.width());
+        GenericMatrix::from_content(MatrixContent::from_fn(
+            Dimension::new(width, height),
+            |row, col| {
+                self.singular_values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, s)| self.u[(row, i as isize)] * s * self.vt[(i as isize, col)])
+                    .sum()
+            },
+        ))
+    }
+
+    /// Keeps the `k` largest singular triplets.
+    pub fn truncate(&self, k: usize) -> Svd {
+        let k = k.min(self.rank()) as isize;
+        Svd {
+            u: GenericMatrix::from_content(MatrixContent::from_fn(
+                Dimension::new(k, self.u.height()),
+                |row, col| self.u[(row, col)],
+            )),
+            singular_values: self.singular_values[..k as usize].to_vec(),
+            vt: GenericMatrix::from_content(MatrixContent::from_fn(
+                Dimension::new(self.vt.width(), k),
+                |row, col| self.vt[(row, col)],
+            )),
+        }
+    }
+}
+
+impl GenericMatrix {
+    /// Thin SVD by one-sided Jacobi rotations, accurate to working precision even for small
+    /// singular values.
+    pub fn svd(&self) -> Svd {
+        let columns = (0..self.width())
+            .map(|col| self.column_iter(col).copied().collect())
+            .collect();
+        let (u, singular_values, v) = jacobi_svd(columns, self.height() as usize);
+        svd_from_columns(&self.dimension, &u, singular_values, &v)
+    }
+
+    /// Approximate SVD of the `k` largest singular triplets by randomized range finding.
+    ///
+    /// The range of `A` is sampled with `k + oversample` Gaussian test vectors, refined by
+    /// `power_iters` rounds of power iteration with `A A^T`, which sharpens the result when the
+    /// singular values decay slowly. The cost is a few passes over `A` plus dense work on
+    /// matrices with `k + oversample` columns.
+    ///
+    /// Exactly `k` triplets are returned. If `A` has rank below `k`, the missing ones have zero
+    /// singular values and zero left vectors, like the exact SVD.
+    pub fn randomized_svd(
+        &self,
+        k: usize,
+        oversample: usize,
+        power_iters: usize,
+        seed: u64,
+    ) -> Result<Svd, MisAlignment> {
+        let (m, n) = (self.height() as usize, self.width() as usize);
+        if k == 0 || k > m.min(n) {
+            return Err(MisAlignment);
+        }
+
+        let samples = (k + oversample).min(m.min(n));
+        let mut rng = SplitMix64::new(seed);
+        let omega: Vec<Vec<f64>> = (0..samples)
+            .map(|_| (0..n).map(|_| rng.next_normal()).collect())
+            .collect();
+
+        let apply = |x: &[f64]| {
+            let mut y = vec![0.0; m];
+            self.apply(x, &mut y);
+            y
+        };
+        let apply_transpose = |x: &[f64]| {
+            let mut y = vec![0.0; n];
+            self.apply_transpose(x, &mut y);
+            y
+        };
+
+        let mut q = orthonormalize(omega.iter().map(|w| apply(w)).collect());
+        for _ in 0..power_iters {
+            let z = orthonormalize(q.iter().map(|q| apply_transpose(q)).collect());
+            q = orthonormalize(z.iter().map(|z| apply(z)).collect());
+        }
+
+        // B = Q^T A is small; the columns of B^T are A^T q_i.
+        let bt: Vec<Vec<f64>> = q.iter().map(|q| apply_transpose(q)).collect();
+        let (mut v, mut singular_values, w) = jacobi_svd(bt, n);
+
+        // B^T = V S W^T, so A ~ Q B = (Q W) S V^T.
+        let mut u: Vec<Vec<f64>> = w
+            .iter()
+            .map(|w| {
+                let mut u = vec![0.0; m];
+                for (q, c) in q.iter().zip(w) {
+                    axpy(*c, q, &mut u);
+                }
+                u
+            })
+            .collect();
+
+        // When A has rank below k the sampled range runs out of directions. Pad with zero
+        // singular values, zero left vectors and right vectors completing V to an orthonormal
+        // set, as the exact SVD does.
+        if singular_values.len() < k {
+            let units = (0..n).map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect());
+            v = orthonormalize(v.into_iter().chain(units).collect());
+            v.truncate(k);
+            u.resize(k, vec![0.0; m]);
+            singular_values.resize(k, 0.0);
+        }
+        Ok(svd_from_columns(&self.dimension, &u, singular_values, &v).truncate(k))
+    }
+
+    /// The best rank-`k` approximation in both the Frobenius and spectral norms, from the
+    /// exact SVD.
+    pub fn truncate_rank(&self, k: usize) -> Result<LowRankApproximation, MisAlignment> {
+        if k > self.width().min(self.height()) as usize {
+            return Err(MisAlignment);
+        }
+
+        let full = self.svd();
+        let discarded = &full.singular_values[k..];
+        let total = full
+            .singular_values
+            .iter()
+            .map(|s| s * s)
+            .sum::<f64>()
+            .sqrt();
+        let frobenius_error = discarded.iter().map(|s| s * s).sum::<f64>().sqrt();
+        let svd = full.truncate(k);
+        Ok(LowRankApproximation {
+            matrix: svd.reconstruct(),
+            svd,
+            frobenius_error,
+            spectral_error: discarded.first().copied().unwrap_or(0.0),
+            relative_error: if total == 0.0 {
+                0.0
+            } else {
+                frobenius_error / total
+            },
+        })
+    }
+}
+
+/// One-sided Jacobi SVD of the matrix with the given columns, each of length `height`.
+///
+/// Returns `(U, s, V)` as column lists with `s` decreasing. Wide matrices are handled through
+/// their transpose.
+fn jacobi_svd(columns: Vec<Vec<f64>>, height: usize) -> (Vec<Vec<f64>>, Vec<f64>, Vec<Vec<f64>>) {
+    let width = columns.len();
+    if width > height {
+        let rows = (0..height)
+            .map(|row| columns.iter().map(|c| c[row]).collect())
+            .collect();
+        let (v, s, u) = jacobi_svd(rows, width);
+        return (u, s, v);
+    }
+
+    let mut w = columns;
+    let mut v: Vec<Vec<f64>> = (0..width)
+        .map(|i| (0..width).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+    for _ in 0..60 {
+        let mut rotated = false;
+        for p in 0..width {
+            for q in p + 1..width {
+                let alpha = dot(&w[p], &w[p]);
+                let beta = dot(&w[q], &w[q]);
+                let gamma = dot(&w[p], &w[q]);
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() || gamma == 0.0 {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + zeta.hypot(1.0));
+                let c = 1.0 / t.hypot(1.0);
+                let s = c * t;
+                for columns in [&mut w, &mut v] {
+                    let (left, right) = columns.split_at_mut(q);
+                    for (x, y) in left[p].iter_mut().zip(right[0].iter_mut()) {
+                        let (a, b) = (*x, *y);
+                        *x = c * a - s * b;
+                        *y = s * a + c * b;
+                    }
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut order: Vec<usize> = (0..width).collect();
+    let lengths: Vec<f64> = w.iter().map(|w| norm(w)).collect();
+    order.sort_by(|&a, &b| lengths[b].total_cmp(&lengths[a]));
+    let u = order
+        .iter()
+        .map(|&i| {
+            let length = lengths[i];
+            w[i].iter()
+                .map(|x| if length == 0.0 { 0.0 } else { x / length })
+                .collect()
+        })
+        .collect();
+    let v = order.iter().map(|&i| v[i].clone()).collect();
+    (u, order.iter().map(|&i| lengths[i]).collect(), v)
+}
+
+/// Orthonormal basis of the span of `columns` by Gram-Schmidt with reorthogonalization.
+/// Columns that are numerically dependent on earlier ones are dropped.
+fn orthonormalize(columns: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let mut basis: Vec<Vec<f64>> = Vec::with_capacity(columns.len());
+    for mut column in columns {
+        let original = norm(&column);
+        for _ in 0..2 {
+            for q in &basis {
+                let projection = dot(q, &column);
+                axpy(-projection, q, &mut column);
+            }
+        }
+        let length = norm(&column);
+        if length > 1e-12 * original {
+            column.iter_mut().for_each(|x| *x /= length);
+            basis.push(column);
+        }
+    }
+    basis
+}
+
+fn svd_from_columns(
+    dimension: &Dimension,
+    u: &[Vec<f64>],
+    singular_values: Vec<f64>,
+    v: &[Vec<f64>],
+) -> Svd {
+    let rank = singular_values.len() as isize;
+    let (height, width) = (dimension.height(), dimension.width());
+    Svd {
+        u: GenericMatrix::from_content(MatrixContent::from_fn(
+            Dimension::new(rank, height),
+            |row, col| u[col as usize][row as usize],
+        )),
+        singular_values,
+        vt: GenericMatrix::from_content(MatrixContent::from_fn(
+            Dimension::new(width, rank),
+            |row, col| v[row as usize][col as usize],
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_matrix_approx_eq;
+
+    fn random(rng: &mut SplitMix64, height: isize, width: isize) -> GenericMatrix {
+        GenericMatrix::from_content(MatrixContent::from_fn(
+            Dimension::new(width, height),
+            |_, _| rng.next_normal(),
+        ))
+    }
+
+    fn transpose(m: &GenericMatrix) -> MatrixContent<f64> {
+        m.content.clone().into_transpose()
+    }
+
+    fn frobenius(m: &MatrixContent<f64>) -> f64 {
+        m.entries().map(|x| x * x).sum::<f64>().sqrt()
+    }
+
+    #[test]
+    fn exact_svd_reconstructs_tall_and_wide_matrices() {
+        let mut rng = SplitMix64::new(39);
+        for (height, width) in [(8, 5), (4, 7), (6, 6), (1, 3)] {
+            let a = random(&mut rng, height, width);
+            let svd = a.svd();
+            let rank = height.min(width);
+            assert_eq!(svd.rank(), rank as usize);
+            assert_matrix_approx_eq!(svd.reconstruct(), a);
+
+            let identity = MatrixContent::identity(rank);
+            assert_matrix_approx_eq!(&transpose(&svd.u) * &svd.u, identity);
+            assert_matrix_approx_eq!(&svd.vt.content * &transpose(&svd.vt), identity);
+            assert!(svd
+                .singular_values
+                .windows(2)
+                .all(|pair| pair[0] >= pair[1]));
+            assert!(svd.singular_values.iter().all(|&s| s >= 0.0));
+        }
+    }
+
+    #[test]
+    fn known_singular_values() {
+        let a = GenericMatrix::try_from(vec![
+            vec![0.0, 0.0, -2.0],
+            vec![3.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0],
+            vec![0.0, 0.5, 0.0],
+        ])
+        .unwrap();
+        let svd = a.svd();
+        assert_eq!(svd.singular_values, vec![3.0, 2.0, 0.5]);
+        assert_matrix_approx_eq!(svd.reconstruct(), a);
+    }
+
+    #[test]
+    fn rank_deficient_matrices_have_zero_left_vectors() {
+        let mut rng = SplitMix64::new(390);
+        let b = random(&mut rng, 6, 2);
+        let c = random(&mut rng, 2, 5);
+        let a = GenericMatrix::from_content(&b.content * &c.content);
+        let svd = a.svd();
+        assert!(svd.singular_values[1] > 1e-3);
+        assert!(svd.singular_values[2..].iter().all(|&s| s < 1e-12));
+        assert_matrix_approx_eq!(svd.reconstruct(), a);
+    }
+
+    #[test]
+    fn randomized_svd_recovers_a_low_rank_matrix() {
+        let mut rng = SplitMix64::new(391);
+        let b = random(&mut rng, 40, 5);
+        let c = random(&mut rng, 5, 30);
+        let a = GenericMatrix::from_content(&b.content * &c.content);
+        let exact = a.svd();
+
+        let approximate = a.randomized_svd(5, 5, 1, 7).unwrap();
+        assert_eq!(approximate.rank(), 5);
+        for (s, t) in approximate
+            .singular_values
+            .iter()
+            .zip(&exact.singular_values)
+        {
+            assert!((s - t).abs() < 1e-9 * t);
+        }
+        assert_matrix_approx_eq!(approximate.reconstruct(), a, 1e-9, 1e-9);
+
+        assert!(a.randomized_svd(0, 5, 1, 7).is_err());
+        assert!(a.randomized_svd(31, 5, 1, 7).is_err());
+    }
+
+    #[test]
+    fn randomized_svd_pads_rank_deficient_matrices() {
+        let mut rng = SplitMix64::new(393);
+        let b = random(&mut rng, 8, 2);
+        let c = random(&mut rng, 2, 6);
+        let low_rank = GenericMatrix::from_content(&b.content * &c.content);
+        let zero =
+            GenericMatrix::from_content(MatrixContent::from_fn(Dimension::new(6, 8), |_, _| 0.0));
+
+        for (a, rank) in [(low_rank, 2), (zero, 0)] {
+            let svd = a.randomized_svd(4, 2, 1, 11).unwrap();
+            assert_eq!(svd.rank(), 4);
+            assert_eq!((svd.u.width(), svd.vt.height()), (4, 4));
+            assert!(svd.singular_values[..rank].iter().all(|&s| s > 1e-3));
+            assert!(svd.singular_values[rank..].iter().all(|&s| s < 1e-9));
+            assert!((rank..4).all(|col| svd.u.column_iter(col as isize).all(|&x| x == 0.0)));
+            assert_matrix_approx_eq!(
+                &svd.vt.content * &transpose(&svd.vt),
+                MatrixContent::identity(4)
+            );
+            assert_matrix_approx_eq!(svd.reconstruct(), a, 1e-9, 1e-9);
+        }
+    }
+
+    #[test]
+    fn truncation_error_matches_the_discarded_singular_values() {
+        let mut rng = SplitMix64::new(392);
+        let a = random(&mut rng, 7, 5);
+        let s = a.svd().singular_values;
+
+        let approximation = a.truncate_rank(2).unwrap();
+        assert_eq!(approximation.svd.rank(), 2);
+        let difference = &a.content + &(approximation.matrix.content.clone() * -1.0);
+        assert!((frobenius(&difference) - approximation.frobenius_error).abs() < 1e-12);
+        let discarded = s[2..].iter().map(|s| s * s).sum::<f64>().sqrt();
+        assert!((approximation.frobenius_error - discarded).abs() < 1e-12);
+        assert_eq!(approximation.spectral_error, s[2]);
+        let relative = discarded / frobenius(&a.content);
+        assert!((approximation.relative_error - relative).abs() < 1e-12);
+
+        let full = a.truncate_rank(5).unwrap();
+        assert_eq!((full.frobenius_error, full.spectral_error), (0.0, 0.0));
+        assert_matrix_approx_eq!(full.matrix, a);
+        assert!(a.truncate_rank(6).is_err());
+    }
+}
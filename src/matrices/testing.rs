@@ -0,0 +1,26 @@
+//! Helpers shared by the unit tests of several modules.
+
+use super::MatrixContent;
+
+/// The cells of each row, for comparing whole matrices against nested literals.
+pub(crate) fn rows<T: Clone>(m: &MatrixContent<T>) -> Vec<Vec<T>> {
+    (0..m.height())
+        .map(|row| m.row_iter(row).cloned().collect())
+        .collect()
+}
+
+/// Largest absolute difference between corresponding cells of two equally long slices, or `NaN`
+/// if any difference is `NaN`.
+pub(crate) fn max_difference(a: &[f64], b: &[f64]) -> f64 {
+    assert_eq!(a.len(), b.len());
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (a - b).abs())
+        .fold(0.0, |max, difference| {
+            if difference > max || difference.is_nan() {
+                difference
+            } else {
+                max
+            }
+        })
+}
@@ -0,0 +1,193 @@
+use std::ops::Index;
+
+use crate::dimension::Dimension;
+
+use super::{Layout, MatrixContent, MatrixIter, MisAlignment};
+
+/// Borrowed rectangular block of a [`MatrixContent`], indexed relative to its top left corner.
+pub struct MatrixView<'a, T> {
+    pub(crate) mat: &'a MatrixContent<T>,
+    pub(crate) row: isize,
+    pub(crate) col: isize,
+    pub(crate) dimension: Dimension,
+}
+
+impl<T> MatrixContent<T> {
+    /// The `height` by `width` block whose top left cell is `(row, col)`, without copying.
+    pub fn view(
+        &self,
+        row: isize,
+        col: isize,
+        height: isize,
+        width: isize,
+    ) -> Result<MatrixView<'_, T>, MisAlignment> {
+        if row < 0
+            || col < 0
+            || height < 0
+            || width < 0
+            || row + height > self.height()
+            || col + width > self.width()
+        {
+            return Err(MisAlignment);
+        }
+
+        Ok(MatrixView {
+            mat: self,
+            row,
+            col,
+            dimension: Dimension::new(width, height),
+        })
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    pub fn width(&self) -> isize {
+        self.dimension.width()
+    }
+
+    pub fn height(&self) -> isize {
+        self.dimension.height()
+    }
+
+    pub fn dimension(&self) -> &Dimension {
+        &self.dimension
+    }
+
+    /// Position of the view's top left cell in the viewed matrix.
+    pub fn offset(&self) -> (isize, isize) {
+        (self.row, self.col)
+    }
+
+    fn reflect(&self, (row, col): (isize, isize)) -> (isize, isize) {
+        let row = if row < 0 { self.height() + row } else { row };
+        let col = if col < 0 { self.width() + col } else { col };
+        assert!(
+            (0..self.height()).contains(&row) && (0..self.width()).contains(&col),
+            "index out of the bounds of the view"
+        );
+        (row, col)
+    }
+
+    pub(crate) fn row_iter(&self, row: isize) -> MatrixIter<'a, T> {
+        let (row, _) = self.reflect((row, 0));
+        let start = self.mat.flat_index((self.row + row, self.col));
+        let step = match self.mat.layout {
+            Layout::RowMajor => 1,
+            Layout::ColumnMajor => self.mat.height() as usize,
+        };
+        self.mat.strided(start, step, self.width() as usize)
+    }
+
+    pub(crate) fn column_iter(&self, col: isize) -> MatrixIter<'a, T> {
+        let (_, col) = self.reflect((0, col));
+        let start = self.mat.flat_index((self.row, self.col + col));
+        let step = match self.mat.layout {
+            Layout::RowMajor => self.mat.width() as usize,
+            Layout::ColumnMajor => 1,
+        };
+        self.mat.strided(start, step, self.height() as usize)
+    }
+}
+
+impl<'a, T> Index<(isize, isize)> for MatrixView<'a, T> {
+    type Output = T;
+
+    fn index(&self, index: (isize, isize)) -> &Self::Output {
+        let (row, col) = self.reflect(index);
+        &self.mat[(self.row + row, self.col + col)]
+    }
+}
+
+impl<'a, T: Clone> From<&MatrixView<'a, T>> for MatrixContent<T> {
+    fn from(value: &MatrixView<'a, T>) -> Self {
+        MatrixContent::from_fn(value.dimension.clone(), |row, col| {
+            value[(row, col)].clone()
+        })
+    }
+}
+
+impl<'a, T: std::fmt::Display> std::fmt::Display for MatrixView<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::new();
+        for row in 0..self.height() {
+            for item in self.row_iter(row) {
+                output.push_str(&format!("{}, ", item));
+            }
+            output.pop();
+            output.pop();
+            output.push('\n');
+        }
+        output.pop();
+        write!(f, "{}", output)
+    }
+}
+
+impl<'a, T: std::fmt::Debug> std::fmt::Debug for MatrixView<'a, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output = String::new();
+        for row in 0..self.height() {
+            for item in self.row_iter(row) {
+                output.push_str(&format!("{:?}, ", item));
+            }
+            output.pop();
+            output.pop();
+            output.push('\n');
+        }
+        output.pop();
+        write!(f, "{}", output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> MatrixContent<isize> {
+        MatrixContent::from_fn(Dimension::new(5, 4), |row, col| row * 10 + col)
+    }
+
+    #[test]
+    fn views_index_relative_to_their_corner() {
+        let m = grid();
+        let view = m.view(1, 2, 2, 3).unwrap();
+        assert_eq!((view.height(), view.width(), view.offset()), (2, 3, (1, 2)));
+        assert_eq!(view[(0, 0)], 12);
+        assert_eq!(view[(1, 2)], 24);
+        assert_eq!(view[(-1, -1)], 24);
+        assert_eq!(
+            MatrixContent::from(&view),
+            MatrixContent::from_fn(Dimension::new(3, 2), |row, col| (row + 1) * 10 + col + 2)
+        );
+        assert_eq!(view.to_string(), "12, 13, 14\n22, 23, 24");
+    }
+
+    #[test]
+    fn views_of_column_major_matrices() {
+        let m = grid().to_layout(Layout::ColumnMajor);
+        let view = m.view(2, 1, 2, 2).unwrap();
+        assert_eq!(view.row_iter(1).copied().collect::<Vec<_>>(), vec![31, 32]);
+        assert_eq!(
+            view.column_iter(0).copied().collect::<Vec<_>>(),
+            vec![21, 31]
+        );
+    }
+
+    #[test]
+    fn out_of_bounds_views_are_rejected() {
+        let m = grid();
+        assert!(m.view(0, 0, 4, 5).is_ok());
+        assert!(m.view(0, 0, 0, 0).is_ok());
+        assert!(m.view(1, 0, 4, 5).is_err());
+        assert!(m.view(0, 1, 4, 5).is_err());
+        assert!(m.view(-1, 0, 1, 1).is_err());
+        assert!(m.view(0, 0, -1, 1).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of the bounds of the view")]
+    fn indexing_outside_a_view_panics() {
+        let m = grid();
+        let view = m.view(0, 0, 2, 2).unwrap();
+        let _ = view[(2, 0)];
+    }
+}
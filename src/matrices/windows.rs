@@ -0,0 +1,254 @@
+use crate::dimension::Dimension;
+
+use super::{view::MatrixView, MatrixContent, MisAlignment};
+
+/// What [`MatrixContent::tiles`] does with the cells left over when the tile size does not
+/// divide the matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Remainder {
+    /// Skip the incomplete tiles along the bottom and right edges.
+    Drop,
+    /// Yield the incomplete tiles along the bottom and right edges, cut to fit.
+    Partial,
+    /// Refuse to tile unless the tile size divides the matrix.
+    Exact,
+}
+
+impl<T> MatrixContent<T> {
+    /// Every `height` by `width` block of adjacent cells, overlapping, with their top left
+    /// corners in row-major order. Yields nothing when the matrix is smaller than the window.
+    pub fn windows(&self, height: isize, width: isize) -> Result<Windows<'_, T>, MisAlignment> {
+        if height < 1 || width < 1 {
+            return Err(MisAlignment);
+        }
+
+        let rows = (self.height() - height + 1).max(0);
+        let cols = (self.width() - width + 1).max(0);
+        Ok(Windows {
+            mat: self,
+            dimension: Dimension::new(width, height),
+            cols,
+            pos: 0,
+            end: if cols == 0 { 0 } else { rows * cols },
+        })
+    }
+
+    /// The matrix cut into non-overlapping `height` by `width` blocks, in row-major order, with
+    /// leftover cells along the edges handled according to `remainder`.
+    pub fn tiles(
+        &self,
+        height: isize,
+        width: isize,
+        remainder: Remainder,
+    ) -> Result<Tiles<'_, T>, MisAlignment> {
+        if height < 1 || width < 1 {
+            return Err(MisAlignment);
+        }
+
+        let (rows, cols) = match remainder {
+            Remainder::Drop => (self.height() / height, self.width() / width),
+            Remainder::Partial => (
+                (self.height() + height - 1) / height,
+                (self.width() + width - 1) / width,
+            ),
+            Remainder::Exact => {
+                if self.height() % height != 0 || self.width() % width != 0 {
+                    return Err(MisAlignment);
+                }
+                (self.height() / height, self.width() / width)
+            }
+        };
+        Ok(Tiles {
+            mat: self,
+            dimension: Dimension::new(width, height),
+            cols,
+            pos: 0,
+            end: rows * cols,
+        })
+    }
+
+    /// Consecutive bands of `size` full rows; the last band is shorter when `size` does not
+    /// divide the height.
+    pub fn chunks_rows(&self, size: isize) -> Result<RowChunks<'_, T>, MisAlignment> {
+        if size < 1 {
+            return Err(MisAlignment);
+        }
+
+        Ok(RowChunks {
+            mat: self,
+            size,
+            row: 0,
+        })
+    }
+}
+
+pub struct Windows<'a, T> {
+    mat: &'a MatrixContent<T>,
+    dimension: Dimension,
+    cols: isize,
+    pos: isize,
+    end: isize,
+}
+
+impl<'a, T> Iterator for Windows<'a, T> {
+    type Item = MatrixView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.end {
+            let pos = self.pos;
+            self.pos += 1;
+            Some(MatrixView {
+                mat: self.mat,
+                row: pos / self.cols,
+                col: pos % self.cols,
+                dimension: self.dimension.clone(),
+            })
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.end - self.pos) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Windows<'a, T> {}
+
+pub struct Tiles<'a, T> {
+    mat: &'a MatrixContent<T>,
+    dimension: Dimension,
+    cols: isize,
+    pos: isize,
+    end: isize,
+}
+
+impl<'a, T> Iterator for Tiles<'a, T> {
+    type Item = MatrixView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.end {
+            let pos = self.pos;
+            self.pos += 1;
+            let row = pos / self.cols * self.dimension.height();
+            let col = pos % self.cols * self.dimension.width();
+            Some(MatrixView {
+                mat: self.mat,
+                row,
+                col,
+                dimension: Dimension::new(
+                    self.dimension.width().min(self.mat.width() - col),
+                    self.dimension.height().min(self.mat.height() - row),
+                ),
+            })
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.end - self.pos) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Tiles<'a, T> {}
+
+pub struct RowChunks<'a, T> {
+    mat: &'a MatrixContent<T>,
+    size: isize,
+    row: isize,
+}
+
+impl<'a, T> Iterator for RowChunks<'a, T> {
+    type Item = MatrixView<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row < self.mat.height() {
+            let row = self.row;
+            let height = self.size.min(self.mat.height() - row);
+            self.row += height;
+            Some(MatrixView {
+                mat: self.mat,
+                row,
+                col: 0,
+                dimension: Dimension::new(self.mat.width(), height),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 3 x 4 grid whose cell `(row, col)` holds `10 * row + col`.
+    fn grid() -> MatrixContent<isize> {
+        MatrixContent::from_fn(Dimension::new(4, 3), |row, col| 10 * row + col)
+    }
+
+    #[test]
+    fn windows_overlap_in_row_major_order() {
+        let m = grid();
+        let windows: Vec<_> = m.windows(2, 3).unwrap().collect();
+        assert_eq!(windows.len(), 4);
+        let corners: Vec<_> = windows.iter().map(|w| w.offset()).collect();
+        assert_eq!(corners, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+        assert_eq!(
+            MatrixContent::from(&windows[3]),
+            MatrixContent::new(Dimension::new(3, 2), vec![11, 12, 13, 21, 22, 23])
+        );
+
+        assert_eq!(m.windows(3, 4).unwrap().len(), 1);
+        assert_eq!(m.windows(4, 1).unwrap().count(), 0);
+        assert_eq!(m.windows(1, 5).unwrap().len(), 0);
+        assert!(m.windows(0, 1).is_err());
+    }
+
+    #[test]
+    fn tiles_handle_the_remainder() {
+        let m = grid();
+        let dropped: Vec<_> = m.tiles(2, 3, Remainder::Drop).unwrap().collect();
+        assert_eq!(dropped.len(), 1);
+        assert_eq!(
+            MatrixContent::from(&dropped[0]),
+            MatrixContent::new(Dimension::new(3, 2), vec![0, 1, 2, 10, 11, 12])
+        );
+
+        let partial: Vec<_> = m.tiles(2, 3, Remainder::Partial).unwrap().collect();
+        let shapes: Vec<_> = partial
+            .iter()
+            .map(|t| (t.offset(), t.height(), t.width()))
+            .collect();
+        assert_eq!(
+            shapes,
+            vec![
+                ((0, 0), 2, 3),
+                ((0, 3), 2, 1),
+                ((2, 0), 1, 3),
+                ((2, 3), 1, 1)
+            ]
+        );
+        assert_eq!(partial[3][(0, 0)], 23);
+
+        assert!(m.tiles(2, 3, Remainder::Exact).is_err());
+        let exact = m.tiles(3, 2, Remainder::Exact).unwrap();
+        assert_eq!(exact.len(), 2);
+        assert!(m.tiles(1, 0, Remainder::Drop).is_err());
+    }
+
+    #[test]
+    fn row_chunks_end_with_the_leftover_rows() {
+        let m = grid();
+        let heights: Vec<_> = m.chunks_rows(2).unwrap().map(|c| c.height()).collect();
+        assert_eq!(heights, vec![2, 1]);
+        let last = m.chunks_rows(2).unwrap().last().unwrap();
+        assert_eq!((last.offset(), last.width()), ((2, 0), 4));
+        assert_eq!(last[(0, 3)], 23);
+        assert_eq!(m.chunks_rows(5).unwrap().count(), 1);
+        assert!(m.chunks_rows(0).is_err());
+    }
+}
@@ -0,0 +1,536 @@
+use crate::matrices::sparse::{CscMatrix, CsrMatrix};
+use crate::matrices::view::MatrixView;
+use crate::matrices::{GenericMatrix, MatrixContent};
+
+/// A linear map `R^ncols -> R^nrows` that can be applied to vectors without necessarily being
+/// stored as a matrix.
+pub trait LinearOperator {
+    fn nrows(&self) -> usize;
+    fn ncols(&self) -> usize;
+
+    /// Writes `A x` into `y`, which has length `nrows()`; `x` has length `ncols()`.
+    fn apply(&self, x: &[f64], y: &mut [f64]);
+
+    /// Writes `A^T x` into `y`, which has length `ncols()`; `x` has length `nrows()`.
+    fn apply_transpose(&self, x: &[f64], y: &mut [f64]);
+
+    /// `other * self`: applies `self` first and `other` to the result.
+    fn then<B: LinearOperator>(self, other: B) -> Composition<Self, B>
+    where
+        Self: Sized,
+    {
+        assert_eq!(
+            self.nrows(),
+            other.ncols(),
+            "The height of the first operator must equal the width of the second in order to compose them"
+        );
+        Composition {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// `self + other`
+    fn plus<B: LinearOperator>(self, other: B) -> Sum<Self, B>
+    where
+        Self: Sized,
+    {
+        assert!(
+            self.nrows() == other.nrows() && self.ncols() == other.ncols(),
+            "The operators must have the same shape in order to add them"
+        );
+        Sum {
+            left: self,
+            right: other,
+        }
+    }
+
+    /// `factor * self`
+    fn scaled(self, factor: f64) -> Scaled<Self>
+    where
+        Self: Sized,
+    {
+        Scaled {
+            operator: self,
+            factor,
+        }
+    }
+
+    /// `self^T`, swapping the roles of `apply` and `apply_transpose`.
+    fn transposed(self) -> Transposed<Self>
+    where
+        Self: Sized,
+    {
+        Transposed(self)
+    }
+}
+
+impl<A: LinearOperator + ?Sized> LinearOperator for &A {
+    fn nrows(&self) -> usize {
+        (**self).nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        (**self).ncols()
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        (**self).apply(x, y)
+    }
+
+    fn apply_transpose(&self, x: &[f64], y: &mut [f64]) {
+        (**self).apply_transpose(x, y)
+    }
+}
+
+fn check_lengths<A: LinearOperator + ?Sized>(a: &A, x: &[f64], y: &[f64], transpose: bool) {
+    let (inputs, outputs) = if transpose {
+        (a.nrows(), a.ncols())
+    } else {
+        (a.ncols(), a.nrows())
+    };
+    assert_eq!(x.len(), inputs, "input length does not match the operator");
+    assert_eq!(
+        y.len(),
+        outputs,
+        "output length does not match the operator"
+    );
+}
+
+impl<'a> LinearOperator for MatrixView<'a, f64> {
+    fn nrows(&self) -> usize {
+        self.height() as usize
+    }
+
+    fn ncols(&self) -> usize {
+        self.width() as usize
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        check_lengths(self, x, y, false);
+        for (row, out) in y.iter_mut().enumerate() {
+            *out = self.row_iter(row as isize).zip(x).map(|(a, x)| a * x).sum();
+        }
+    }
+
+    fn apply_transpose(&self, x: &[f64], y: &mut [f64]) {
+        check_lengths(self, x, y, true);
+        for (col, out) in y.iter_mut().enumerate() {
+            *out = self
+                .column_iter(col as isize)
+                .zip(x)
+                .map(|(a, x)| a * x)
+                .sum();
+        }
+    }
+}
+
+impl LinearOperator for MatrixContent<f64> {
+    fn nrows(&self) -> usize {
+        self.height() as usize
+    }
+
+    fn ncols(&self) -> usize {
+        self.width() as usize
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        check_lengths(self, x, y, false);
+        for (row, out) in y.iter_mut().enumerate() {
+            *out = self.row_iter(row as isize).zip(x).map(|(a, x)| a * x).sum();
+        }
+    }
+
+    fn apply_transpose(&self, x: &[f64], y: &mut [f64]) {
+        check_lengths(self, x, y, true);
+        for (col, out) in y.iter_mut().enumerate() {
+            *out = self
+                .column_iter(col as isize)
+                .zip(x)
+                .map(|(a, x)| a * x)
+                .sum();
+        }
+    }
+}
+
+impl LinearOperator for GenericMatrix {
+    fn nrows(&self) -> usize {
+        self.content.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.content.ncols()
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        self.content.apply(x, y)
+    }
+
+    fn apply_transpose(&self, x: &[f64], y: &mut [f64]) {
+        self.content.apply_transpose(x, y)
+    }
+}
+
+impl LinearOperator for CsrMatrix<f64> {
+    fn nrows(&self) -> usize {
+        self.height() as usize
+    }
+
+    fn ncols(&self) -> usize {
+        self.width() as usize
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        check_lengths(self, x, y, false);
+        for (row, out) in y.iter_mut().enumerate() {
+            *out = self.row(row).map(|(col, a)| a * x[col]).sum();
+        }
+    }
+
+    fn apply_transpose(&self, x: &[f64], y: &mut [f64]) {
+        check_lengths(self, x, y, true);
+        y.iter_mut().for_each(|v| *v = 0.0);
+        for (row, x) in x.iter().enumerate() {
+            for (col, a) in self.row(row) {
+                y[col] += a * x;
+            }
+        }
+    }
+}
+
+impl LinearOperator for CscMatrix<f64> {
+    fn nrows(&self) -> usize {
+        self.height() as usize
+    }
+
+    fn ncols(&self) -> usize {
+        self.width() as usize
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        check_lengths(self, x, y, false);
+        y.iter_mut().for_each(|v| *v = 0.0);
+        for (col, x) in x.iter().enumerate() {
+            for (row, a) in self.column(col) {
+                y[row] += a * x;
+            }
+        }
+    }
+
+    fn apply_transpose(&self, x: &[f64], y: &mut [f64]) {
+        check_lengths(self, x, y, true);
+        for (col, out) in y.iter_mut().enumerate() {
+            *out = self.column(col).map(|(row, a)| a * x[row]).sum();
+        }
+    }
+}
+
+/// Operator defined by closures, for maps that are never materialized.
+///
+/// `apply_transpose` panics unless a transpose closure was supplied with
+/// [`FnOperator::with_transpose`].
+pub struct FnOperator<F, G = fn(&[f64], &mut [f64])> {
+    nrows: usize,
+    ncols: usize,
+    apply: F,
+    transpose: Option<G>,
+}
+
+impl<F: Fn(&[f64], &mut [f64])> FnOperator<F> {
+    pub fn new(nrows: usize, ncols: usize, apply: F) -> Self {
+        Self {
+            nrows,
+            ncols,
+            apply,
+            transpose: None,
+        }
+    }
+}
+
+impl<F: Fn(&[f64], &mut [f64]), G: Fn(&[f64], &mut [f64])> FnOperator<F, G> {
+    pub fn with_transpose<H: Fn(&[f64], &mut [f64])>(self, transpose: H) -> FnOperator<F, H> {
+        FnOperator {
+            nrows: self.nrows,
+            ncols: self.ncols,
+            apply: self.apply,
+            transpose: Some(transpose),
+        }
+    }
+}
+
+impl<F: Fn(&[f64], &mut [f64]), G: Fn(&[f64], &mut [f64])> LinearOperator for FnOperator<F, G> {
+    fn nrows(&self) -> usize {
+        self.nrows
+    }
+
+    fn ncols(&self) -> usize {
+        self.ncols
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        check_lengths(self, x, y, false);
+        (self.apply)(x, y)
+    }
+
+    fn apply_transpose(&self, x: &[f64], y: &mut [f64]) {
+        check_lengths(self, x, y, true);
+        let transpose = self
+            .transpose
+            .as_ref()
+            .expect("this operator was built without a transpose");
+        transpose(x, y)
+    }
+}
+
+/// See [`LinearOperator::then`].
+pub struct Composition<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: LinearOperator, B: LinearOperator> LinearOperator for Composition<A, B> {
+    fn nrows(&self) -> usize {
+        self.second.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.first.ncols()
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        let mut middle = vec![0.0; self.first.nrows()];
+        self.first.apply(x, &mut middle);
+        self.second.apply(&middle, y);
+    }
+
+    fn apply_transpose(&self, x: &[f64], y: &mut [f64]) {
+        let mut middle = vec![0.0; self.first.nrows()];
+        self.second.apply_transpose(x, &mut middle);
+        self.first.apply_transpose(&middle, y);
+    }
+}
+
+/// See [`LinearOperator::plus`].
+pub struct Sum<A, B> {
+    left: A,
+    right: B,
+}
+
+impl<A: LinearOperator, B: LinearOperator> LinearOperator for Sum<A, B> {
+    fn nrows(&self) -> usize {
+        self.left.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.left.ncols()
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        let mut other = vec![0.0; y.len()];
+        self.left.apply(x, y);
+        self.right.apply(x, &mut other);
+        y.iter_mut().zip(other).for_each(|(y, other)| *y += other);
+    }
+
+    fn apply_transpose(&self, x: &[f64], y: &mut [f64]) {
+        let mut other = vec![0.0; y.len()];
+        self.left.apply_transpose(x, y);
+        self.right.apply_transpose(x, &mut other);
+        y.iter_mut().zip(other).for_each(|(y, other)| *y += other);
+    }
+}
+
+/// See [`LinearOperator::scaled`].
+pub struct Scaled<A> {
+    operator: A,
+    factor: f64,
+}
+
+impl<A: LinearOperator> LinearOperator for Scaled<A> {
+    fn nrows(&self) -> usize {
+        self.operator.nrows()
+    }
+
+    fn ncols(&self) -> usize {
+        self.operator.ncols()
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        self.operator.apply(x, y);
+        y.iter_mut().for_each(|y| *y *= self.factor);
+    }
+
+    fn apply_transpose(&self, x: &[f64], y: &mut [f64]) {
+        self.operator.apply_transpose(x, y);
+        y.iter_mut().for_each(|y| *y *= self.factor);
+    }
+}
+
+/// See [`LinearOperator::transposed`].
+pub struct Transposed<A>(A);
+
+impl<A: LinearOperator> LinearOperator for Transposed<A> {
+    fn nrows(&self) -> usize {
+        self.0.ncols()
+    }
+
+    fn ncols(&self) -> usize {
+        self.0.nrows()
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        self.0.apply_transpose(x, y)
+    }
+
+    fn apply_transpose(&self, x: &[f64], y: &mut [f64]) {
+        self.0.apply(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimension::Dimension;
+    use crate::matrices::testing::max_difference;
+    use crate::matrices::Layout;
+    use crate::random::SplitMix64;
+
+    fn dense() -> MatrixContent<f64> {
+        MatrixContent::from_fn(Dimension::new(4, 3), |row, col| {
+            if (row + col) % 3 == 0 {
+                0.0
+            } else {
+                (row * 4 + col) as f64 - 5.0
+            }
+        })
+    }
+
+    fn applied<A: LinearOperator + ?Sized>(a: &A, x: &[f64]) -> Vec<f64> {
+        let mut y = vec![0.0; a.nrows()];
+        a.apply(x, &mut y);
+        y
+    }
+
+    fn applied_transpose<A: LinearOperator + ?Sized>(a: &A, x: &[f64]) -> Vec<f64> {
+        let mut y = vec![0.0; a.ncols()];
+        a.apply_transpose(x, &mut y);
+        y
+    }
+
+    #[test]
+    fn sparse_formats_agree_with_dense() {
+        let dense = dense();
+        let triplets: Vec<(usize, usize, f64)> = (0..3)
+            .flat_map(|row| (0..4).map(move |col| (row, col)))
+            .map(|(row, col)| (row, col, dense[(row as isize, col as isize)]))
+            .filter(|&(_, _, value)| value != 0.0)
+            .collect();
+        let csr = CsrMatrix::from_triplets(3, 4, &triplets).unwrap();
+        let csc = csr.to_csc();
+
+        let x = [1.0, -2.0, 0.5, 3.0];
+        let expected = vec![6.5, 5.0, 23.5];
+        assert_eq!(applied(&dense, &x), expected);
+        assert_eq!(applied(&csr, &x), expected);
+        assert_eq!(applied(&csc, &x), expected);
+
+        let x = [1.0, 2.0, -1.0];
+        let expected = applied_transpose(&dense, &x);
+        assert_eq!(applied_transpose(&csr, &x), expected);
+        assert_eq!(applied_transpose(&csc, &x), expected);
+        assert_eq!((dense.nrows(), dense.ncols()), (3, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "input length does not match the operator")]
+    fn wrong_input_length_panics() {
+        applied(&dense(), &[1.0; 3]);
+    }
+
+    fn random_dense(rng: &mut SplitMix64, height: isize, width: isize) -> MatrixContent<f64> {
+        MatrixContent::from_fn(Dimension::new(width, height), |_, _| rng.next_normal())
+    }
+
+    fn random_vector(rng: &mut SplitMix64, n: usize) -> Vec<f64> {
+        (0..n).map(|_| rng.next_normal()).collect()
+    }
+
+    /// Checks `op` against the dense matrix it should represent, in both directions.
+    fn assert_represents<A: LinearOperator>(op: &A, expected: &MatrixContent<f64>) {
+        let mut rng = SplitMix64::new(37);
+        assert_eq!(op.nrows(), expected.height() as usize);
+        assert_eq!(op.ncols(), expected.width() as usize);
+
+        let x = random_vector(&mut rng, op.ncols());
+        assert!(max_difference(&applied(op, &x), &applied(expected, &x)) < 1e-12);
+        let y = random_vector(&mut rng, op.nrows());
+        let transposed = applied_transpose(op, &y);
+        assert!(max_difference(&transposed, &applied_transpose(expected, &y)) < 1e-12);
+    }
+
+    #[test]
+    fn combinators_match_dense_arithmetic() {
+        let mut rng = SplitMix64::new(370);
+        let a = random_dense(&mut rng, 5, 3);
+        let b = random_dense(&mut rng, 4, 5);
+        let c = random_dense(&mut rng, 5, 3);
+
+        assert_represents(&(&a).then(&b), &(&b * &a));
+        assert_represents(&(&a).plus(&c), &(&a + &c));
+        assert_represents(&(&a).scaled(-2.5), &(a.clone() * -2.5));
+        assert_represents(&(&a).transposed(), &a.clone().into_transpose());
+        assert_represents(
+            &(&a)
+                .plus((&c).scaled(2.0))
+                .then((&b).transposed().transposed()),
+            &(&b * &(&a + &(c.clone() * 2.0))),
+        );
+    }
+
+    #[test]
+    fn closures_and_views() {
+        let mut rng = SplitMix64::new(371);
+        let a = random_dense(&mut rng, 6, 4);
+        let view = a.view(1, 1, 4, 2).unwrap();
+        assert_represents(&view, &MatrixContent::from(&view));
+        let column_major = a.to_layout(Layout::ColumnMajor);
+        let view = column_major.view(2, 0, 3, 4).unwrap();
+        assert_represents(&view, &MatrixContent::from(&view));
+
+        let difference = FnOperator::new(3, 4, |x: &[f64], y: &mut [f64]| {
+            for (i, y) in y.iter_mut().enumerate() {
+                *y = x[i + 1] - x[i];
+            }
+        })
+        .with_transpose(|x: &[f64], y: &mut [f64]| {
+            y.iter_mut().for_each(|y| *y = 0.0);
+            for (i, x) in x.iter().enumerate() {
+                y[i] -= x;
+                y[i + 1] += x;
+            }
+        });
+        let expected = MatrixContent::from_fn(Dimension::new(4, 3), |row, col| {
+            if col == row + 1 {
+                1.0
+            } else if col == row {
+                -1.0
+            } else {
+                0.0
+            }
+        });
+        assert_represents(&difference, &expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "built without a transpose")]
+    fn closures_without_a_transpose_panic() {
+        let op = FnOperator::new(2, 2, |x: &[f64], y: &mut [f64]| y.copy_from_slice(x));
+        applied_transpose(&op, &[1.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "in order to compose them")]
+    fn composing_mismatched_shapes_panics() {
+        let _ = dense().then(dense());
+    }
+}
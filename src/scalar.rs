@@ -0,0 +1,124 @@
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// Types with an additive identity.
+pub trait Zero: Sized {
+    fn zero() -> Self;
+    fn is_zero(&self) -> bool;
+}
+
+/// Types with a multiplicative identity.
+pub trait One: Sized {
+    fn one() -> Self;
+}
+
+macro_rules! impl_identities {
+    ($zero:expr, $one:expr; $($t:ty),*) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+
+                fn is_zero(&self) -> bool {
+                    *self == $zero
+                }
+            }
+
+            impl One for $t {
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+
+impl_identities!(0, 1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_identities!(0.0, 1.0; f32, f64);
+
+/// Signed integers supporting exact, fraction-free arithmetic.
+///
+/// Division and remainder truncate toward zero, matching the primitive integer types.
+pub trait Integer:
+    Clone
+    + Ord
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    fn is_negative(&self) -> bool {
+        *self < Self::zero()
+    }
+
+    fn abs(&self) -> Self {
+        if self.is_negative() {
+            -self.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Quotient rounded toward negative infinity.
+    fn div_floor(&self, other: &Self) -> Self {
+        let quotient = self.clone() / other.clone();
+        let remainder = self.clone() % other.clone();
+        if !remainder.is_zero() && (remainder.is_negative() != other.is_negative()) {
+            quotient - Self::one()
+        } else {
+            quotient
+        }
+    }
+
+    /// Returns `(g, x, y)` such that `x * self + y * other = g` and `g = gcd(self, other) >= 0`.
+    fn extended_gcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (mut old_r, mut r) = (self.clone(), other.clone());
+        let (mut old_s, mut s) = (Self::one(), Self::zero());
+        let (mut old_t, mut t) = (Self::zero(), Self::one());
+
+        while !r.is_zero() {
+            let q = old_r.clone() / r.clone();
+            let next_r = old_r - q.clone() * r.clone();
+            old_r = std::mem::replace(&mut r, next_r);
+            let next_s = old_s - q.clone() * s.clone();
+            old_s = std::mem::replace(&mut s, next_s);
+            let next_t = old_t - q * t.clone();
+            old_t = std::mem::replace(&mut t, next_t);
+        }
+
+        if old_r.is_negative() {
+            (-old_r, -old_s, -old_t)
+        } else {
+            (old_r, old_s, old_t)
+        }
+    }
+}
+
+impl Integer for i8 {}
+impl Integer for i16 {}
+impl Integer for i32 {}
+impl Integer for i64 {}
+impl Integer for i128 {}
+impl Integer for isize {}
+
+/// Types closed under exact addition, subtraction, multiplication and division by non-zero
+/// elements, such as the prime fields in [`crate::finite_field`].
+///
+/// Elimination over a `Field` pivots on the first non-zero entry, so this is intended for exact
+/// arithmetic rather than floating point.
+pub trait Field:
+    Clone
+    + PartialEq
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+}